@@ -0,0 +1,107 @@
+// rawstring benchmarks
+//
+// Run with `cargo bench`. Each group measures `rawstring` against `bstr` on the same
+// input, so the two can be compared directly in the report.
+//
+// To check a change for regressions, save a baseline before making it and compare after:
+//
+//     cargo bench -- --save-baseline before
+//     cargo bench -- --baseline before
+//
+// A single group or corpus can be selected by name, e.g. `cargo bench -- display/ascii`.
+
+use std::{fmt::Write, hint::black_box};
+
+use bstr::ByteSlice;
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use rawstring::RawStr;
+
+const CORPUS_LEN: usize = 64 * 1024;
+
+/// Repeats `unit` until the corpus is `CORPUS_LEN` bytes long.
+fn repeat_to_len(unit: &[u8]) -> Vec<u8> {
+	unit.iter().copied().cycle().take(CORPUS_LEN).collect()
+}
+
+/// The corpora every group runs against: mostly-ASCII log lines, mixed scripts,
+/// and text with an invalid byte in every few characters.
+fn corpora() -> [(&'static str, Vec<u8>); 3] {
+	[
+		("ascii", repeat_to_len(b"2024-05-01T12:00:00Z INFO request served path=/api/v1/items status=200\n")),
+		("multilingual", repeat_to_len("Gr\u{fc}\u{df}e, \u{4e16}\u{754c}! \u{41f}\u{440}\u{438}\u{432}\u{435}\u{442} \u{1F980} caf\u{e9}\n".as_bytes())),
+		("invalid", repeat_to_len(b"abc\xFFdef\xC0\xAFghi\xED\xA0\x80jkl\xE2\x82\n")),
+	]
+}
+
+fn find(c: &mut Criterion) {
+	let mut group = c.benchmark_group("find");
+	for (name, corpus) in corpora() {
+		group.throughput(Throughput::Bytes(corpus.len() as u64));
+		// the needle never occurs, so the whole corpus is scanned
+		let needle = b"needle not present";
+		group.bench_with_input(BenchmarkId::new("rawstring", name), &corpus, |b, corpus| {
+			let raw = RawStr::new(corpus);
+			b.iter(|| black_box(raw).find(needle));
+		});
+		group.bench_with_input(BenchmarkId::new("bstr", name), &corpus, |b, corpus| {
+			b.iter(|| black_box(corpus.as_slice()).find(needle));
+		});
+	}
+	group.finish();
+}
+
+fn split(c: &mut Criterion) {
+	let mut group = c.benchmark_group("split");
+	for (name, corpus) in corpora() {
+		group.throughput(Throughput::Bytes(corpus.len() as u64));
+		group.bench_with_input(BenchmarkId::new("rawstring", name), &corpus, |b, corpus| {
+			let raw = RawStr::new(corpus);
+			b.iter(|| black_box(raw).split_str(" ").count());
+		});
+		group.bench_with_input(BenchmarkId::new("bstr", name), &corpus, |b, corpus| {
+			b.iter(|| black_box(corpus.as_slice()).split_str(" ").count());
+		});
+	}
+	group.finish();
+}
+
+fn display(c: &mut Criterion) {
+	let mut group = c.benchmark_group("display");
+	for (name, corpus) in corpora() {
+		group.throughput(Throughput::Bytes(corpus.len() as u64));
+		let mut out = String::with_capacity(corpus.len() * 2);
+		group.bench_with_input(BenchmarkId::new("rawstring", name), &corpus, |b, corpus| {
+			let raw = RawStr::new(corpus);
+			b.iter(|| {
+				out.clear();
+				write!(out, "{}", black_box(raw)).unwrap();
+			});
+		});
+		group.bench_with_input(BenchmarkId::new("bstr", name), &corpus, |b, corpus| {
+			let bstr = corpus.as_bstr();
+			b.iter(|| {
+				out.clear();
+				write!(out, "{}", black_box(bstr)).unwrap();
+			});
+		});
+	}
+	group.finish();
+}
+
+fn lossy(c: &mut Criterion) {
+	let mut group = c.benchmark_group("lossy");
+	for (name, corpus) in corpora() {
+		group.throughput(Throughput::Bytes(corpus.len() as u64));
+		group.bench_with_input(BenchmarkId::new("rawstring", name), &corpus, |b, corpus| {
+			let raw = RawStr::new(corpus);
+			b.iter(|| black_box(raw).to_utf8_lossy().len());
+		});
+		group.bench_with_input(BenchmarkId::new("bstr", name), &corpus, |b, corpus| {
+			b.iter(|| black_box(corpus.as_slice()).to_str_lossy().len());
+		});
+	}
+	group.finish();
+}
+
+criterion_group!(benches, find, split, display, lossy);
+criterion_main!(benches);
@@ -0,0 +1,83 @@
+#ifndef RAWSTRING_H
+#define RAWSTRING_H
+
+/* This file is generated by cbindgen. Do not edit it by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// An owned raw string with a C-compatible layout.
+//
+// The buffer is allocated by Rust and must be released with `rawstring_buf_free`;
+// it must not be freed by C. C code may read the fields and write the bytes, but must not
+// change the fields. On the Rust side, a `RawStringBuf` frees its buffer when dropped
+// and converts back with `RawString::from`.
+typedef struct RawStringBuf {
+  // A pointer to the first byte.
+  uint8_t *ptr;
+  // The number of bytes.
+  size_t len;
+  // The capacity of the allocation.
+  size_t cap;
+} RawStringBuf;
+
+// A borrowed view of raw string data with a C-compatible layout.
+//
+// A null `ptr` is allowed when `len` is zero.
+typedef struct RawStrView {
+  // A pointer to the first byte.
+  const uint8_t *ptr;
+  // The number of bytes.
+  size_t len;
+} RawStrView;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Copies the bytes of `view` into a new `RawStringBuf`, which must be released with
+// `rawstring_buf_free`.
+//
+// # Safety
+// Unless `view.len` is zero, `view.ptr` must point to `view.len` readable bytes.
+struct RawStringBuf rawstring_buf_from_view(struct RawStrView view);
+
+// Returns a view of the bytes of `buf`, valid until `buf` is freed or its bytes are changed.
+//
+// In Rust, the lifetime of the returned view is unbounded, as with `slice::from_raw_parts`,
+// and the caller must not let the view outlive `buf`; prefer `RawStringBuf::as_view`,
+// which ties the view to a borrow of the buffer.
+//
+// # Safety
+// `buf` must point to a valid `RawStringBuf`.
+struct RawStrView rawstring_buf_as_view(const struct RawStringBuf *buf);
+
+// Frees a `RawStringBuf` created by Rust.
+//
+// # Safety
+// `buf` must have been created by this crate, with its fields unchanged, and not freed before.
+void rawstring_buf_free(struct RawStringBuf buf);
+
+// Returns `true` if the bytes of `view` are valid UTF-8.
+//
+// # Safety
+// Unless `view.len` is zero, `view.ptr` must point to `view.len` readable bytes.
+bool rawstring_view_is_utf8(struct RawStrView view);
+
+// Returns `true` if the bytes of `a` and `b` are equal.
+//
+// # Safety
+// Unless its `len` is zero, the `ptr` of each view must point to `len` readable bytes.
+bool rawstring_view_eq(struct RawStrView a, struct RawStrView b);
+
+// Returns an empty view with a null pointer.
+struct RawStrView rawstring_view_empty(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* RAWSTRING_H */
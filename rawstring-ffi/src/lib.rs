@@ -0,0 +1,221 @@
+// rawstring-ffi
+
+//! A C-compatible boundary for exchanging raw strings from `rawstring` with C and C++ code.
+//!
+//! [`RawStrView`] borrows bytes and [`RawStringBuf`] owns them; both are `#[repr(C)]`
+//! and can be passed by value across `extern "C"` functions. The `rawstring_*` functions
+//! in this crate are exported unmangled, and `include/rawstring.h` declares them for C callers.
+//! The header is generated by cbindgen from this crate using the `cbindgen.toml` in the crate root.
+//!
+//! The exports live in this crate rather than behind a feature of `rawstring`, so that
+//! a binary which links two semver-incompatible versions of `rawstring` does not get
+//! duplicate symbols.
+//!
+//! # Examples
+//! ```
+//! # use rawstring::{RawStr, RawString};
+//! use rawstring_ffi::{self as ffi, RawStrView, RawStringBuf};
+//!
+//! // a Rust library returning an owned string to C
+//! let buf = RawStringBuf::from(RawString::from(b"caf\xE9".as_slice()));
+//! let view = unsafe { ffi::rawstring_buf_as_view(&buf) };
+//! assert!(!unsafe { ffi::rawstring_view_is_utf8(view) });
+//! assert_eq!(unsafe { view.as_raw_str() }, RawStr::new(b"caf\xE9"));
+//!
+//! // C hands ownership back to be freed
+//! unsafe { ffi::rawstring_buf_free(buf) };
+//! ```
+
+use std::{marker::PhantomData, mem::ManuallyDrop, ptr};
+
+use rawstring::{RawStr, RawString};
+
+/// A borrowed view of raw string data with a C-compatible layout.
+///
+/// A null `ptr` is allowed when `len` is zero.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawStrView<'a> {
+	/// A pointer to the first byte.
+	pub ptr: *const u8,
+	/// The number of bytes.
+	pub len: usize,
+	_marker: PhantomData<&'a RawStr>,
+}
+
+impl<'a> RawStrView<'a> {
+	/// Creates a view of a [`RawStr`].
+	#[inline]
+	#[must_use]
+	pub const fn new(raw: &'a RawStr) -> Self {
+		Self { ptr: raw.0.as_ptr(), len: raw.0.len(), _marker: PhantomData }
+	}
+
+	/// Returns the viewed bytes as a [`RawStr`].
+	///
+	/// # Safety
+	/// Unless `len` is zero, `ptr` must point to `len` initialized bytes that are valid
+	/// and not mutated for `'a`. Views created by [`RawStrView::new`] always satisfy this,
+	/// but views received from C must be trusted.
+	#[inline]
+	#[must_use]
+	pub const unsafe fn as_raw_str(&self) -> &'a RawStr {
+		if self.len == 0 {
+			return RawStr::EMPTY;
+		}
+		// SAFETY: upheld by the caller
+		unsafe { RawStr::from_raw_parts(self.ptr, self.len) }
+	}
+}
+
+impl<'a> From<&'a RawStr> for RawStrView<'a> {
+	#[inline]
+	fn from(raw: &'a RawStr) -> Self {
+		Self::new(raw)
+	}
+}
+
+/// An owned raw string with a C-compatible layout.
+///
+/// The buffer is allocated by Rust and must be released with `rawstring_buf_free`;
+/// it must not be freed by C. C code may read the fields and write the bytes, but must not
+/// change the fields. On the Rust side, a `RawStringBuf` frees its buffer when dropped
+/// and converts back with `RawString::from`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct RawStringBuf {
+	/// A pointer to the first byte.
+	ptr: *mut u8,
+	/// The number of bytes.
+	len: usize,
+	/// The capacity of the allocation.
+	cap: usize,
+}
+
+impl RawStringBuf {
+	/// Returns a pointer to the first byte.
+	#[inline]
+	#[must_use]
+	pub const fn ptr(&self) -> *mut u8 {
+		self.ptr
+	}
+
+	/// Returns the number of bytes.
+	#[inline]
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns `true` if the buffer holds no bytes.
+	#[inline]
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Returns the capacity of the allocation.
+	#[inline]
+	#[must_use]
+	pub const fn capacity(&self) -> usize {
+		self.cap
+	}
+
+	/// Returns a view of the bytes.
+	#[inline]
+	#[must_use]
+	pub fn as_view(&self) -> RawStrView<'_> {
+		RawStrView { ptr: self.ptr, len: self.len, _marker: PhantomData }
+	}
+}
+
+impl From<RawString> for RawStringBuf {
+	#[inline]
+	fn from(raw: RawString) -> Self {
+		let mut vec = ManuallyDrop::new(raw.0);
+		Self { ptr: vec.as_mut_ptr(), len: vec.len(), cap: vec.capacity() }
+	}
+}
+
+/// Takes back ownership of the buffer, which also makes `RawString::from(buf)` available.
+///
+/// This is sound because the fields are private, so every `RawStringBuf` comes from a
+/// [`RawString`], or from C code that is trusted not to change the fields.
+impl From<RawStringBuf> for Vec<u8> {
+	#[inline]
+	fn from(buf: RawStringBuf) -> Self {
+		let buf = ManuallyDrop::new(buf);
+		// SAFETY: the fields come from a Vec<u8> in `From<RawString> for RawStringBuf`
+		unsafe { Vec::from_raw_parts(buf.ptr, buf.len, buf.cap) }
+	}
+}
+
+impl Drop for RawStringBuf {
+	#[inline]
+	fn drop(&mut self) {
+		// SAFETY: the fields come from a Vec<u8> in `From<RawString> for RawStringBuf`
+		drop(unsafe { Vec::from_raw_parts(self.ptr, self.len, self.cap) });
+	}
+}
+
+/// Copies the bytes of `view` into a new `RawStringBuf`, which must be released with
+/// `rawstring_buf_free`.
+///
+/// # Safety
+/// Unless `view.len` is zero, `view.ptr` must point to `view.len` readable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rawstring_buf_from_view(view: RawStrView<'_>) -> RawStringBuf {
+	// SAFETY: upheld by the caller
+	let raw = unsafe { view.as_raw_str() };
+	RawStringBuf::from(raw.to_owned())
+}
+
+/// Returns a view of the bytes of `buf`, valid until `buf` is freed or its bytes are changed.
+///
+/// In Rust, the lifetime of the returned view is unbounded, as with `slice::from_raw_parts`,
+/// and the caller must not let the view outlive `buf`; prefer `RawStringBuf::as_view`,
+/// which ties the view to a borrow of the buffer.
+///
+/// # Safety
+/// `buf` must point to a valid `RawStringBuf`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rawstring_buf_as_view<'a>(buf: *const RawStringBuf) -> RawStrView<'a> {
+	// SAFETY: upheld by the caller
+	let buf = unsafe { &*buf };
+	RawStrView { ptr: buf.ptr, len: buf.len, _marker: PhantomData }
+}
+
+/// Frees a `RawStringBuf` created by Rust.
+///
+/// # Safety
+/// `buf` must have been created by this crate, with its fields unchanged, and not freed before.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rawstring_buf_free(buf: RawStringBuf) {
+	drop(buf);
+}
+
+/// Returns `true` if the bytes of `view` are valid UTF-8.
+///
+/// # Safety
+/// Unless `view.len` is zero, `view.ptr` must point to `view.len` readable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rawstring_view_is_utf8(view: RawStrView<'_>) -> bool {
+	// SAFETY: upheld by the caller
+	unsafe { view.as_raw_str() }.is_utf8()
+}
+
+/// Returns `true` if the bytes of `a` and `b` are equal.
+///
+/// # Safety
+/// Unless its `len` is zero, the `ptr` of each view must point to `len` readable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rawstring_view_eq(a: RawStrView<'_>, b: RawStrView<'_>) -> bool {
+	// SAFETY: upheld by the caller
+	unsafe { a.as_raw_str() == b.as_raw_str() }
+}
+
+/// Returns an empty view with a null pointer.
+#[unsafe(no_mangle)]
+pub extern "C" fn rawstring_view_empty() -> RawStrView<'static> {
+	RawStrView { ptr: ptr::null(), len: 0, _marker: PhantomData }
+}
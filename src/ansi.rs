@@ -0,0 +1,157 @@
+// rawstring::ansi
+
+use std::{borrow::Cow, iter::FusedIterator};
+
+use crate::{RawStr, RawString};
+
+const ESC: u8 = 0x1B;
+const BEL: u8 = 0x07;
+
+/// A piece of a [`RawStr`] split into text and ANSI escape sequences.
+///
+/// Yielded by [`RawStr::ansi_tokens`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnsiToken<'a> {
+	/// Text between escape sequences, which may include invalid UTF-8.
+	Text(&'a RawStr),
+	/// A complete escape sequence, including the leading `ESC`.
+	Escape(&'a RawStr),
+}
+
+impl<'a> AnsiToken<'a> {
+	/// Returns the bytes of the token.
+	#[inline]
+	#[must_use]
+	pub const fn as_raw_str(&self) -> &'a RawStr {
+		match *self {
+			Self::Text(raw) | Self::Escape(raw) => raw,
+		}
+	}
+}
+
+/// Returns the length of the escape sequence at the start of `bytes`, which starts with `ESC`.
+///
+/// Sequences cut off by the end of the input extend to the end, and malformed sequences end
+/// before the first byte that does not belong to them, so an escape never swallows the text
+/// after it.
+fn escape_len(bytes: &[u8]) -> usize {
+	let rest = &bytes[1..];
+	match rest.first() {
+		// CSI: parameter bytes, intermediate bytes, then a final byte
+		Some(b'[') => {
+			let body = &rest[1..];
+			let params = body.iter().take_while(|b| (0x30..=0x3F).contains(*b)).count();
+			let inters = body[params..].iter().take_while(|b| (0x20..=0x2F).contains(*b)).count();
+			let end = params + inters;
+			let last = usize::from(matches!(body.get(end), Some(0x40..=0x7E)));
+			2 + end + last
+		}
+		// OSC, DCS, SOS, PM and APC: a string terminated by ST (`ESC \`), or by BEL for OSC
+		Some(&kind @ (b']' | b'P' | b'X' | b'^' | b'_')) => {
+			let body = &rest[1..];
+			for (i, &b) in body.iter().enumerate() {
+				if b == BEL && kind == b']' {
+					return 2 + i + 1;
+				}
+				if b == ESC && body.get(i + 1) == Some(&b'\\') {
+					return 2 + i + 2;
+				}
+			}
+			bytes.len()
+		}
+		// other escapes: intermediate bytes then a final byte, such as `ESC ( B` or `ESC 7`
+		Some(_) => {
+			let inters = rest.iter().take_while(|b| (0x20..=0x2F).contains(*b)).count();
+			let last = usize::from(matches!(rest.get(inters), Some(0x30..=0x7E)));
+			1 + inters + last
+		}
+		None => 1,
+	}
+}
+
+impl RawStr {
+	/// Returns an iterator that splits the [`RawStr`] into text and ANSI escape sequences.
+	///
+	/// Recognized sequences are CSI sequences such as colors and cursor movement (`ESC [ … m`),
+	/// string sequences such as window titles and hyperlinks (`ESC ] … BEL` or `ESC ] … ESC \`),
+	/// and two- or three-byte escapes such as `ESC ( B`. Concatenating the tokens gives back the
+	/// [`RawStr`], and invalid UTF-8 and other binary data is always left in the text.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{AnsiToken, RawStr};
+	/// let log = RawStr::new(b"\x1B[1;31merror\x1B[0m: \xFF\x1B]0;title\x07");
+	/// let tokens: Vec<_> = log.ansi_tokens().collect();
+	/// assert_eq!(tokens, [
+	///     AnsiToken::Escape(RawStr::new(b"\x1B[1;31m")),
+	///     AnsiToken::Text(RawStr::new("error")),
+	///     AnsiToken::Escape(RawStr::new(b"\x1B[0m")),
+	///     AnsiToken::Text(RawStr::new(b": \xFF")),
+	///     AnsiToken::Escape(RawStr::new(b"\x1B]0;title\x07")),
+	/// ]);
+	/// ```
+	#[inline]
+	pub fn ansi_tokens(&self) -> AnsiTokens<'_> {
+		AnsiTokens { rest: &self.0 }
+	}
+
+	/// Returns the [`RawStr`] with all ANSI escape sequences removed.
+	///
+	/// Returns the [`RawStr`] unchanged, without copying, if it contains no `ESC` byte.
+	/// See [`RawStr::ansi_tokens`] for the recognized sequences.
+	///
+	/// # Examples
+	/// ```
+	/// # use std::borrow::Cow;
+	/// # use rawstring::RawStr;
+	/// let colored = RawStr::new(b"\x1B[32mok\x1B[0m \xFE\xFF");
+	/// assert_eq!(*colored.strip_ansi(), b"ok \xFE\xFF");
+	/// assert!(matches!(RawStr::new("plain").strip_ansi(), Cow::Borrowed(_)));
+	/// ```
+	#[must_use]
+	pub fn strip_ansi(&self) -> Cow<'_, RawStr> {
+		if !self.contains(&ESC) {
+			return Cow::Borrowed(self);
+		}
+		let mut stripped = RawString::from(Vec::with_capacity(self.len()));
+		for token in self.ansi_tokens() {
+			if let AnsiToken::Text(text) = token {
+				stripped.extend_from_slice(text);
+			}
+		}
+		Cow::Owned(stripped)
+	}
+}
+
+/// An iterator over the text and ANSI escape sequences of a [`RawStr`].
+///
+/// Created by [`RawStr::ansi_tokens`].
+#[derive(Debug, Clone)]
+pub struct AnsiTokens<'a> {
+	rest: &'a [u8],
+}
+
+impl<'a> Iterator for AnsiTokens<'a> {
+	type Item = AnsiToken<'a>;
+
+	fn next(&mut self) -> Option<AnsiToken<'a>> {
+		if self.rest.is_empty() {
+			return None;
+		}
+		let (len, token): (usize, fn(&'a RawStr) -> AnsiToken<'a>) = if self.rest[0] == ESC {
+			(escape_len(self.rest), AnsiToken::Escape)
+		} else {
+			(self.rest.iter().position(|&b| b == ESC).unwrap_or(self.rest.len()), AnsiToken::Text)
+		};
+		let (head, rest) = self.rest.split_at(len);
+		self.rest = rest;
+		Some(token(RawStr::new(head)))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::from(!self.rest.is_empty()), Some(self.rest.len()))
+	}
+}
+
+impl FusedIterator for AnsiTokens<'_> {}
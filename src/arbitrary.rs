@@ -0,0 +1,37 @@
+// rawstring::arbitrary
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::RawString;
+
+/// Generates both valid UTF-8 text and arbitrary bytes. Requires the `quickcheck` feature.
+///
+/// Shrinking first replaces every non-ASCII byte of invalid UTF-8 data with `?`,
+/// then removes bytes and simplifies the remaining ones.
+///
+/// # Examples
+/// ```
+/// # use rawstring::RawString;
+/// use quickcheck::Arbitrary;
+///
+/// let raw = RawString::from(b"a\xFFb".as_slice());
+/// let first = raw.shrink().next().unwrap();
+/// assert_eq!(first, RawString::from("a?b"));
+/// ```
+impl Arbitrary for RawString {
+	fn arbitrary(g: &mut Gen) -> Self {
+		if bool::arbitrary(g) {
+			RawString::from(String::arbitrary(g))
+		} else {
+			RawString::from(Vec::<u8>::arbitrary(g))
+		}
+	}
+
+	fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+		let ascii = self.as_ref().to_utf8_checked().is_err().then(|| {
+			let bytes: Vec<u8> = self.iter().map(|&b| if b.is_ascii() { b } else { b'?' }).collect();
+			RawString(bytes)
+		});
+		Box::new(ascii.into_iter().chain(self.0.shrink().map(RawString)))
+	}
+}
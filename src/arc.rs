@@ -0,0 +1,212 @@
+// rawstring::arc
+
+use std::{
+	borrow::Borrow,
+	cmp::Ordering,
+	fmt,
+	hash::{Hash, Hasher},
+	ops::Deref,
+	sync::Arc,
+};
+
+use crate::{RawStr, RawString};
+
+/// The longest [`RawStrArc`] stored inline.
+const INLINE_CAP: usize = 22;
+
+/// An immutable raw string with O(1) clones.
+///
+/// Strings of up to 22 bytes are stored inline without allocating; longer strings are
+/// stored in an [`Arc`] shared between clones. Either way the value is 24 bytes, the same
+/// as a [`RawString`]. This suits identifiers, tokens and AST nodes, which are cloned far
+/// more often than they are built. [`RawStrArc`] derefs to [`RawStr`].
+///
+/// Requires the `arc` feature.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{RawStr, RawStrArc, RawString};
+/// let ident = RawStrArc::from(RawStr::new(b"caf\xE9"));
+/// assert!(ident.is_inline());
+/// assert_eq!(size_of::<RawStrArc>(), size_of::<RawString>());
+///
+/// let body = RawStrArc::from(RawString::from(vec![0xFF; 100]));
+/// let shared = body.clone();
+/// assert!(!shared.is_inline());
+/// assert_eq!(shared.as_ptr(), body.as_ptr());
+///
+/// assert_eq!(ident, RawStr::new(b"caf\xE9"));
+/// assert_eq!(RawString::from(ident), RawString::from(b"caf\xE9"));
+/// ```
+#[derive(Clone)]
+pub struct RawStrArc(Repr);
+
+#[derive(Clone)]
+enum Repr {
+	Inline { len: u8, buf: [u8; INLINE_CAP] },
+	Heap(Arc<[u8]>),
+}
+
+impl RawStrArc {
+	/// An empty [`RawStrArc`].
+	pub const EMPTY: Self = Self(Repr::Inline { len: 0, buf: [0; INLINE_CAP] });
+
+	/// Creates a [`RawStrArc`] holding a copy of `raw`.
+	#[inline]
+	#[must_use]
+	pub fn new(raw: &RawStr) -> Self {
+		match Self::new_inline(raw) {
+			Some(inline) => inline,
+			None => Self(Repr::Heap(Arc::from(&raw.0))),
+		}
+	}
+
+	/// Creates a [`RawStrArc`] stored inline, or returns `None` if `raw` is longer than 22 bytes.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawStrArc};
+	/// const KEYWORD: RawStrArc = RawStrArc::new_inline(RawStr::from_bytes(b"return")).unwrap();
+	/// assert_eq!(KEYWORD, "return");
+	/// assert!(RawStrArc::new_inline(RawStr::new(&[0; 23])).is_none());
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn new_inline(raw: &RawStr) -> Option<Self> {
+		if raw.0.len() > INLINE_CAP {
+			return None;
+		}
+		let mut buf = [0; INLINE_CAP];
+		buf.split_at_mut(raw.0.len()).0.copy_from_slice(&raw.0);
+		Some(Self(Repr::Inline { len: raw.0.len() as u8, buf }))
+	}
+
+	/// Returns the contents as a [`RawStr`].
+	#[inline]
+	#[must_use]
+	pub fn as_raw_str(&self) -> &RawStr {
+		match &self.0 {
+			Repr::Inline { len, buf } => RawStr::new(&buf[..usize::from(*len)]),
+			Repr::Heap(arc) => RawStr::new(arc),
+		}
+	}
+
+	/// Returns `true` if the contents are stored inline rather than in a shared allocation.
+	#[inline]
+	#[must_use]
+	pub const fn is_inline(&self) -> bool {
+		matches!(self.0, Repr::Inline { .. })
+	}
+}
+
+impl Default for RawStrArc {
+	#[inline]
+	fn default() -> Self {
+		Self::EMPTY
+	}
+}
+
+impl Deref for RawStrArc {
+	type Target = RawStr;
+
+	#[inline]
+	fn deref(&self) -> &RawStr {
+		self.as_raw_str()
+	}
+}
+
+impl AsRef<RawStr> for RawStrArc {
+	#[inline]
+	fn as_ref(&self) -> &RawStr {
+		self.as_raw_str()
+	}
+}
+
+impl AsRef<[u8]> for RawStrArc {
+	#[inline]
+	fn as_ref(&self) -> &[u8] {
+		self.as_raw_str()
+	}
+}
+
+impl Borrow<RawStr> for RawStrArc {
+	#[inline]
+	fn borrow(&self) -> &RawStr {
+		self.as_raw_str()
+	}
+}
+
+impl From<&RawStr> for RawStrArc {
+	#[inline]
+	fn from(raw: &RawStr) -> Self {
+		Self::new(raw)
+	}
+}
+
+impl From<&str> for RawStrArc {
+	#[inline]
+	fn from(s: &str) -> Self {
+		Self::new(RawStr::new(s))
+	}
+}
+
+impl From<RawString> for RawStrArc {
+	#[inline]
+	fn from(raw: RawString) -> Self {
+		match Self::new_inline(raw.as_ref()) {
+			Some(inline) => inline,
+			None => Self(Repr::Heap(Arc::from(raw.0))),
+		}
+	}
+}
+
+impl From<RawStrArc> for Vec<u8> {
+	#[inline]
+	fn from(arc: RawStrArc) -> Self {
+		arc.as_raw_str().0.to_vec()
+	}
+}
+
+impl fmt::Debug for RawStrArc {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(self.as_raw_str(), f)
+	}
+}
+
+impl fmt::Display for RawStrArc {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(self.as_raw_str(), f)
+	}
+}
+
+impl<T: ?Sized + AsRef<[u8]>> PartialEq<T> for RawStrArc {
+	#[inline]
+	fn eq(&self, other: &T) -> bool {
+		self.as_raw_str() == other
+	}
+}
+
+impl Eq for RawStrArc {}
+
+impl<T: ?Sized + AsRef<[u8]>> PartialOrd<T> for RawStrArc {
+	#[inline]
+	fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+		self.as_raw_str().partial_cmp(other)
+	}
+}
+
+impl Ord for RawStrArc {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.as_raw_str().cmp(other.as_raw_str())
+	}
+}
+
+impl Hash for RawStrArc {
+	#[inline]
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.as_raw_str().hash(state);
+	}
+}
@@ -0,0 +1,144 @@
+// rawstring::between
+
+use std::iter::FusedIterator;
+
+use crate::{RawStr, search::Finder};
+
+impl RawStr {
+	/// Returns the bytes between the first occurrence of `open` and the next occurrence
+	/// of `close` after it, or `None` if either is missing.
+	///
+	/// Delimiters are not nested; use [`RawStr::matching_delimiter`] for nested brackets.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"Hello {{name}}, you owe {{\xFF}}");
+	/// assert_eq!(raw.between("{{", "}}"), Some(RawStr::new("name")));
+	/// assert_eq!(raw.between("<", ">"), None);
+	/// ```
+	#[must_use]
+	pub fn between<O, C>(&self, open: &O, close: &C) -> Option<&RawStr>
+	where
+		O: ?Sized + AsRef<[u8]>,
+		C: ?Sized + AsRef<[u8]>,
+	{
+		let start = self.find(open)? + open.as_ref().len();
+		let end = start + RawStr::new(&self[start..]).find(close)?;
+		Some(RawStr::new(&self[start..end]))
+	}
+
+	/// Returns an iterator over the bytes between each `open` and the next `close` after it.
+	///
+	/// Sections do not overlap: the search for the next `open` starts after the previous `close`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"{{a}} and {{b\xFF}} and {{unclosed");
+	/// let sections: Vec<_> = raw.between_all("{{", "}}").collect();
+	/// assert_eq!(sections, [RawStr::new("a"), RawStr::new(b"b\xFF")]);
+	/// ```
+	#[inline]
+	pub fn between_all<'n, O, C>(&self, open: &'n O, close: &'n C) -> BetweenAll<'_, 'n>
+	where
+		O: ?Sized + AsRef<[u8]>,
+		C: ?Sized + AsRef<[u8]>,
+	{
+		BetweenAll { haystack: self, open: Finder::new(open), close: Finder::new(close), pos: Some(0) }
+	}
+
+	/// Returns the index of the `close` delimiter matching the `open` delimiter at `open_idx`,
+	/// counting nested pairs, or `None` if there is no `open` at `open_idx` or it is never closed.
+	///
+	/// If `open` and `close` are the same, such as quotes, the next occurrence is the match.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"f(a, (b\xFF), c) + (d");
+	/// assert_eq!(raw.matching_delimiter(1, "(", ")"), Some(12));
+	/// assert_eq!(raw.matching_delimiter(5, "(", ")"), Some(8));
+	/// assert_eq!(raw.matching_delimiter(16, "(", ")"), None);
+	/// assert_eq!(raw.matching_delimiter(0, "(", ")"), None);
+	///
+	/// let template = RawStr::new("{% if %}{% if %}{% end %}{% end %}");
+	/// assert_eq!(template.matching_delimiter(0, "{% if %}", "{% end %}"), Some(25));
+	/// ```
+	#[must_use]
+	pub fn matching_delimiter<O, C>(&self, open_idx: usize, open: &O, close: &C) -> Option<usize>
+	where
+		O: ?Sized + AsRef<[u8]>,
+		C: ?Sized + AsRef<[u8]>,
+	{
+		let (open, close) = (Finder::new(open), Finder::new(close));
+		if !self.get(open_idx..)?.starts_with(open.needle()) {
+			return None;
+		}
+
+		let mut depth = 1usize;
+		let mut pos = open_idx + open.needle().len();
+		loop {
+			let next_close = close.find_from(self, pos)?;
+			match open.find_from(self, pos) {
+				Some(next_open) if next_open < next_close && !open.needle().is_empty() => {
+					depth += 1;
+					pos = next_open + open.needle().len();
+				}
+				_ => {
+					depth -= 1;
+					if depth == 0 {
+						return Some(next_close);
+					}
+					pos = next_close + close.needle().len().max(1);
+				}
+			}
+		}
+	}
+}
+
+/// An iterator over the sections of a [`RawStr`] between pairs of delimiters.
+///
+/// Created by [`RawStr::between_all`].
+#[derive(Debug, Clone)]
+pub struct BetweenAll<'h, 'n> {
+	haystack: &'h RawStr,
+	open: Finder<'n>,
+	close: Finder<'n>,
+	pos: Option<usize>,
+}
+
+impl<'h> Iterator for BetweenAll<'h, '_> {
+	type Item = &'h RawStr;
+
+	fn next(&mut self) -> Option<&'h RawStr> {
+		let pos = self.pos?;
+		let section = self.open.find_from(self.haystack, pos).and_then(|open| {
+			let start = open + self.open.needle().len();
+			let end = self.close.find_from(self.haystack, start)?;
+			Some((start, end))
+		});
+		let Some((start, end)) = section else {
+			self.pos = None;
+			return None;
+		};
+		// with empty delimiters, step forward so every position is visited once
+		let next = end + self.close.needle().len();
+		self.pos = if next > pos {
+			Some(next)
+		} else if next < self.haystack.len() {
+			Some(next + 1)
+		} else {
+			None
+		};
+		Some(RawStr::new(&self.haystack[start..end]))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let upper = self.pos.map(|pos| self.haystack.len() - pos + 1);
+		(0, Some(upper.unwrap_or(0)))
+	}
+}
+
+impl FusedIterator for BetweenAll<'_, '_> {}
@@ -0,0 +1,166 @@
+// rawstring::bits
+
+use std::iter::FusedIterator;
+
+use crate::{RawStr, RawString};
+
+/// The order in which bits are numbered within each byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BitOrder {
+	/// Bit 0 is the most significant bit of the first byte, as in network protocol diagrams.
+	#[default]
+	Msb0,
+	/// Bit 0 is the least significant bit of the first byte.
+	Lsb0,
+}
+
+impl BitOrder {
+	/// Returns the mask selecting bit `index % 8` of a byte.
+	#[inline]
+	const fn mask(self, index: usize) -> u8 {
+		let shift = (index % 8) as u32;
+		match self {
+			Self::Msb0 => 0x80 >> shift,
+			Self::Lsb0 => 0x01 << shift,
+		}
+	}
+}
+
+impl RawStr {
+	/// Returns an iterator over the bits of the [`RawStr`] in the given order.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{BitOrder, RawStr};
+	/// let raw = RawStr::new(&[0b1000_0001]);
+	/// let msb: Vec<_> = raw.bits(BitOrder::Msb0).take(2).collect();
+	/// assert_eq!(msb, [true, false]);
+	/// assert_eq!(raw.bits(BitOrder::Lsb0).rev().nth(1), Some(false));
+	/// assert_eq!(raw.bits(BitOrder::Lsb0).filter(|&bit| bit).count(), 2);
+	/// ```
+	#[inline]
+	pub fn bits(&self, order: BitOrder) -> Bits<'_> {
+		Bits { bytes: &self.0, order, front: 0, back: self.len() * 8 }
+	}
+
+	/// Returns bit `index` in the given order, or `None` if it is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{BitOrder, RawStr};
+	/// // the QR flag is the first bit of the third byte of a DNS header
+	/// let header = RawStr::new(&[0x12, 0x34, 0x81, 0x80]);
+	/// assert_eq!(header.get_bit(16, BitOrder::Msb0), Some(true));
+	/// assert_eq!(header.get_bit(32, BitOrder::Msb0), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn get_bit(&self, index: usize, order: BitOrder) -> Option<bool> {
+		self.get(index / 8).map(|&byte| byte & order.mask(index) != 0)
+	}
+
+	/// Sets bit `index` in the given order to `value`.
+	///
+	/// # Panics
+	/// Panics if `index` is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{BitOrder, RawStr};
+	/// let mut data = [0u8; 2];
+	/// let raw = RawStr::new_mut(&mut data);
+	/// raw.set_bit(9, BitOrder::Msb0, true);
+	/// raw.set_bit(0, BitOrder::Lsb0, true);
+	/// assert_eq!(data, [0b0000_0001, 0b0100_0000]);
+	/// ```
+	#[inline]
+	pub fn set_bit(&mut self, index: usize, order: BitOrder, value: bool) {
+		let mask = order.mask(index);
+		let byte = &mut self[index / 8];
+		if value {
+			*byte |= mask;
+		} else {
+			*byte &= !mask;
+		}
+	}
+}
+
+impl RawString {
+	/// Returns bit `index` in the given order, or `None` if it is out of bounds.
+	///
+	/// See [`RawStr::get_bit`].
+	#[inline]
+	#[must_use]
+	pub fn get_bit(&self, index: usize, order: BitOrder) -> Option<bool> {
+		self.as_ref().get_bit(index, order)
+	}
+
+	/// Sets bit `index` in the given order to `value`.
+	///
+	/// See [`RawStr::set_bit`].
+	///
+	/// # Panics
+	/// Panics if `index` is out of bounds.
+	#[inline]
+	pub fn set_bit(&mut self, index: usize, order: BitOrder, value: bool) {
+		self.as_mut().set_bit(index, order, value);
+	}
+}
+
+/// An iterator over the bits of a [`RawStr`].
+///
+/// Created by [`RawStr::bits`].
+#[derive(Debug, Clone)]
+pub struct Bits<'a> {
+	bytes: &'a [u8],
+	order: BitOrder,
+	front: usize,
+	back: usize,
+}
+
+impl Bits<'_> {
+	#[inline]
+	fn bit(&self, index: usize) -> bool {
+		self.bytes[index / 8] & self.order.mask(index) != 0
+	}
+}
+
+impl Iterator for Bits<'_> {
+	type Item = bool;
+
+	#[inline]
+	fn next(&mut self) -> Option<bool> {
+		if self.front == self.back {
+			return None;
+		}
+		self.front += 1;
+		Some(self.bit(self.front - 1))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<bool> {
+		self.front = self.front.saturating_add(n).min(self.back);
+		self.next()
+	}
+}
+
+impl DoubleEndedIterator for Bits<'_> {
+	#[inline]
+	fn next_back(&mut self) -> Option<bool> {
+		if self.front == self.back {
+			return None;
+		}
+		self.back -= 1;
+		Some(self.bit(self.back))
+	}
+}
+
+impl ExactSizeIterator for Bits<'_> {}
+
+impl FusedIterator for Bits<'_> {}
@@ -0,0 +1,163 @@
+// rawstring::bom
+
+use std::borrow::Cow;
+
+use crate::{RawStr, RawString};
+
+/// A Unicode byte order mark, identifying the encoding of the data that follows it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bom {
+	/// `EF BB BF`
+	Utf8,
+	/// `FF FE`
+	Utf16Le,
+	/// `FE FF`
+	Utf16Be,
+	/// `FF FE 00 00`
+	Utf32Le,
+	/// `00 00 FE FF`
+	Utf32Be,
+}
+
+impl Bom {
+	/// Returns the bytes of the byte order mark.
+	#[inline]
+	#[must_use]
+	pub const fn as_bytes(self) -> &'static [u8] {
+		match self {
+			Self::Utf8 => b"\xEF\xBB\xBF",
+			Self::Utf16Le => b"\xFF\xFE",
+			Self::Utf16Be => b"\xFE\xFF",
+			Self::Utf32Le => b"\xFF\xFE\x00\x00",
+			Self::Utf32Be => b"\x00\x00\xFE\xFF",
+		}
+	}
+}
+
+impl RawStr {
+	/// Detects a byte order mark at the start of the [`RawStr`].
+	///
+	/// `FF FE 00 00` is reported as [`Bom::Utf32Le`] rather than [`Bom::Utf16Le`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{Bom, RawStr};
+	/// assert_eq!(RawStr::new(b"\xEF\xBB\xBFhi").detect_bom(), Some(Bom::Utf8));
+	/// assert_eq!(RawStr::new(b"\xFE\xFF\x00h").detect_bom(), Some(Bom::Utf16Be));
+	/// assert_eq!(RawStr::new("hi").detect_bom(), None);
+	/// ```
+	#[must_use]
+	pub fn detect_bom(&self) -> Option<Bom> {
+		[Bom::Utf8, Bom::Utf32Le, Bom::Utf32Be, Bom::Utf16Le, Bom::Utf16Be]
+			.into_iter()
+			.find(|bom| self.starts_with(bom.as_bytes()))
+	}
+
+	/// Returns the [`RawStr`] without its leading byte order mark, if any.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"\xEF\xBB\xBFhi").strip_bom(), "hi");
+	/// assert_eq!(RawStr::new("hi").strip_bom(), "hi");
+	/// ```
+	#[must_use]
+	pub fn strip_bom(&self) -> &RawStr {
+		match self.detect_bom() {
+			Some(bom) => RawStr::new(&self[bom.as_bytes().len()..]),
+			None => self,
+		}
+	}
+
+	/// Decodes the [`RawStr`] according to its byte order mark.
+	///
+	/// A UTF-8 byte order mark is stripped without copying. UTF-16 and UTF-32 data is
+	/// transcoded to UTF-8 with [`RawString::from_utf16le`] and friends, replacing unpaired
+	/// surrogates, invalid scalar values and trailing partial code units with `�`.
+	/// Data without a byte order mark is returned unchanged.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(*RawStr::new(b"\xEF\xBB\xBFh\xFF").decode_bom(), b"h\xFF");
+	/// assert_eq!(*RawStr::new(b"\xFF\xFEh\x00i\x00").decode_bom(), "hi");
+	/// assert_eq!(*RawStr::new(b"\x00\x00\xFE\xFF\x00\x01\xF6\x00").decode_bom(), "😀");
+	/// ```
+	#[must_use]
+	pub fn decode_bom(&self) -> Cow<'_, RawStr> {
+		let Some(bom) = self.detect_bom() else {
+			return Cow::Borrowed(self);
+		};
+		let data = &self[bom.as_bytes().len()..];
+		match bom {
+			Bom::Utf8 => Cow::Borrowed(RawStr::new(data)),
+			Bom::Utf16Le => Cow::Owned(RawString::from_utf16le(data)),
+			Bom::Utf16Be => Cow::Owned(RawString::from_utf16be(data)),
+			Bom::Utf32Le => Cow::Owned(RawString::from_utf32le(data)),
+			Bom::Utf32Be => Cow::Owned(RawString::from_utf32be(data)),
+		}
+	}
+}
+
+impl RawString {
+	/// Lossily decodes little-endian UTF-16 bytes into a [`RawString`] containing UTF-8.
+	///
+	/// Unpaired surrogates and a trailing odd byte are replaced with `�`.
+	/// Any byte order mark is kept as `U+FEFF`; see [`RawStr::decode_bom`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// assert_eq!(RawString::from_utf16le(b"h\x00i\x00\x00\xD8"), RawString::from("hi�"));
+	/// ```
+	#[must_use]
+	pub fn from_utf16le(bytes: &[u8]) -> Self {
+		Self::from_utf16_units(bytes, u16::from_le_bytes)
+	}
+
+	/// Lossily decodes big-endian UTF-16 bytes into a [`RawString`] containing UTF-8.
+	///
+	/// See [`RawString::from_utf16le`].
+	#[must_use]
+	pub fn from_utf16be(bytes: &[u8]) -> Self {
+		Self::from_utf16_units(bytes, u16::from_be_bytes)
+	}
+
+	/// Lossily decodes little-endian UTF-32 bytes into a [`RawString`] containing UTF-8.
+	///
+	/// Invalid scalar values and trailing partial code units are replaced with `�`.
+	#[must_use]
+	pub fn from_utf32le(bytes: &[u8]) -> Self {
+		Self::from_utf32_units(bytes, u32::from_le_bytes)
+	}
+
+	/// Lossily decodes big-endian UTF-32 bytes into a [`RawString`] containing UTF-8.
+	///
+	/// See [`RawString::from_utf32le`].
+	#[must_use]
+	pub fn from_utf32be(bytes: &[u8]) -> Self {
+		Self::from_utf32_units(bytes, u32::from_be_bytes)
+	}
+
+	fn from_utf16_units(bytes: &[u8], unit: fn([u8; 2]) -> u16) -> Self {
+		let (units, rest) = bytes.as_chunks::<2>();
+		let mut decoded: String = char::decode_utf16(units.iter().map(|&b| unit(b)))
+			.map(|c| c.unwrap_or(crate::UNICODE_REPLACEMENT_CHARACTER))
+			.collect();
+		if !rest.is_empty() {
+			decoded.push(crate::UNICODE_REPLACEMENT_CHARACTER);
+		}
+		Self::from(decoded)
+	}
+
+	fn from_utf32_units(bytes: &[u8], unit: fn([u8; 4]) -> u32) -> Self {
+		let (units, rest) = bytes.as_chunks::<4>();
+		let mut decoded: String = units.iter()
+			.map(|&b| char::from_u32(unit(b)).unwrap_or(crate::UNICODE_REPLACEMENT_CHARACTER))
+			.collect();
+		if !rest.is_empty() {
+			decoded.push(crate::UNICODE_REPLACEMENT_CHARACTER);
+		}
+		Self::from(decoded)
+	}
+}
@@ -0,0 +1,199 @@
+// rawstring::case
+
+use std::char::{ToLowercase, ToUppercase};
+
+use crate::{RawStr, RawString, utf8};
+
+impl RawStr {
+	/// Returns the uppercase equivalent of the [`RawStr`] as a new [`RawString`].
+	///
+	/// Valid UTF-8 is converted with [`char::to_uppercase`]; invalid bytes are copied unchanged.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// assert_eq!(RawStr::new(b"stra\xDFe \xFF").to_uppercase(), RawString::from(b"STRA\xDFE \xFF"));
+	/// assert_eq!(RawStr::new("straße").to_uppercase(), RawString::from("STRASSE"));
+	/// ```
+	#[must_use]
+	pub fn to_uppercase(&self) -> RawString {
+		let mut buf = RawString::new();
+		self.to_uppercase_into(&mut buf);
+		buf
+	}
+
+	/// Writes the uppercase equivalent of the [`RawStr`] into `buf`, clearing it first.
+	///
+	/// This reuses the allocation of `buf`. See [`RawStr::to_uppercase`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// let mut buf = RawString::new();
+	/// for word in ["one", "two"] {
+	///     RawStr::new(word).to_uppercase_into(&mut buf);
+	///     assert_eq!(buf, RawString::from(word.to_uppercase()));
+	/// }
+	/// ```
+	pub fn to_uppercase_into(&self, buf: &mut RawString) {
+		self.map_chars_into(buf, |c, _| c.to_uppercase());
+	}
+
+	/// Returns the lowercase equivalent of the [`RawStr`] as a new [`RawString`].
+	///
+	/// Valid UTF-8 is converted with [`char::to_lowercase`]; invalid bytes are copied unchanged.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// assert_eq!(RawStr::new(b"\xFFHELLO").to_lowercase(), RawString::from(b"\xFFhello"));
+	/// ```
+	#[must_use]
+	pub fn to_lowercase(&self) -> RawString {
+		let mut buf = RawString::new();
+		self.to_lowercase_into(&mut buf);
+		buf
+	}
+
+	/// Writes the lowercase equivalent of the [`RawStr`] into `buf`, clearing it first.
+	///
+	/// This reuses the allocation of `buf`. See [`RawStr::to_lowercase`].
+	pub fn to_lowercase_into(&self, buf: &mut RawString) {
+		self.map_chars_into(buf, |c, _| c.to_lowercase());
+	}
+
+	/// Returns the [`RawStr`] with its first character converted to title case
+	/// as a new [`RawString`], leaving the rest unchanged.
+	///
+	/// Nothing is changed if the [`RawStr`] starts with invalid UTF-8.
+	/// See [`RawStr::to_title_case`] for how characters are title-cased.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// assert_eq!(RawStr::new(b"\xC3\xA9t\xC3\xA9 \xFF").capitalize(), RawString::from(b"\xC3\x89t\xC3\xA9 \xFF"));
+	/// assert_eq!(RawStr::new("ǆungla").capitalize(), RawString::from("ǅungla"));
+	/// assert_eq!(RawStr::new(b"\xFFabc").capitalize(), RawString::from(b"\xFFabc"));
+	/// ```
+	#[must_use]
+	pub fn capitalize(&self) -> RawString {
+		let mut buf = RawString::from(Vec::with_capacity(self.len()));
+		let rest = match utf8::decode_first(self) {
+			Some(Ok(c)) => {
+				for mapped in to_titlecase(c) {
+					buf.extend_from_slice(mapped.encode_utf8(&mut [0; 4]).as_bytes());
+				}
+				&self[c.len_utf8()..]
+			}
+			_ => self,
+		};
+		buf.extend_from_slice(rest);
+		buf
+	}
+
+	/// Returns the title case equivalent of the [`RawStr`] as a new [`RawString`].
+	///
+	/// The first letter or digit of each word is converted to title case and the rest of the
+	/// word to lowercase. A word starts at an alphanumeric character that does not follow
+	/// another alphanumeric character or an apostrophe, so invalid bytes, spaces and other
+	/// punctuation separate words. Invalid bytes are copied unchanged.
+	///
+	/// Title case is the same as [`char::to_uppercase`], except for the Latin digraphs such as
+	/// `ǆ` that have a dedicated title case form.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// let raw = RawStr::new(b"they're HERE\xFFnow, well-known");
+	/// assert_eq!(raw.to_title_case(), RawString::from(b"They're Here\xFFNow, Well-Known"));
+	/// assert_eq!(RawStr::new("3RD ÉCOLE").to_title_case(), RawString::from("3rd École"));
+	/// ```
+	#[must_use]
+	pub fn to_title_case(&self) -> RawString {
+		let mut buf = RawString::new();
+		self.map_chars_into(&mut buf, |c, prev| match prev {
+			Some(prev) if prev.is_alphanumeric() || matches!(prev, '\'' | '’') => CaseMapping::Lower(c.to_lowercase()),
+			_ if c.is_alphanumeric() => to_titlecase(c),
+			_ => CaseMapping::One(Some(c)),
+		});
+		buf
+	}
+
+	/// Returns the [`RawStr`] with uppercase characters converted to lowercase and lowercase
+	/// characters converted to uppercase, as a new [`RawString`].
+	///
+	/// Other characters and invalid bytes are copied unchanged.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// assert_eq!(RawStr::new(b"Hello \xFFW\xC3\xB6rld").swap_case(), RawString::from(b"hELLO \xFFw\xC3\x96RLD"));
+	/// ```
+	#[must_use]
+	pub fn swap_case(&self) -> RawString {
+		let mut buf = RawString::new();
+		self.map_chars_into(&mut buf, |c, _| {
+			if c.is_uppercase() {
+				CaseMapping::Lower(c.to_lowercase())
+			} else if c.is_lowercase() {
+				CaseMapping::Upper(c.to_uppercase())
+			} else {
+				CaseMapping::One(Some(c))
+			}
+		});
+		buf
+	}
+
+	/// Clears `buf` and writes the result of mapping each valid character of the [`RawStr`]
+	/// through `f`, copying invalid bytes unchanged.
+	///
+	/// `f` is also passed the previous character, or `None` at the start and after invalid bytes.
+	fn map_chars_into<I>(&self, buf: &mut RawString, mut f: impl FnMut(char, Option<char>) -> I)
+	where
+		I: Iterator<Item = char>
+	{
+		buf.clear();
+		buf.reserve(self.len());
+		for chunk in self.utf8_chunks() {
+			let mut prev = None;
+			for c in chunk.valid().chars() {
+				for mapped in f(c, prev) {
+					buf.extend_from_slice(mapped.encode_utf8(&mut [0; 4]).as_bytes());
+				}
+				prev = Some(c);
+			}
+			buf.extend_from_slice(chunk.invalid());
+		}
+	}
+}
+
+/// The characters a character maps to when its case is changed.
+enum CaseMapping {
+	One(Option<char>),
+	Upper(ToUppercase),
+	Lower(ToLowercase),
+}
+
+impl Iterator for CaseMapping {
+	type Item = char;
+
+	#[inline]
+	fn next(&mut self) -> Option<char> {
+		match self {
+			Self::One(c) => c.take(),
+			Self::Upper(iter) => iter.next(),
+			Self::Lower(iter) => iter.next(),
+		}
+	}
+}
+
+/// Converts `c` to title case.
+fn to_titlecase(c: char) -> CaseMapping {
+	match c {
+		'\u{01C4}'..='\u{01C6}' => CaseMapping::One(Some('\u{01C5}')),
+		'\u{01C7}'..='\u{01C9}' => CaseMapping::One(Some('\u{01C8}')),
+		'\u{01CA}'..='\u{01CC}' => CaseMapping::One(Some('\u{01CB}')),
+		'\u{01F1}'..='\u{01F3}' => CaseMapping::One(Some('\u{01F2}')),
+		_ => CaseMapping::Upper(c.to_uppercase()),
+	}
+}
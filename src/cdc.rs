@@ -0,0 +1,170 @@
+// rawstring::cdc
+
+use std::iter::FusedIterator;
+
+use crate::RawStr;
+
+/// Chunk size limits for [`RawStr::cdc_chunks`].
+///
+/// Created by [`CdcOptions::new`], which checks that `0 < min_size <= avg_size <= max_size`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CdcOptions {
+	min_size: usize,
+	avg_size: usize,
+	max_size: usize,
+}
+
+impl CdcOptions {
+	/// Creates chunking options with the given sizes.
+	///
+	/// # Panics
+	/// Panics unless `0 < min_size <= avg_size <= max_size`.
+	#[inline]
+	#[must_use]
+	pub const fn new(min_size: usize, avg_size: usize, max_size: usize) -> Self {
+		assert!(0 < min_size && min_size <= avg_size && avg_size <= max_size, "invalid chunk sizes");
+		Self { min_size, avg_size, max_size }
+	}
+
+	/// Returns the minimum size of a chunk. Only the last chunk may be smaller.
+	#[inline]
+	#[must_use]
+	pub const fn min_size(&self) -> usize {
+		self.min_size
+	}
+
+	/// Returns the desired average size of a chunk, which is rounded down to a power of two.
+	#[inline]
+	#[must_use]
+	pub const fn avg_size(&self) -> usize {
+		self.avg_size
+	}
+
+	/// Returns the maximum size of a chunk.
+	#[inline]
+	#[must_use]
+	pub const fn max_size(&self) -> usize {
+		self.max_size
+	}
+}
+
+impl Default for CdcOptions {
+	/// 2 KiB minimum, 8 KiB average and 64 KiB maximum chunk sizes.
+	#[inline]
+	fn default() -> Self {
+		Self::new(2 * 1024, 8 * 1024, 64 * 1024)
+	}
+}
+
+impl RawStr {
+	/// Splits the [`RawStr`] into content-defined chunks.
+	///
+	/// Chunk boundaries are chosen with a Gear rolling hash using FastCDC's normalized chunking,
+	/// so they depend only on nearby content: inserting or removing bytes only changes
+	/// the chunks around the edit, which makes the chunks suitable for deduplication.
+	/// Boundaries are deterministic and do not depend on the platform.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{CdcOptions, RawStr};
+	/// let data: Vec<u8> = (0..100_000u32).map(|i| (i.wrapping_mul(2654435761) >> 13) as u8).collect();
+	/// let options = CdcOptions::new(256, 1024, 4096);
+	/// let chunks: Vec<_> = RawStr::new(&data).cdc_chunks(options).collect();
+	/// assert!(chunks.iter().all(|chunk| chunk.len() <= 4096));
+	/// assert!(chunks.len() > 20);
+	/// assert_eq!(chunks.iter().map(|chunk| chunk.len()).sum::<usize>(), data.len());
+	///
+	/// // an edit at the front leaves later chunk boundaries intact
+	/// let edited = [b"prefix".as_slice(), &data].concat();
+	/// let edited_chunks: Vec<_> = RawStr::new(&edited).cdc_chunks(options).collect();
+	/// assert_eq!(chunks.last(), edited_chunks.last());
+	/// ```
+	#[inline]
+	pub fn cdc_chunks(&self, options: CdcOptions) -> CdcChunks<'_> {
+		let bits = options.avg_size.ilog2();
+		CdcChunks {
+			rest: &self.0,
+			options,
+			// more bits must match before the average size, fewer after it
+			mask_small: mask(bits + 1),
+			mask_large: mask(bits.saturating_sub(1)),
+		}
+	}
+}
+
+/// Returns a mask with `bits` bits set, spread over the high half of the hash.
+const fn mask(bits: u32) -> u64 {
+	let bits = if bits > 32 { 32 } else { bits };
+	if bits == 0 { 0 } else { (u64::MAX >> (64 - bits)) << (64 - bits) }
+}
+
+/// An iterator over the content-defined chunks of a [`RawStr`].
+///
+/// Created by [`RawStr::cdc_chunks`].
+#[derive(Debug, Clone)]
+pub struct CdcChunks<'a> {
+	rest: &'a [u8],
+	options: CdcOptions,
+	mask_small: u64,
+	mask_large: u64,
+}
+
+impl<'a> CdcChunks<'a> {
+	/// Returns the length of the next chunk of `self.rest`.
+	fn cut_point(&self) -> usize {
+		let CdcOptions { min_size, avg_size, max_size } = self.options;
+		let len = self.rest.len();
+		if len <= min_size {
+			return len;
+		}
+		let max = len.min(max_size);
+		let normal = max.min(avg_size);
+
+		let mut hash = 0u64;
+		for (i, &byte) in self.rest.iter().enumerate().take(max).skip(min_size) {
+			hash = (hash << 1).wrapping_add(GEAR[usize::from(byte)]);
+			let mask = if i < normal { self.mask_small } else { self.mask_large };
+			if hash & mask == 0 {
+				return i + 1;
+			}
+		}
+		max
+	}
+}
+
+impl<'a> Iterator for CdcChunks<'a> {
+	type Item = &'a RawStr;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.rest.is_empty() {
+			return None;
+		}
+		let (chunk, rest) = self.rest.split_at(self.cut_point());
+		self.rest = rest;
+		Some(RawStr::new(chunk))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.rest.len();
+		(len.div_ceil(self.options.max_size), Some(len.div_ceil(self.options.min_size)))
+	}
+}
+
+impl FusedIterator for CdcChunks<'_> {}
+
+/// The Gear hash table: 256 pseudo-random values generated with SplitMix64.
+static GEAR: [u64; 256] = {
+	let mut table = [0; 256];
+	let mut state: u64 = 0x5261_7753_7472_696E;
+	let mut i = 0;
+	while i < 256 {
+		state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+		let mut z = state;
+		z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+		table[i] = z ^ (z >> 31);
+		i += 1;
+	}
+	table
+};
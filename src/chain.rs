@@ -0,0 +1,214 @@
+// rawstring::chain
+
+use std::{cmp::Ordering, fmt, iter::FusedIterator, slice};
+
+use crate::{DecodeUtf8Ext, RawStr, RawString};
+
+impl RawStr {
+	/// Returns a view of the [`RawStr`] followed by `other`, without copying either.
+	///
+	/// More pieces can be added with [`RawStrChain::chain`]. The view can be displayed,
+	/// compared and iterated over as if the pieces were one [`RawStr`], and collected into
+	/// a [`RawString`] when a contiguous copy is needed.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// let header = RawStr::new("len=3\n");
+	/// let payload = RawStr::new(b"\xE2\x82");
+	/// let message = header.chain(payload).chain(b"\xAC");
+	///
+	/// assert_eq!(message.len(), 9);
+	/// assert_eq!(message, "len=3\n€");
+	/// assert_eq!(message.to_string(), "len=3\n€");
+	///
+	/// let joined: RawString = message.bytes().collect();
+	/// assert_eq!(joined, RawString::from("len=3\n€"));
+	/// ```
+	#[inline]
+	pub fn chain<'a, B>(&'a self, other: &'a B) -> RawStrChain<'a>
+	where
+		B: ?Sized + AsRef<[u8]>,
+	{
+		RawStrChain { head: [self, RawStr::new(other.as_ref())], rest: Vec::new() }
+	}
+}
+
+/// A view of several [`RawStr`] pieces as one, without copying them.
+///
+/// The first two pieces are stored inline, so chaining two [`RawStr`]s does not allocate.
+///
+/// Created by [`RawStr::chain`].
+#[derive(Clone)]
+pub struct RawStrChain<'a> {
+	head: [&'a RawStr; 2],
+	rest: Vec<&'a RawStr>,
+}
+
+impl<'a> RawStrChain<'a> {
+	/// Appends `other` to the view.
+	#[inline]
+	#[must_use]
+	pub fn chain<B>(mut self, other: &'a B) -> Self
+	where
+		B: ?Sized + AsRef<[u8]>,
+	{
+		self.rest.push(RawStr::new(other.as_ref()));
+		self
+	}
+
+	/// Returns an iterator over the pieces of the view.
+	#[inline]
+	pub fn pieces(&self) -> impl DoubleEndedIterator<Item = &'a RawStr> + '_ {
+		self.head.iter().chain(&self.rest).copied()
+	}
+
+	/// Returns the total length of the pieces in bytes.
+	#[inline]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.pieces().map(|piece| piece.len()).sum()
+	}
+
+	/// Returns `true` if every piece is empty.
+	#[inline]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.pieces().all(|piece| piece.is_empty())
+	}
+
+	/// Returns an iterator over the bytes of the pieces.
+	#[inline]
+	pub fn bytes(&self) -> ChainBytes<'a, '_> {
+		ChainBytes { chain: self, index: 1, current: self.head[0].0.iter(), remaining: self.len() }
+	}
+
+	/// Copies the pieces into a new [`RawString`].
+	#[inline]
+	#[must_use]
+	pub fn to_raw_string(&self) -> RawString {
+		self.pieces().collect()
+	}
+
+	#[inline]
+	fn piece(&self, index: usize) -> Option<&'a RawStr> {
+		match index {
+			0 | 1 => Some(self.head[index]),
+			_ => self.rest.get(index - 2).copied(),
+		}
+	}
+}
+
+/// Formats the pieces as one [`RawStr`], so a character split between pieces is shown whole.
+///
+/// With the `strict` feature in debug builds, panics if the pieces together are not valid UTF-8.
+impl fmt::Display for RawStrChain<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let chars = self.bytes().decode_utf8().map(|c| {
+			c.unwrap_or_else(|err| {
+				crate::strict::replaced_invalid(err.position(), "Display");
+				crate::UNICODE_REPLACEMENT_CHARACTER
+			})
+		});
+		if f.width().is_some() {
+			let text: String = chars.collect();
+			return f.pad(&text);
+		}
+		for c in chars {
+			fmt::Write::write_char(f, c)?;
+		}
+		Ok(())
+	}
+}
+
+/// Formats the pieces as one [`RawStr`]. This copies the pieces into a temporary buffer.
+impl fmt::Debug for RawStrChain<'_> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(self.to_raw_string().as_ref(), f)
+	}
+}
+
+impl<T: ?Sized + AsRef<[u8]>> PartialEq<T> for RawStrChain<'_> {
+	#[inline]
+	fn eq(&self, other: &T) -> bool {
+		let other = other.as_ref();
+		self.len() == other.len() && self.bytes().eq(other.iter().copied())
+	}
+}
+
+impl PartialEq for RawStrChain<'_> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.len() == other.len() && self.bytes().eq(other.bytes())
+	}
+}
+
+impl Eq for RawStrChain<'_> {}
+
+impl PartialOrd for RawStrChain<'_> {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for RawStrChain<'_> {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.bytes().cmp(other.bytes())
+	}
+}
+
+impl<'a, 'b> IntoIterator for &'b RawStrChain<'a> {
+	type Item = u8;
+	type IntoIter = ChainBytes<'a, 'b>;
+
+	#[inline]
+	fn into_iter(self) -> ChainBytes<'a, 'b> {
+		self.bytes()
+	}
+}
+
+impl From<RawStrChain<'_>> for Vec<u8> {
+	#[inline]
+	fn from(chain: RawStrChain<'_>) -> Self {
+		chain.to_raw_string().0
+	}
+}
+
+/// An iterator over the bytes of a [`RawStrChain`].
+///
+/// Created by [`RawStrChain::bytes`].
+#[derive(Debug, Clone)]
+pub struct ChainBytes<'a, 'b> {
+	chain: &'b RawStrChain<'a>,
+	index: usize,
+	current: slice::Iter<'a, u8>,
+	remaining: usize,
+}
+
+impl Iterator for ChainBytes<'_, '_> {
+	type Item = u8;
+
+	#[inline]
+	fn next(&mut self) -> Option<u8> {
+		loop {
+			if let Some(&byte) = self.current.next() {
+				self.remaining -= 1;
+				return Some(byte);
+			}
+			self.current = self.chain.piece(self.index)?.0.iter();
+			self.index += 1;
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl ExactSizeIterator for ChainBytes<'_, '_> {}
+
+impl FusedIterator for ChainBytes<'_, '_> {}
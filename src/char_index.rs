@@ -0,0 +1,252 @@
+// rawstring::char_index
+
+use crate::{RawStr, utf8};
+
+/// The number of characters between the checkpoints of a [`CharIndex`].
+const STRIDE: usize = 64;
+
+impl RawStr {
+	/// Returns the character at character index `n`, or the invalid sequence there,
+	/// or `None` if there are not that many.
+	///
+	/// Characters are counted as in [`RawStr::char_ranges`], so each invalid sequence counts
+	/// as one character, just as it is displayed as one `�`. This takes `O(n)` time;
+	/// use a [`CharIndex`] for repeated lookups in the same text.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"h\xC3\xA9\xFF\xFEllo");
+	/// assert_eq!(raw.char_at(1), Some(Ok('\u{e9}')));
+	/// assert_eq!(raw.char_at(2), Some(Err(RawStr::new(b"\xFF"))));
+	/// assert_eq!(raw.char_at(4), Some(Ok('l')));
+	/// assert_eq!(raw.char_at(8), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn char_at(&self, n: usize) -> Option<Result<char, &RawStr>> {
+		self.char_ranges().nth(n).map(|(_, unit)| unit)
+	}
+
+	/// Returns the byte index at which character `n` starts, or `None` if there are
+	/// not that many characters.
+	///
+	/// The number of characters maps to the length of the [`RawStr`]. Characters are counted
+	/// as in [`RawStr::char_at`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xE2\x82\xAC1\xFF");
+	/// assert_eq!(raw.byte_index_of_char(1), Some(3));
+	/// assert_eq!(raw.byte_index_of_char(3), Some(5));
+	/// assert_eq!(raw.byte_index_of_char(4), None);
+	/// ```
+	#[must_use]
+	pub fn byte_index_of_char(&self, n: usize) -> Option<usize> {
+		let mut ranges = self.char_ranges();
+		match n.checked_sub(1) {
+			None => Some(0),
+			Some(last) => ranges.nth(last).map(|(range, _)| range.end),
+		}
+	}
+
+	/// Returns the number of characters before byte index `i`, or `None` if `i` is not
+	/// the start of a character or the end of the [`RawStr`].
+	///
+	/// This is the inverse of [`RawStr::byte_index_of_char`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xE2\x82\xAC1\xFF");
+	/// assert_eq!(raw.char_index_of_byte(3), Some(1));
+	/// assert_eq!(raw.char_index_of_byte(5), Some(3));
+	/// assert_eq!(raw.char_index_of_byte(1), None);
+	/// ```
+	#[must_use]
+	pub fn char_index_of_byte(&self, i: usize) -> Option<usize> {
+		char_index_from(self, 0, i)
+	}
+
+	/// Returns the length of the [`RawStr`] in UTF-16 code units, as used for positions
+	/// by the Language Server Protocol and JavaScript.
+	///
+	/// Each invalid sequence counts as one code unit, the length of the `U+FFFD` it is
+	/// displayed as, so this is the UTF-16 length of the lossily converted text.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("a\u{e9}\u{1F600}").utf16_len(), 4);
+	/// assert_eq!(RawStr::new(b"a\xF0\x9F\xFFb").utf16_len(), 4);
+	/// ```
+	#[must_use]
+	pub fn utf16_len(&self) -> usize {
+		self.char_ranges().map(|(_, unit)| unit_len_utf16(unit)).sum()
+	}
+
+	/// Converts byte offset `i` to an offset in UTF-16 code units, or returns `None` if `i` is
+	/// not the start of a character or the end of the [`RawStr`].
+	///
+	/// Invalid sequences count as one code unit, as in [`RawStr::utf16_len`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let line = RawStr::new(b"\xFF\xFElet \xF0\x9F\x98\x80 = 1;");
+	/// assert_eq!(line.byte_offset_to_utf16_offset(6), Some(6));
+	/// assert_eq!(line.byte_offset_to_utf16_offset(10), Some(8));
+	/// assert_eq!(line.byte_offset_to_utf16_offset(7), None);
+	/// ```
+	#[must_use]
+	pub fn byte_offset_to_utf16_offset(&self, i: usize) -> Option<usize> {
+		if i > self.len() {
+			return None;
+		}
+		let mut utf16 = 0;
+		for (range, unit) in self.char_ranges() {
+			if range.start >= i {
+				return (range.start == i).then_some(utf16);
+			}
+			utf16 += unit_len_utf16(unit);
+		}
+		(i == self.len()).then_some(utf16)
+	}
+
+	/// Converts an offset in UTF-16 code units to a byte offset, or returns `None` if it falls
+	/// between the two code units of a surrogate pair or past the end of the [`RawStr`].
+	///
+	/// This is the inverse of [`RawStr::byte_offset_to_utf16_offset`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let line = RawStr::new(b"\xFF\xFElet \xF0\x9F\x98\x80 = 1;");
+	/// assert_eq!(line.utf16_offset_to_byte_offset(6), Some(6));
+	/// assert_eq!(line.utf16_offset_to_byte_offset(8), Some(10));
+	/// assert_eq!(line.utf16_offset_to_byte_offset(7), None);
+	/// ```
+	#[must_use]
+	pub fn utf16_offset_to_byte_offset(&self, utf16: usize) -> Option<usize> {
+		let mut count = 0;
+		for (range, unit) in self.char_ranges() {
+			if count >= utf16 {
+				return (count == utf16).then_some(range.start);
+			}
+			count += unit_len_utf16(unit);
+		}
+		(count == utf16).then_some(self.len())
+	}
+}
+
+/// An index over a [`RawStr`] for converting between character and byte indices
+/// without scanning from the start each time.
+///
+/// The index stores the byte index of every 64th character, so each conversion scans at most
+/// 64 characters. Characters are counted as in [`RawStr::char_at`]. Like [`LineIndex`](crate::LineIndex),
+/// the index does not borrow the text it was built from, so the text must be passed to each lookup.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{CharIndex, RawStr};
+/// let text = "\u{e9}".repeat(100) + "x";
+/// let text = RawStr::new(&text);
+/// let index = CharIndex::new(text);
+/// assert_eq!(index.char_count(), 101);
+/// assert_eq!(index.byte_index_of_char(text, 100), Some(200));
+/// assert_eq!(index.char_index_of_byte(text, 130), Some(65));
+/// assert_eq!(index.char_index_of_byte(text, 131), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CharIndex {
+	/// Byte index of every `STRIDE`th character, starting with `0`.
+	checkpoints: Vec<usize>,
+	/// Number of characters in the indexed text.
+	chars: usize,
+	/// Length of the indexed text in bytes.
+	len: usize,
+}
+
+impl CharIndex {
+	/// Builds a [`CharIndex`] for the given text.
+	#[must_use]
+	pub fn new(text: &RawStr) -> Self {
+		let mut checkpoints = Vec::with_capacity(text.len() / STRIDE + 1);
+		let mut chars = 0;
+		for (range, _) in text.char_ranges() {
+			if chars % STRIDE == 0 {
+				checkpoints.push(range.start);
+			}
+			chars += 1;
+		}
+		if checkpoints.is_empty() {
+			checkpoints.push(0);
+		}
+		Self { checkpoints, chars, len: text.len() }
+	}
+
+	/// Returns the number of characters in the indexed text.
+	#[inline]
+	#[must_use]
+	pub fn char_count(&self) -> usize {
+		self.chars
+	}
+
+	/// Returns the length in bytes of the indexed text.
+	#[inline]
+	#[must_use]
+	pub fn text_len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns the byte index at which character `n` starts, or `None` if there are
+	/// not that many characters. See [`RawStr::byte_index_of_char`].
+	#[must_use]
+	pub fn byte_index_of_char(&self, text: &RawStr, n: usize) -> Option<usize> {
+		debug_assert_eq!(text.len(), self.len, "text does not match the char index");
+		if n >= self.chars {
+			return (n == self.chars).then_some(self.len);
+		}
+		let mut i = self.checkpoints[n / STRIDE];
+		for _ in 0..n % STRIDE {
+			i += utf8::unit_len(utf8::decode_first(&text[i..])?);
+		}
+		Some(i)
+	}
+
+	/// Returns the number of characters before byte index `i`, or `None` if `i` is not
+	/// the start of a character or the end of the text. See [`RawStr::char_index_of_byte`].
+	#[must_use]
+	pub fn char_index_of_byte(&self, text: &RawStr, i: usize) -> Option<usize> {
+		debug_assert_eq!(text.len(), self.len, "text does not match the char index");
+		let k = self.checkpoints.partition_point(|&start| start <= i) - 1;
+		char_index_from(text, self.checkpoints[k], i).map(|n| k * STRIDE + n)
+	}
+}
+
+impl From<&RawStr> for CharIndex {
+	#[inline]
+	fn from(text: &RawStr) -> Self {
+		Self::new(text)
+	}
+}
+
+/// Counts the characters from byte index `start`, which must be the start of a character,
+/// to byte index `i`, or returns `None` if `i` is not the start of a character or the end.
+fn char_index_from(text: &RawStr, start: usize, i: usize) -> Option<usize> {
+	let mut pos = start;
+	let mut n = 0;
+	while pos < i {
+		pos += utf8::unit_len(utf8::decode_first(&text[pos..])?);
+		n += 1;
+	}
+	(pos == i).then_some(n)
+}
+
+/// Returns the number of UTF-16 code units of a decoded unit, counting an invalid sequence
+/// as the one code unit of `U+FFFD`.
+#[inline]
+fn unit_len_utf16(unit: Result<char, &RawStr>) -> usize {
+	unit.map_or(1, char::len_utf16)
+}
@@ -0,0 +1,272 @@
+// rawstring::chars
+
+use std::{iter::FusedIterator, ops::Range};
+
+use crate::{RawStr, utf8};
+
+impl RawStr {
+	/// Returns an iterator over the characters of the [`RawStr`] and their byte ranges.
+	///
+	/// Each item is either a valid character, or an invalid sequence as a [`RawStr`].
+	/// Invalid data is split into the same sequences that are replaced by `�` when displaying
+	/// the [`RawStr`], so every byte belongs to exactly one item.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a\xE2\x82\xAC\xFF\xE2\x82");
+	/// let ranges: Vec<_> = raw.char_ranges().collect();
+	/// assert_eq!(ranges, [
+	///     (0..1, Ok('a')),
+	///     (1..4, Ok('€')),
+	///     (4..5, Err(RawStr::new(b"\xFF"))),
+	///     (5..7, Err(RawStr::new(b"\xE2\x82"))),
+	/// ]);
+	///
+	/// let last = raw.char_ranges().next_back();
+	/// assert_eq!(last, Some((5..7, Err(RawStr::new(b"\xE2\x82")))));
+	/// ```
+	#[inline]
+	pub fn char_ranges(&self) -> CharRanges<'_> {
+		CharRanges { bytes: &self.0, front: 0, back: self.len() }
+	}
+
+	/// Returns the first character of the [`RawStr`], or the invalid sequence it starts with,
+	/// or `None` if it is empty.
+	///
+	/// This is the first item of [`RawStr::char_ranges`] without its range.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("\u{e9}t\u{e9}").first_char(), Some(Ok('\u{e9}')));
+	/// assert_eq!(RawStr::new(b"\xE2\x82x").first_char(), Some(Err(RawStr::new(b"\xE2\x82"))));
+	/// assert_eq!(RawStr::new("").first_char(), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn first_char(&self) -> Option<Result<char, &RawStr>> {
+		utf8::decode_first(&self.0).map(|unit| unit.map_err(RawStr::new))
+	}
+
+	/// Returns the last character of the [`RawStr`], or the invalid sequence it ends with,
+	/// or `None` if it is empty.
+	///
+	/// This is the last item of [`RawStr::char_ranges`] without its range.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("t\u{e9}").last_char(), Some(Ok('\u{e9}')));
+	/// assert_eq!(RawStr::new(b"x\xF0\x9F\x92").last_char(), Some(Err(RawStr::new(b"\xF0\x9F\x92"))));
+	/// assert_eq!(RawStr::new(b"x\x9F\x92").last_char(), Some(Err(RawStr::new(b"\x92"))));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn last_char(&self) -> Option<Result<char, &RawStr>> {
+		utf8::decode_last(&self.0).map(|unit| unit.map_err(RawStr::new))
+	}
+
+	/// Returns an iterator over the byte indices at which the [`RawStr`] can be split
+	/// without breaking a valid character, including `0` and the length.
+	///
+	/// Every index inside a run of invalid UTF-8 is a split point, since splitting there
+	/// cannot break a character that was not there to begin with.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a\xE2\x82\xAC\xE2\x82");
+	/// let boundaries: Vec<_> = raw.char_boundaries().collect();
+	/// assert_eq!(boundaries, [0, 1, 4, 5, 6]);
+	/// assert_eq!(raw.char_boundaries().rev().nth(1), Some(5));
+	/// assert_eq!(RawStr::new("").char_boundaries().collect::<Vec<_>>(), [0]);
+	/// ```
+	#[inline]
+	pub fn char_boundaries(&self) -> CharBoundaries<'_> {
+		CharBoundaries { bytes: &self.0, front: 0, back: self.len(), finished: false }
+	}
+
+	/// Returns an iterator over pieces of the [`RawStr`] of at most `max_len` bytes,
+	/// split so that no valid character is broken.
+	///
+	/// Each piece is as long as possible. Runs of invalid UTF-8 may be split anywhere,
+	/// as with [`RawStr::char_boundaries`].
+	///
+	/// # Panics
+	/// Panics if `max_len` is less than 4, the length of the longest character.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"ab\xE2\x82\xACcd\xFF\xFF\xFFe");
+	/// let chunks: Vec<_> = raw.safe_chunks(4).collect();
+	/// assert_eq!(chunks, [
+	///     RawStr::new("ab"),
+	///     RawStr::new("\u{20ac}c"),
+	///     RawStr::new(b"d\xFF\xFF\xFF"),
+	///     RawStr::new("e"),
+	/// ]);
+	/// ```
+	#[inline]
+	pub fn safe_chunks(&self, max_len: usize) -> SafeChunks<'_> {
+		assert!(max_len >= 4, "max_len must be at least 4, got {max_len}");
+		SafeChunks { bytes: &self.0, max_len }
+	}
+}
+
+/// An iterator over the characters of a [`RawStr`] and their byte ranges.
+///
+/// Created by [`RawStr::char_ranges`].
+#[derive(Debug, Clone)]
+pub struct CharRanges<'a> {
+	bytes: &'a [u8],
+	front: usize,
+	back: usize,
+}
+
+impl<'a> CharRanges<'a> {
+	/// Returns the part of the [`RawStr`] that has not been iterated over yet.
+	#[inline]
+	#[must_use]
+	pub fn as_raw_str(&self) -> &'a RawStr {
+		RawStr::new(&self.bytes[self.front..self.back])
+	}
+}
+
+impl<'a> Iterator for CharRanges<'a> {
+	type Item = (Range<usize>, Result<char, &'a RawStr>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let unit = utf8::decode_first(&self.bytes[self.front..self.back])?;
+		let start = self.front;
+		self.front += utf8::unit_len(unit);
+		Some((start..self.front, unit.map_err(RawStr::new)))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len.div_ceil(4), Some(len))
+	}
+}
+
+impl DoubleEndedIterator for CharRanges<'_> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let unit = utf8::decode_last(&self.bytes[self.front..self.back])?;
+		let end = self.back;
+		self.back -= utf8::unit_len(unit);
+		Some((self.back..end, unit.map_err(RawStr::new)))
+	}
+}
+
+impl FusedIterator for CharRanges<'_> {}
+
+/// An iterator over the byte indices at which a [`RawStr`] can be split without breaking a character.
+///
+/// Created by [`RawStr::char_boundaries`].
+#[derive(Debug, Clone)]
+pub struct CharBoundaries<'a> {
+	bytes: &'a [u8],
+	front: usize,
+	back: usize,
+	finished: bool,
+}
+
+/// Returns the distance to the next split point past a decoded unit.
+#[inline]
+fn boundary_step(unit: Result<char, &[u8]>) -> usize {
+	match unit {
+		Ok(c) => c.len_utf8(),
+		Err(_) => 1,
+	}
+}
+
+impl Iterator for CharBoundaries<'_> {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<usize> {
+		if self.finished {
+			return None;
+		}
+		let index = self.front;
+		match utf8::decode_first(&self.bytes[self.front..self.back]) {
+			Some(unit) => self.front += boundary_step(unit),
+			None => self.finished = true,
+		}
+		Some(index)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.finished {
+			return (0, Some(0));
+		}
+		let len = self.back - self.front;
+		(len.div_ceil(4) + 1, Some(len + 1))
+	}
+}
+
+impl DoubleEndedIterator for CharBoundaries<'_> {
+	fn next_back(&mut self) -> Option<usize> {
+		if self.finished {
+			return None;
+		}
+		let index = self.back;
+		match utf8::decode_last(&self.bytes[self.front..self.back]) {
+			Some(unit) => self.back -= boundary_step(unit),
+			None => self.finished = true,
+		}
+		Some(index)
+	}
+}
+
+impl FusedIterator for CharBoundaries<'_> {}
+
+/// An iterator over pieces of a [`RawStr`] that do not break valid characters.
+///
+/// Created by [`RawStr::safe_chunks`].
+#[derive(Debug, Clone)]
+pub struct SafeChunks<'a> {
+	bytes: &'a [u8],
+	max_len: usize,
+}
+
+impl<'a> Iterator for SafeChunks<'a> {
+	type Item = &'a RawStr;
+
+	fn next(&mut self) -> Option<&'a RawStr> {
+		if self.bytes.is_empty() {
+			return None;
+		}
+		let mut end = self.max_len.min(self.bytes.len());
+		if end < self.bytes.len() {
+			// move the split back to the start of a valid character that straddles it
+			for start in (end.saturating_sub(3)..end).rev() {
+				match self.bytes[start] {
+					0x00..=0x7F => break,
+					0x80..=0xBF => continue,
+					_ => {}
+				}
+				if let Some(Ok(c)) = utf8::decode_first(&self.bytes[start..])
+					&& start + c.len_utf8() > end
+				{
+					end = start;
+				}
+				break;
+			}
+		}
+		let (chunk, rest) = self.bytes.split_at(end);
+		self.bytes = rest;
+		Some(RawStr::new(chunk))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		// every chunk but the last is at least `max_len - 3` bytes long
+		let len = self.bytes.len();
+		(len.div_ceil(self.max_len), Some(len.div_ceil(self.max_len - 3)))
+	}
+}
+
+impl FusedIterator for SafeChunks<'_> {}
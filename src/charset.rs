@@ -0,0 +1,234 @@
+// rawstring::charset
+
+use std::ops::RangeInclusive;
+
+use crate::{RawStr, RawString};
+
+/// A set of bytes, for checking that input only contains allowed bytes.
+///
+/// Sets are built with `const` methods, so they can be declared as constants,
+/// and the most common classes are provided as associated constants.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{CharSet, RawStr};
+/// const IDENT: CharSet = CharSet::ALNUM.with_bytes(b"_-");
+///
+/// assert!(RawStr::new("user_name-2").matches_charset(&IDENT));
+/// assert_eq!(RawStr::new(b"user name\xFF").position_of_violation(&IDENT), Some((4, b' ')));
+/// assert!(IDENT.contains(b'_') && !IDENT.contains(b'.'));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CharSet {
+	bits: [u64; 4],
+}
+
+impl CharSet {
+	/// The empty set.
+	pub const EMPTY: Self = Self { bits: [0; 4] };
+	/// All 256 bytes.
+	pub const ALL: Self = Self { bits: [u64::MAX; 4] };
+	/// ASCII digits: `0-9`.
+	pub const DIGIT: Self = Self::EMPTY.with_range(b'0'..=b'9');
+	/// ASCII letters: `A-Z` and `a-z`.
+	pub const ALPHA: Self = Self::EMPTY.with_range(b'A'..=b'Z').with_range(b'a'..=b'z');
+	/// ASCII letters and digits.
+	pub const ALNUM: Self = Self::ALPHA.union(Self::DIGIT);
+	/// ASCII hexadecimal digits: `0-9`, `A-F` and `a-f`.
+	pub const HEX: Self = Self::DIGIT.with_range(b'A'..=b'F').with_range(b'a'..=b'f');
+	/// ASCII whitespace, as defined by [`u8::is_ascii_whitespace`].
+	pub const WHITESPACE: Self = Self::EMPTY.with_bytes(b" \t\n\x0C\r");
+	/// Printable ASCII: the graphic characters and space.
+	pub const PRINTABLE: Self = Self::EMPTY.with_range(b' '..=b'~');
+	/// Characters allowed in an HTTP token, such as a header name (`tchar` in RFC 9110).
+	pub const TOKEN: Self = Self::ALNUM.with_bytes(b"!#$%&'*+-.^_`|~");
+	/// The standard Base64 alphabet, including the `=` padding character.
+	pub const BASE64: Self = Self::ALNUM.with_bytes(b"+/=");
+	/// The URL-safe Base64 alphabet, including the `=` padding character.
+	pub const BASE64_URL: Self = Self::ALNUM.with_bytes(b"-_=");
+
+	/// Creates an empty set.
+	#[inline]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self::EMPTY
+	}
+
+	/// Returns the set with `byte` added.
+	#[inline]
+	#[must_use]
+	pub const fn with_byte(mut self, byte: u8) -> Self {
+		self.bits[(byte / 64) as usize] |= 1 << (byte % 64);
+		self
+	}
+
+	/// Returns the set with every byte in `bytes` added.
+	#[must_use]
+	pub const fn with_bytes(mut self, bytes: &[u8]) -> Self {
+		let mut i = 0;
+		while i < bytes.len() {
+			self = self.with_byte(bytes[i]);
+			i += 1;
+		}
+		self
+	}
+
+	/// Returns the set with every byte in `range` added.
+	#[must_use]
+	pub const fn with_range(mut self, range: RangeInclusive<u8>) -> Self {
+		let (mut byte, end) = (*range.start(), *range.end());
+		while byte <= end {
+			self = self.with_byte(byte);
+			if byte == u8::MAX {
+				break;
+			}
+			byte += 1;
+		}
+		self
+	}
+
+	/// Returns the set with `byte` removed.
+	#[inline]
+	#[must_use]
+	pub const fn without_byte(mut self, byte: u8) -> Self {
+		self.bits[(byte / 64) as usize] &= !(1 << (byte % 64));
+		self
+	}
+
+	/// Returns the bytes in either set.
+	#[inline]
+	#[must_use]
+	pub const fn union(self, other: Self) -> Self {
+		let [a, b, c, d] = self.bits;
+		let [e, f, g, h] = other.bits;
+		Self { bits: [a | e, b | f, c | g, d | h] }
+	}
+
+	/// Returns the bytes in both sets.
+	#[inline]
+	#[must_use]
+	pub const fn intersection(self, other: Self) -> Self {
+		let [a, b, c, d] = self.bits;
+		let [e, f, g, h] = other.bits;
+		Self { bits: [a & e, b & f, c & g, d & h] }
+	}
+
+	/// Returns the bytes not in the set.
+	#[inline]
+	#[must_use]
+	pub const fn complement(self) -> Self {
+		let [a, b, c, d] = self.bits;
+		Self { bits: [!a, !b, !c, !d] }
+	}
+
+	/// Returns `true` if the set contains `byte`.
+	#[inline]
+	#[must_use]
+	pub const fn contains(&self, byte: u8) -> bool {
+		self.bits[(byte / 64) as usize] & (1 << (byte % 64)) != 0
+	}
+
+	/// Returns the number of bytes in the set.
+	#[inline]
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		let [a, b, c, d] = self.bits;
+		(a.count_ones() + b.count_ones() + c.count_ones() + d.count_ones()) as usize
+	}
+
+	/// Returns `true` if the set is empty.
+	#[inline]
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+impl FromIterator<u8> for CharSet {
+	fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+		iter.into_iter().fold(Self::EMPTY, Self::with_byte)
+	}
+}
+
+impl RawStr {
+	/// Returns `true` if every byte of the [`RawStr`] is in `set`.
+	///
+	/// An empty [`RawStr`] matches every set.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{CharSet, RawStr};
+	/// assert!(RawStr::new("deadBEEF").matches_charset(&CharSet::HEX));
+	/// assert!(!RawStr::new("0xdeadbeef").matches_charset(&CharSet::HEX));
+	/// assert!(RawStr::new("").matches_charset(&CharSet::EMPTY));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn matches_charset(&self, set: &CharSet) -> bool {
+		self.iter().all(|&b| set.contains(b))
+	}
+
+	/// Returns the index of the first byte that is not in `set`,
+	/// or `None` if every byte is.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{CharSet, RawStr};
+	/// assert_eq!(RawStr::new("aGVsbG8=\n").find_not_in(&CharSet::BASE64), Some(8));
+	/// assert_eq!(RawStr::new("aGVsbG8=").find_not_in(&CharSet::BASE64), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn find_not_in(&self, set: &CharSet) -> Option<usize> {
+		self.iter().position(|&b| !set.contains(b))
+	}
+
+	/// Returns the index and value of the first byte that is not in `set`,
+	/// or `None` if every byte is.
+	///
+	/// This is [`find_not_in`](RawStr::find_not_in) with the offending byte included,
+	/// for building error messages.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{CharSet, RawStr};
+	/// let header = RawStr::new(b"X-Custom\xFF");
+	/// assert_eq!(header.position_of_violation(&CharSet::TOKEN), Some((8, 0xFF)));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn position_of_violation(&self, set: &CharSet) -> Option<(usize, u8)> {
+		self.find_not_in(set).map(|i| (i, self[i]))
+	}
+}
+
+impl RawString {
+	/// Returns `true` if every byte of the [`RawString`] is in `set`.
+	///
+	/// See [`RawStr::matches_charset`].
+	#[inline]
+	#[must_use]
+	pub fn matches_charset(&self, set: &CharSet) -> bool {
+		self.as_ref().matches_charset(set)
+	}
+
+	/// Returns the index of the first byte that is not in `set`,
+	/// or `None` if every byte is.
+	///
+	/// See [`RawStr::find_not_in`].
+	#[inline]
+	#[must_use]
+	pub fn find_not_in(&self, set: &CharSet) -> Option<usize> {
+		self.as_ref().find_not_in(set)
+	}
+
+	/// Returns the index and value of the first byte that is not in `set`,
+	/// or `None` if every byte is.
+	///
+	/// See [`RawStr::position_of_violation`].
+	#[inline]
+	#[must_use]
+	pub fn position_of_violation(&self, set: &CharSet) -> Option<(usize, u8)> {
+		self.as_ref().position_of_violation(set)
+	}
+}
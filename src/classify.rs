@@ -0,0 +1,250 @@
+// rawstring::classify
+
+use crate::RawStr;
+
+/// Returns `true` if `c` is a printable character or common whitespace.
+#[inline]
+fn is_text_char(c: char) -> bool {
+	!c.is_control() || matches!(c, '\t' | '\n' | '\r' | '\x0C')
+}
+
+impl RawStr {
+	/// Returns `true` if the [`RawStr`] is valid UTF-8 without control characters,
+	/// other than tabs, newlines, carriage returns and form feeds.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert!(RawStr::new("héllo,\tworld\r\n").is_printable());
+	/// assert!(!RawStr::new("\x1B[31mred").is_printable());
+	/// assert!(!RawStr::new(b"\xFF").is_printable());
+	/// ```
+	#[must_use]
+	pub fn is_printable(&self) -> bool {
+		self.to_utf8_checked().is_ok_and(|s| s.chars().all(is_text_char))
+	}
+
+	/// Returns `true` if the [`RawStr`] only contains printable ASCII characters,
+	/// tabs, newlines, carriage returns and form feeds.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert!(RawStr::new("hello,\tworld\r\n").is_printable_ascii());
+	/// assert!(!RawStr::new("héllo").is_printable_ascii());
+	/// ```
+	#[must_use]
+	pub fn is_printable_ascii(&self) -> bool {
+		self.iter().all(|&b| b.is_ascii() && is_text_char(char::from(b)))
+	}
+
+	/// Returns the fraction of bytes that belong to printable characters or common whitespace,
+	/// from `0.0` to `1.0`. Invalid UTF-8 counts as non-text.
+	///
+	/// An empty [`RawStr`] has a ratio of `1.0`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("text\n").text_ratio(), 1.0);
+	/// assert_eq!(RawStr::new(b"ab\x00\xFF").text_ratio(), 0.5);
+	/// ```
+	#[must_use]
+	pub fn text_ratio(&self) -> f64 {
+		if self.is_empty() {
+			return 1.0;
+		}
+		let text: usize = self.utf8_chunks()
+			.flat_map(|chunk| chunk.valid().chars())
+			.filter(|&c| is_text_char(c))
+			.map(char::len_utf8)
+			.sum();
+		text as f64 / self.len() as f64
+	}
+
+	/// Guesses whether the [`RawStr`] holds binary data rather than text.
+	///
+	/// Like git and grep, only the first 8000 bytes are inspected; the data is considered binary
+	/// if they contain a NUL byte or, like Perl's `-B` test, if less than 70% of them are text
+	/// as measured by [`RawStr::text_ratio`]. See [`BinaryDetector`] to change these rules.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert!(!RawStr::new("fn main() {}\n").looks_binary());
+	/// assert!(!RawStr::new(b"caf\xC3\xA9 \xE9t\xE9 latin-1").looks_binary());
+	/// assert!(RawStr::new(b"\x7FELF\x02\x01\x01\x00").looks_binary());
+	/// assert!(RawStr::new(b"\x89PNG\r\n\x1A\n\xC4\xE5\xF2\xF8").looks_binary());
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn looks_binary(&self) -> bool {
+		BinaryDetector::DEFAULT.detect(self).is_binary()
+	}
+
+	/// Classifies the content of the [`RawStr`] by combining [`RawStr::is_printable_ascii`],
+	/// [`RawStr::is_printable`] and [`RawStr::looks_binary`], in that order.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{ContentClass, RawStr};
+	/// assert_eq!(RawStr::new("plain\n").classify_content(), ContentClass::Ascii);
+	/// assert_eq!(RawStr::new("caf\u{e9}\n").classify_content(), ContentClass::Utf8);
+	/// assert_eq!(RawStr::new(b"\x1B[1mcaf\xE9\x1B[0m").classify_content(), ContentClass::MostlyText);
+	/// assert_eq!(RawStr::new(b"\x7FELF\x02\x01\x01\x00").classify_content(), ContentClass::Binary);
+	/// ```
+	#[must_use]
+	pub fn classify_content(&self) -> ContentClass {
+		if self.is_printable_ascii() {
+			ContentClass::Ascii
+		} else if self.is_printable() {
+			ContentClass::Utf8
+		} else if self.looks_binary() {
+			ContentClass::Binary
+		} else {
+			ContentClass::MostlyText
+		}
+	}
+}
+
+/// The kind of content in a [`RawStr`], as decided by [`RawStr::classify_content`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ContentClass {
+	/// Printable ASCII text, which includes empty data.
+	Ascii,
+	/// Printable UTF-8 text that is not all ASCII.
+	Utf8,
+	/// Text with control characters or invalid UTF-8, but not enough to look binary.
+	MostlyText,
+	/// Data that [looks binary](RawStr::looks_binary).
+	Binary,
+}
+
+/// Configurable rules for deciding whether data is binary or text.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{BinaryDetector, BinaryReason, Detection, RawStr};
+/// let raw = RawStr::new(b"\xC4\xE5\xF2\xF8 no NUL here");
+/// assert!(!BinaryDetector::GIT.detect(raw).is_binary());
+///
+/// let strict = BinaryDetector { max_invalid_utf8: Some(0), ..BinaryDetector::GIT };
+/// assert_eq!(strict.detect(raw), Detection::Binary { offset: 0, reason: BinaryReason::InvalidUtf8 });
+///
+/// let raw = RawStr::new("text\0");
+/// assert_eq!(BinaryDetector::GIT.detect(raw), Detection::Binary { offset: 4, reason: BinaryReason::Nul });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BinaryDetector {
+	/// The number of leading bytes to inspect.
+	pub sample_len: usize,
+	/// Whether a NUL byte makes the data binary.
+	pub nul_is_binary: bool,
+	/// The number of bytes of invalid UTF-8 allowed before the data is binary, or `None` for no limit.
+	pub max_invalid_utf8: Option<usize>,
+	/// The smallest [text ratio](RawStr::text_ratio) the sample can have and still be text.
+	pub min_text_ratio: f64,
+}
+
+impl BinaryDetector {
+	/// The rules used by [`RawStr::looks_binary`]: the first 8000 bytes are binary
+	/// if they contain a NUL byte or less than 70% of them are text.
+	pub const DEFAULT: BinaryDetector = BinaryDetector {
+		sample_len: 8000,
+		nul_is_binary: true,
+		max_invalid_utf8: None,
+		min_text_ratio: 0.7,
+	};
+
+	/// The rules git uses to decide whether to diff a file: the first 8000 bytes
+	/// are binary if and only if they contain a NUL byte.
+	pub const GIT: BinaryDetector = BinaryDetector {
+		sample_len: 8000,
+		nul_is_binary: true,
+		max_invalid_utf8: None,
+		min_text_ratio: 0.0,
+	};
+
+	/// Decides whether `raw` is binary, returning the evidence if it is.
+	pub fn detect(&self, raw: &RawStr) -> Detection {
+		let sample = &raw[..raw.len().min(self.sample_len)];
+		let mut offset = 0;
+		let mut invalid = 0;
+		let mut text = 0;
+		let mut first_non_text = None;
+
+		for chunk in sample.utf8_chunks() {
+			for c in chunk.valid().chars() {
+				if c == '\0' && self.nul_is_binary {
+					return Detection::Binary { offset, reason: BinaryReason::Nul };
+				}
+				if is_text_char(c) {
+					text += c.len_utf8();
+				} else {
+					first_non_text.get_or_insert(offset);
+				}
+				offset += c.len_utf8();
+			}
+
+			if !chunk.invalid().is_empty() {
+				invalid += chunk.invalid().len();
+				if self.max_invalid_utf8.is_some_and(|max| invalid > max) {
+					return Detection::Binary { offset, reason: BinaryReason::InvalidUtf8 };
+				}
+				first_non_text.get_or_insert(offset);
+				offset += chunk.invalid().len();
+			}
+		}
+
+		match first_non_text {
+			Some(offset) if (text as f64) < self.min_text_ratio * sample.len() as f64 => {
+				Detection::Binary { offset, reason: BinaryReason::LowTextRatio }
+			}
+			_ => Detection::Text,
+		}
+	}
+}
+
+impl Default for BinaryDetector {
+	#[inline]
+	fn default() -> Self {
+		BinaryDetector::DEFAULT
+	}
+}
+
+/// The decision of a [`BinaryDetector`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Detection {
+	/// The data is text.
+	Text,
+	/// The data is binary.
+	Binary {
+		/// The offset of the byte that made the data binary; for [`BinaryReason::LowTextRatio`],
+		/// the offset of the first byte that is not text.
+		offset: usize,
+		/// The rule that made the data binary.
+		reason: BinaryReason,
+	},
+}
+
+impl Detection {
+	/// Returns `true` if the data is binary.
+	#[inline]
+	#[must_use]
+	pub const fn is_binary(self) -> bool {
+		matches!(self, Self::Binary { .. })
+	}
+}
+
+/// Why a [`BinaryDetector`] decided that data is binary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BinaryReason {
+	/// The data contains a NUL byte.
+	Nul,
+	/// The data contains more invalid UTF-8 than allowed.
+	InvalidUtf8,
+	/// Too little of the data is text.
+	LowTextRatio,
+}
@@ -0,0 +1,68 @@
+// rawstring::collect
+
+use crate::RawString;
+
+/// An extension trait for joining the items of an iterator into a [`RawString`].
+///
+/// Unlike collecting, which concatenates, these methods put a separator between items.
+pub trait CollectRawExt: Iterator + Sized {
+	/// Joins the items into a single [`RawString`], with `separator` between each pair.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{CollectRawExt, RawStr, RawString};
+	/// let fields = [RawStr::new("id"), RawStr::new(b"caf\xE9"), RawStr::new("")];
+	/// assert_eq!(fields.into_iter().collect_raw(","), RawString::from(b"id,caf\xE9,"));
+	/// ```
+	fn collect_raw<S>(self, separator: &S) -> RawString
+	where
+		Self::Item: AsRef<[u8]>,
+		S: ?Sized + AsRef<[u8]>,
+	{
+		let separator = separator.as_ref();
+		let mut raw = RawString::new();
+		for (i, item) in self.enumerate() {
+			if i > 0 {
+				raw.extend_from_slice(separator);
+			}
+			raw.extend_from_slice(item.as_ref());
+		}
+		raw
+	}
+
+	/// Joins the items of an iterator of [`Result`]s into a single [`RawString`], with
+	/// `separator` between each pair, stopping at the first error.
+	///
+	/// # Errors
+	/// Returns the first error yielded by the iterator.
+	///
+	/// # Examples
+	/// ```
+	/// # use std::io::{self, BufRead};
+	/// # use rawstring::{CollectRawExt, RawString};
+	/// let input = io::Cursor::new(b"first\nsecond \xFF\nthird");
+	/// let joined = input.split(b'\n').try_collect_raw(" | ").unwrap();
+	/// assert_eq!(joined, RawString::from(b"first | second \xFF | third"));
+	///
+	/// let failing = [Ok(b"a".to_vec()), Err(io::ErrorKind::InvalidData), Ok(b"b".to_vec())];
+	/// assert_eq!(failing.into_iter().try_collect_raw("\n"), Err(io::ErrorKind::InvalidData));
+	/// ```
+	fn try_collect_raw<T, E, S>(self, separator: &S) -> Result<RawString, E>
+	where
+		Self: Iterator<Item = Result<T, E>>,
+		T: AsRef<[u8]>,
+		S: ?Sized + AsRef<[u8]>,
+	{
+		let separator = separator.as_ref();
+		let mut raw = RawString::new();
+		for (i, item) in self.enumerate() {
+			if i > 0 {
+				raw.extend_from_slice(separator);
+			}
+			raw.extend_from_slice(item?.as_ref());
+		}
+		Ok(raw)
+	}
+}
+
+impl<I: Iterator> CollectRawExt for I {}
@@ -0,0 +1,90 @@
+// rawstring::compress
+
+use std::io::{self, Read};
+
+use crate::{RawStr, RawString};
+
+/// A compression format supported by [`RawStr::compress`] and [`RawString::decompress`].
+///
+/// Each variant is only available when the corresponding crate feature is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Codec {
+	/// Zstandard at the default compression level. Requires the `zstd` feature.
+	#[cfg(feature = "zstd")]
+	Zstd,
+	/// Gzip at the default compression level. Requires the `gzip` feature.
+	#[cfg(feature = "gzip")]
+	Gzip,
+}
+
+impl RawStr {
+	/// Compresses the [`RawStr`] using the given codec.
+	///
+	/// # Errors
+	/// Returns an error if the underlying encoder fails.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(feature = "zstd")] {
+	/// # use rawstring::{Codec, RawStr, RawString};
+	/// let data = RawStr::new(b"\xFF raw payload \xFF raw payload \xFF raw payload");
+	/// let compressed = data.compress(Codec::Zstd).unwrap();
+	/// let decompressed = RawString::decompress(compressed.as_ref(), Codec::Zstd, 1024).unwrap();
+	/// assert_eq!(decompressed.as_ref(), data);
+	/// # }
+	/// ```
+	pub fn compress(&self, codec: Codec) -> io::Result<RawString> {
+		let compressed = match codec {
+			#[cfg(feature = "zstd")]
+			Codec::Zstd => zstd::stream::encode_all(&self.0, zstd::DEFAULT_COMPRESSION_LEVEL)?,
+			#[cfg(feature = "gzip")]
+			Codec::Gzip => {
+				let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+				io::Write::write_all(&mut encoder, &self.0)?;
+				encoder.finish()?
+			}
+		};
+		Ok(RawString::from(compressed))
+	}
+}
+
+impl RawString {
+	/// Decompresses `compressed` using the given codec,
+	/// producing at most `max_len` bytes of output.
+	///
+	/// # Errors
+	/// Returns an error if the data is not valid for the codec, or an error of kind
+	/// [`io::ErrorKind::InvalidData`] if the decompressed data would exceed `max_len` bytes,
+	/// which guards against decompression bombs.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(feature = "gzip")] {
+	/// # use std::io;
+	/// # use rawstring::{Codec, RawStr, RawString};
+	/// let compressed = RawStr::new(&[0u8; 4096]).compress(Codec::Gzip).unwrap();
+	/// let err = RawString::decompress(compressed.as_ref(), Codec::Gzip, 1024).unwrap_err();
+	/// assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+	/// # }
+	/// ```
+	pub fn decompress(compressed: &RawStr, codec: Codec, max_len: usize) -> io::Result<RawString> {
+		let decoder: Box<dyn Read + '_> = match codec {
+			#[cfg(feature = "zstd")]
+			Codec::Zstd => Box::new(zstd::stream::read::Decoder::new(&compressed.0)?),
+			#[cfg(feature = "gzip")]
+			Codec::Gzip => Box::new(flate2::read::GzDecoder::new(&compressed.0)),
+		};
+
+		let mut decompressed = Vec::new();
+		let limit = u64::try_from(max_len).unwrap_or(u64::MAX).saturating_add(1);
+		decoder.take(limit).read_to_end(&mut decompressed)?;
+		if decompressed.len() > max_len {
+			return Err(io::Error::new(
+				io::ErrorKind::InvalidData,
+				format!("decompressed data exceeds the limit of {max_len} bytes"),
+			));
+		}
+		Ok(RawString::from(decompressed))
+	}
+}
@@ -0,0 +1,195 @@
+// rawstring::const_ops
+
+use crate::RawStr;
+
+impl RawStr {
+	/// Returns `true` if the [`RawStr`] is equal to `bytes`.
+	///
+	/// This is the same as `==`, but usable in constant expressions, for example to build
+	/// lookup tables over [`RawStr`] keys at compile time.
+	///
+	/// # Examples
+	/// ```
+	/// #![feature(const_trait_impl, const_convert)]
+	/// # use rawstring::RawStr;
+	/// const METHODS: [&[u8]; 4] = [b"GET", b"HEAD", b"POST", b"PUT"];
+	///
+	/// const fn method_index(method: &RawStr) -> Option<usize> {
+	///     let mut i = 0;
+	///     while i < METHODS.len() {
+	///         if method.eq_bytes(METHODS[i]) {
+	///             return Some(i);
+	///         }
+	///         i += 1;
+	///     }
+	///     None
+	/// }
+	///
+	/// const POST: Option<usize> = method_index(RawStr::new(b"POST"));
+	/// assert_eq!(POST, Some(2));
+	/// assert_eq!(method_index(RawStr::new("PATCH")), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn eq_bytes(&self, bytes: &[u8]) -> bool {
+		self.0.len() == bytes.len() && self.starts_with_bytes(bytes)
+	}
+
+	/// Returns `true` if the [`RawStr`] starts with `prefix`, in constant expressions.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert!(RawStr::new("Content-Type").starts_with_bytes(b"Content-"));
+	/// ```
+	#[must_use]
+	pub const fn starts_with_bytes(&self, prefix: &[u8]) -> bool {
+		if prefix.len() > self.0.len() {
+			return false;
+		}
+		let mut i = 0;
+		while i < prefix.len() {
+			if self.0[i] != prefix[i] {
+				return false;
+			}
+			i += 1;
+		}
+		true
+	}
+
+	/// Returns `true` if the [`RawStr`] ends with `suffix`, in constant expressions.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert!(RawStr::new("archive.tar.gz").ends_with_bytes(b".gz"));
+	/// ```
+	#[must_use]
+	pub const fn ends_with_bytes(&self, suffix: &[u8]) -> bool {
+		if suffix.len() > self.0.len() {
+			return false;
+		}
+		let offset = self.0.len() - suffix.len();
+		let mut i = 0;
+		while i < suffix.len() {
+			if self.0[offset + i] != suffix[i] {
+				return false;
+			}
+			i += 1;
+		}
+		true
+	}
+
+	/// Returns the index of the first occurrence of `byte`, in constant expressions.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("key=value").find_byte(b'='), Some(3));
+	/// assert_eq!(RawStr::new("key").find_byte(b'='), None);
+	/// ```
+	#[must_use]
+	pub const fn find_byte(&self, byte: u8) -> Option<usize> {
+		let mut i = 0;
+		while i < self.0.len() {
+			if self.0[i] == byte {
+				return Some(i);
+			}
+			i += 1;
+		}
+		None
+	}
+
+	/// Returns the index of the last occurrence of `byte`, in constant expressions.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("a/b/c").rfind_byte(b'/'), Some(3));
+	/// ```
+	#[must_use]
+	pub const fn rfind_byte(&self, byte: u8) -> Option<usize> {
+		let mut i = self.0.len();
+		while i > 0 {
+			i -= 1;
+			if self.0[i] == byte {
+				return Some(i);
+			}
+		}
+		None
+	}
+
+	/// Splits the [`RawStr`] on the first occurrence of `byte`, in constant expressions.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let (key, value) = RawStr::new("key=a=b").split_once_byte(b'=').unwrap();
+	/// assert_eq!((key, value), (RawStr::new("key"), RawStr::new("a=b")));
+	/// ```
+	#[must_use]
+	pub const fn split_once_byte(&self, byte: u8) -> Option<(&RawStr, &RawStr)> {
+		match self.find_byte(byte) {
+			Some(i) => {
+				let (head, tail) = self.0.split_at(i);
+				Some((RawStr::from_bytes(head), RawStr::from_bytes(tail.split_at(1).1)))
+			}
+			None => None,
+		}
+	}
+
+	/// Splits off the first byte of the [`RawStr`], or returns `None` if it is empty.
+	///
+	/// This is [`<[u8]>::split_first`] returning the rest as a [`RawStr`], usable in constant
+	/// expressions and `while let` loops.
+	///
+	/// # Examples
+	/// ```
+	/// #![feature(const_trait_impl, const_convert)]
+	/// # use rawstring::RawStr;
+	/// // parses a decimal version number at compile time
+	/// const fn parse_u32(mut raw: &RawStr) -> u32 {
+	///     let mut n = 0;
+	///     while let Some((digit @ b'0'..=b'9', rest)) = raw.split_first_byte() {
+	///         n = n * 10 + (digit - b'0') as u32;
+	///         raw = rest;
+	///     }
+	///     n
+	/// }
+	///
+	/// const MAJOR: u32 = parse_u32(RawStr::new(b"12.4"));
+	/// assert_eq!(MAJOR, 12);
+	/// assert_eq!(RawStr::new("").split_first_byte(), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn split_first_byte(&self) -> Option<(u8, &RawStr)> {
+		match self.0.split_first() {
+			Some((&head, rest)) => Some((head, RawStr::from_bytes(rest))),
+			None => None,
+		}
+	}
+
+	/// Splits off the last byte of the [`RawStr`], or returns `None` if it is empty.
+	///
+	/// This is [`<[u8]>::split_last`] returning the rest as a [`RawStr`], usable in constant
+	/// expressions and `while let` loops.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let mut raw = RawStr::new("path///");
+	/// while let Some((b'/', rest)) = raw.split_last_byte() {
+	///     raw = rest;
+	/// }
+	/// assert_eq!(raw, "path");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn split_last_byte(&self) -> Option<(u8, &RawStr)> {
+		match self.0.split_last() {
+			Some((&last, rest)) => Some((last, RawStr::from_bytes(rest))),
+			None => None,
+		}
+	}
+}
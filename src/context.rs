@@ -0,0 +1,195 @@
+// rawstring::context
+
+use std::{error::Error, fmt, str::Utf8Error, string::FromUtf8Error};
+
+use crate::{DecodeError, IntoStringError, RawStr, ReadError, ShellSplitError};
+
+/// Errors that can report the byte offset in the input at which they occurred.
+///
+/// This is implemented for the error types of this crate and for the UTF-8 errors of the
+/// standard library, and is used by [`ResultExt::with_raw_context`] to show the input
+/// around the offending bytes.
+pub trait ErrorOffset {
+	/// Returns the byte offset at which the error occurred, if it is known.
+	fn error_offset(&self) -> Option<usize>;
+}
+
+impl ErrorOffset for ReadError {
+	#[inline]
+	fn error_offset(&self) -> Option<usize> {
+		Some(self.position())
+	}
+}
+
+impl ErrorOffset for DecodeError {
+	#[inline]
+	fn error_offset(&self) -> Option<usize> {
+		Some(self.position())
+	}
+}
+
+impl ErrorOffset for ShellSplitError {
+	#[inline]
+	fn error_offset(&self) -> Option<usize> {
+		match *self {
+			Self::UnterminatedQuote { offset } => Some(offset),
+			Self::TrailingBackslash => None,
+		}
+	}
+}
+
+impl ErrorOffset for Utf8Error {
+	#[inline]
+	fn error_offset(&self) -> Option<usize> {
+		Some(self.valid_up_to())
+	}
+}
+
+impl ErrorOffset for FromUtf8Error {
+	#[inline]
+	fn error_offset(&self) -> Option<usize> {
+		Some(self.utf8_error().valid_up_to())
+	}
+}
+
+impl ErrorOffset for IntoStringError {
+	#[inline]
+	fn error_offset(&self) -> Option<usize> {
+		Some(self.utf8_error().valid_up_to())
+	}
+}
+
+impl<E> ErrorOffset for RawContextError<E> {
+	#[inline]
+	fn error_offset(&self) -> Option<usize> {
+		self.offset
+	}
+}
+
+/// An error annotated with a short, escaped snippet of the input it occurred in.
+///
+/// The snippet is at most [`SNIPPET_LEN`](Self::SNIPPET_LEN) bytes of the input,
+/// starting a few bytes before the error offset if there is one, and is escaped as in
+/// [`Debug`](fmt::Debug) output, so it is safe to include in logs and messages.
+/// The original error is returned by [`Error::source`], so error reporters such as
+/// `anyhow` print it as the cause.
+///
+/// Created by [`ResultExt::with_raw_context`] and [`ResultExt::with_raw_context_at`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawContextError<E> {
+	source: E,
+	snippet: String,
+	offset: Option<usize>,
+}
+
+impl<E> RawContextError<E> {
+	/// The maximum number of input bytes shown in a snippet.
+	pub const SNIPPET_LEN: usize = 32;
+
+	/// The number of bytes shown before the error offset.
+	const LEADING_LEN: usize = 8;
+
+	/// Annotates `source` with a snippet of `raw` around `offset`.
+	#[must_use]
+	pub fn new(source: E, raw: &RawStr, offset: Option<usize>) -> Self {
+		let start = offset.map_or(0, |offset| offset.min(raw.len()).saturating_sub(Self::LEADING_LEN));
+		let window = RawStr::new(&raw[start..]);
+		let ellipsis = if start > 0 { "…" } else { "" };
+		let snippet = format!("{ellipsis}{:?}", window.debug_truncated(Self::SNIPPET_LEN));
+		Self { source, snippet, offset }
+	}
+
+	/// Returns the escaped snippet of the input.
+	#[inline]
+	#[must_use]
+	pub fn snippet(&self) -> &str {
+		&self.snippet
+	}
+
+	/// Returns the byte offset at which the error occurred, if it is known.
+	#[inline]
+	#[must_use]
+	pub const fn offset(&self) -> Option<usize> {
+		self.offset
+	}
+
+	/// Returns a reference to the original error.
+	#[inline]
+	#[must_use]
+	pub const fn get_ref(&self) -> &E {
+		&self.source
+	}
+
+	/// Returns the original error.
+	#[inline]
+	#[must_use]
+	pub fn into_inner(self) -> E {
+		self.source
+	}
+}
+
+impl<E> fmt::Display for RawContextError<E> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.offset {
+			Some(offset) => write!(f, "in {} at byte {offset}", self.snippet),
+			None => write!(f, "in {}", self.snippet),
+		}
+	}
+}
+
+impl<E: Error + 'static> Error for RawContextError<E> {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		Some(&self.source)
+	}
+}
+
+/// An extension trait for attaching the input to errors.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{RawStr, RawStrReader, ResultExt};
+/// use std::error::Error;
+///
+/// let data = RawStr::new(b"\x02\x00\xFF");
+/// let err = RawStrReader::new(data).read_u32_le().with_raw_context(data).unwrap_err();
+/// assert_eq!(err.to_string(), r#"in "\x02\0\xff" at byte 0"#);
+/// assert_eq!(
+///     err.source().unwrap().to_string(),
+///     "unexpected end of input at byte 0: needed 4 bytes, 3 remaining",
+/// );
+///
+/// let mut data = [b'x'; 100];
+/// data[50] = 0xFF;
+/// let long = RawStr::new(&data);
+/// let err = long.to_utf8_checked().with_raw_context(long).unwrap_err();
+/// assert_eq!(err.offset(), Some(50));
+/// assert_eq!(err.snippet(), r#"…"xxxxxxxx\xffxxxxxxxxxxxxxxxxxxxxxxx"… (+26 bytes)"#);
+/// ```
+pub trait ResultExt<T, E> {
+	/// Annotates the error with a snippet of `raw` around the offset reported by the error.
+	fn with_raw_context(self, raw: &RawStr) -> Result<T, RawContextError<E>>
+	where
+		E: ErrorOffset;
+
+	/// Annotates the error with a snippet of `raw` around `offset`.
+	fn with_raw_context_at(self, raw: &RawStr, offset: usize) -> Result<T, RawContextError<E>>;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+	#[inline]
+	fn with_raw_context(self, raw: &RawStr) -> Result<T, RawContextError<E>>
+	where
+		E: ErrorOffset,
+	{
+		self.map_err(|err| {
+			let offset = err.error_offset();
+			RawContextError::new(err, raw, offset)
+		})
+	}
+
+	#[inline]
+	fn with_raw_context_at(self, raw: &RawStr, offset: usize) -> Result<T, RawContextError<E>> {
+		self.map_err(|err| RawContextError::new(err, raw, Some(offset)))
+	}
+}
@@ -0,0 +1,87 @@
+// rawstring::cow
+
+use std::borrow::Cow;
+
+use crate::{RawStr, RawString};
+
+/// Borrows the [`RawStr`] without copying.
+///
+/// # Examples
+/// ```
+/// # use std::borrow::Cow;
+/// # use rawstring::{RawStr, RawString};
+/// let borrowed: Cow<'_, RawStr> = RawStr::new(b"caf\xC3\xA9 \xFF").into();
+/// let owned: Cow<'_, RawStr> = RawString::from("caf\u{e9} \u{fffd}").into();
+///
+/// // Display and Debug pass through to RawStr
+/// # #[cfg(not(feature = "strict"))]
+/// assert_eq!(borrowed.to_string(), owned.to_string());
+/// assert_eq!(format!("{borrowed:?}"), r#""café \xff""#);
+///
+/// // compare through a deref, or against other Cows
+/// assert_eq!(*borrowed, *b"caf\xC3\xA9 \xFF");
+/// assert!(*owned == *"caf\u{e9} \u{fffd}");
+/// assert_eq!(owned, Cow::<str>::Borrowed("caf\u{e9} \u{fffd}"));
+/// assert_ne!(borrowed, owned);
+/// ```
+impl<'a> From<&'a RawStr> for Cow<'a, RawStr> {
+	#[inline]
+	fn from(raw: &'a RawStr) -> Self {
+		Cow::Borrowed(raw)
+	}
+}
+
+/// Borrows the contents of the [`RawString`] without copying.
+impl<'a> From<&'a RawString> for Cow<'a, RawStr> {
+	#[inline]
+	fn from(raw: &'a RawString) -> Self {
+		Cow::Borrowed(raw.as_ref())
+	}
+}
+
+/// Takes ownership of the [`RawString`] without copying.
+impl From<RawString> for Cow<'_, RawStr> {
+	#[inline]
+	fn from(raw: RawString) -> Self {
+		Cow::Owned(raw)
+	}
+}
+
+/// Lossily converts the [`RawStr`] to text. See [`RawStr::to_utf8_lossy`].
+///
+/// # Panics
+/// With the `strict` feature in debug builds, panics if the [`RawStr`] is not valid UTF-8.
+///
+/// # Examples
+/// ```
+/// # use std::borrow::Cow;
+/// # use rawstring::RawStr;
+/// let text: Cow<'_, str> = RawStr::new(b"ok").into();
+/// assert!(matches!(text, Cow::Borrowed("ok")));
+///
+/// # #[cfg(not(feature = "strict"))] {
+/// let text: Cow<'_, str> = RawStr::new(b"not \xFF ok").into();
+/// assert_eq!(text, "not \u{FFFD} ok");
+/// # }
+/// ```
+impl<'a> From<&'a RawStr> for Cow<'a, str> {
+	#[inline]
+	#[track_caller]
+	fn from(raw: &'a RawStr) -> Self {
+		raw.to_utf8_lossy()
+	}
+}
+
+impl PartialEq<Cow<'_, RawStr>> for RawString {
+	#[inline]
+	fn eq(&self, other: &Cow<'_, RawStr>) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl PartialEq<Cow<'_, RawStr>> for &RawStr {
+	#[inline]
+	fn eq(&self, other: &Cow<'_, RawStr>) -> bool {
+		self.0 == other.0
+	}
+}
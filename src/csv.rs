@@ -0,0 +1,201 @@
+// rawstring::csv
+
+//! Binary-safe CSV splitting following [RFC 4180] quoting rules.
+//!
+//! Fields are returned as [`Cow<RawStr>`]: they borrow from the input
+//! unless unescaping a quoted field requires a copy.
+//! Parsing is lenient: stray quotes inside unquoted fields are kept literally,
+//! bytes following a closing quote are appended to the field,
+//! and an unterminated quoted field extends to the end of the input.
+//!
+//! [RFC 4180]: https://www.rfc-editor.org/rfc/rfc4180
+//!
+//! # Examples
+//! ```
+//! # use rawstring::RawStr;
+//! let data = RawStr::new(b"name,bio\r\nbob,\"likes \"\"quotes\"\"\nand \xFF\"\r\n");
+//! let mut records = data.csv_records(b'"');
+//! assert_eq!(records.next().unwrap(), "name,bio");
+//!
+//! let record = records.next().unwrap();
+//! let fields: Vec<_> = record.split_csv_record(b',', b'"').collect();
+//! assert_eq!(&*fields[0], "bob");
+//! assert_eq!(&*fields[1], b"likes \"quotes\"\nand \xFF");
+//! assert_eq!(records.next(), None);
+//! ```
+
+use std::{borrow::Cow, iter::FusedIterator};
+
+use crate::{RawStr, RawString};
+
+/// An iterator over the fields of a single CSV record.
+///
+/// Created by [`RawStr::split_csv_record`].
+#[derive(Debug, Clone)]
+pub struct Fields<'a> {
+	rest: &'a [u8],
+	delimiter: u8,
+	quote: u8,
+	finished: bool,
+}
+
+impl<'a> Iterator for Fields<'a> {
+	type Item = Cow<'a, RawStr>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.finished {
+			return None;
+		}
+
+		let (field, consumed) = if self.rest.first() == Some(&self.quote) {
+			parse_quoted(self.rest, self.delimiter, self.quote)
+		} else {
+			let len = self.rest.iter()
+				.position(|&b| b == self.delimiter)
+				.unwrap_or(self.rest.len());
+			(Cow::Borrowed(RawStr::new(&self.rest[..len])), len)
+		};
+
+		match self.rest.get(consumed) {
+			// skip the delimiter
+			Some(_) => self.rest = &self.rest[consumed + 1..],
+			None => self.finished = true,
+		}
+		Some(field)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.finished {
+			(0, Some(0))
+		} else {
+			(1, Some(self.rest.len() + 1))
+		}
+	}
+}
+
+impl FusedIterator for Fields<'_> {}
+
+/// Parses a quoted field at the start of `bytes`,
+/// returning the field and the number of bytes consumed up to the next delimiter.
+fn parse_quoted(bytes: &[u8], delimiter: u8, quote: u8) -> (Cow<'_, RawStr>, usize) {
+	let mut owned: Option<RawString> = None;
+	let mut start = 1;
+	let mut i = 1;
+	loop {
+		match bytes.get(i) {
+			// unterminated quote: the field runs to the end
+			None => break,
+			Some(&b) if b == quote => {
+				if bytes.get(i + 1) == Some(&quote) {
+					// escaped quote: keep one of the pair
+					owned.get_or_insert_default().extend_from_slice(&bytes[start..=i]);
+					i += 2;
+					start = i;
+				} else {
+					break;
+				}
+			}
+			Some(_) => i += 1,
+		}
+	}
+
+	let inner = &bytes[start..i];
+	let after_quote = (i + 1).min(bytes.len());
+	let end = bytes[after_quote..].iter()
+		.position(|&b| b == delimiter)
+		.map_or(bytes.len(), |pos| after_quote + pos);
+	let trailing = &bytes[after_quote..end];
+
+	let field = match owned {
+		None if trailing.is_empty() => Cow::Borrowed(RawStr::new(inner)),
+		owned => {
+			let mut owned = owned.unwrap_or_default();
+			owned.extend_from_slice(inner);
+			owned.extend_from_slice(trailing);
+			Cow::Owned(owned)
+		}
+	};
+	(field, end)
+}
+
+/// An iterator over the records of CSV data.
+///
+/// Created by [`RawStr::csv_records`].
+#[derive(Debug, Clone)]
+pub struct Records<'a> {
+	rest: &'a [u8],
+	quote: u8,
+}
+
+impl<'a> Iterator for Records<'a> {
+	type Item = &'a RawStr;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.rest.is_empty() {
+			return None;
+		}
+
+		let mut quoted = false;
+		let end = self.rest.iter()
+			.position(|&b| {
+				if b == self.quote {
+					quoted = !quoted;
+				}
+				b == b'\n' && !quoted
+			});
+		let (record, rest) = match end {
+			Some(end) => (&self.rest[..end], &self.rest[end + 1..]),
+			None => (self.rest, &[][..]),
+		};
+		self.rest = rest;
+		Some(RawStr::new(record.strip_suffix(b"\r").unwrap_or(record)))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.rest.len();
+		(usize::from(len > 0), Some(len))
+	}
+}
+
+impl FusedIterator for Records<'_> {}
+
+impl RawStr {
+	/// Splits a single CSV record into its fields.
+	///
+	/// Fields are separated by `delimiter` and may be enclosed in `quote` bytes,
+	/// in which case the delimiter loses its meaning and a doubled quote stands for a single one.
+	/// The record should not include its line terminator.
+	///
+	/// An empty record yields a single empty field.
+	///
+	/// # Examples
+	/// ```
+	/// # use std::borrow::Cow;
+	/// # use rawstring::RawStr;
+	/// let record = RawStr::new(b"a;'b;c';'it''s';\xFF");
+	/// let fields: Vec<_> = record.split_csv_record(b';', b'\'').collect();
+	/// assert_eq!(fields, [
+	///     Cow::Borrowed(RawStr::new("a")),
+	///     Cow::Borrowed(RawStr::new("b;c")),
+	///     Cow::Borrowed(RawStr::new("it's")),
+	///     Cow::Borrowed(RawStr::new(b"\xFF")),
+	/// ]);
+	/// assert!(matches!(fields[1], Cow::Borrowed(_)));
+	/// assert!(matches!(fields[2], Cow::Owned(_)));
+	/// ```
+	#[inline]
+	pub fn split_csv_record(&self, delimiter: u8, quote: u8) -> Fields<'_> {
+		Fields { rest: &self.0, delimiter, quote, finished: false }
+	}
+
+	/// Splits CSV data into records, ignoring line breaks inside quoted fields.
+	///
+	/// Records are terminated by `\n` or `\r\n`, which are not included in the returned records.
+	/// A trailing line terminator does not produce an empty final record.
+	#[inline]
+	pub fn csv_records(&self, quote: u8) -> Records<'_> {
+		Records { rest: &self.0, quote }
+	}
+}
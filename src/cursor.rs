@@ -0,0 +1,229 @@
+// rawstring::cursor
+
+use std::ops::Range;
+
+use crate::{RawStr, utf8};
+
+/// A cursor for reading a [`RawStr`] one character at a time.
+///
+/// This is the character-level counterpart of [`RawStrReader`](crate::RawStrReader),
+/// meant for hand-written lexers. Characters are decoded as in [`RawStr::char_ranges`],
+/// so invalid UTF-8 comes out as invalid sequences rather than being skipped or replaced.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{RawStr, RawStrCursor};
+/// let mut cursor = RawStrCursor::new(RawStr::new(b"x=\xFF1"));
+/// assert_eq!(cursor.peek_char(), Some(Ok('x')));
+/// assert_eq!(cursor.pop_front_char(), Some(Ok('x')));
+/// assert_eq!(cursor.pop_front_char(), Some(Ok('=')));
+/// assert_eq!(cursor.pop_front_char(), Some(Err(RawStr::new(b"\xFF"))));
+/// assert_eq!(cursor.position(), 3);
+/// assert_eq!(cursor.remaining(), "1");
+/// assert_eq!(cursor.pop_front_char(), Some(Ok('1')));
+/// assert_eq!(cursor.pop_front_char(), None);
+/// ```
+///
+/// A small lexer built on [`mark`](Self::mark) and [`span_from`](Self::span_from):
+/// ```
+/// # use rawstring::{RawStr, RawStrCursor};
+/// let mut cursor = RawStrCursor::new(RawStr::new(b"let x\xFF = 42;"));
+/// let mut tokens = Vec::new();
+/// while !cursor.is_empty() {
+///     cursor.take_while(|c| c == Ok(' '));
+///     let start = cursor.mark();
+///     if cursor.take_while(|c| c.is_ok_and(|c| c.is_ascii_alphanumeric())).text.is_empty() {
+///         cursor.bump();
+///     }
+///     tokens.push(cursor.span_from(start));
+/// }
+/// let texts: Vec<_> = tokens.iter().map(|span| span.text).collect();
+/// assert_eq!(texts, [RawStr::new("let"), RawStr::new("x"), RawStr::new(b"\xFF"), RawStr::new("="), RawStr::new("42"), RawStr::new(";")]);
+/// assert_eq!(tokens[4].range, 9..11);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RawStrCursor<'a> {
+	data: &'a RawStr,
+	pos: usize,
+}
+
+/// A saved position of a [`RawStrCursor`].
+///
+/// Created by [`RawStrCursor::mark`], and used to [`reset`](RawStrCursor::reset) the cursor
+/// or to take the [`span`](RawStrCursor::span_from) consumed since.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CursorMark(usize);
+
+impl CursorMark {
+	/// Returns the marked position in bytes from the start of the data.
+	#[inline]
+	#[must_use]
+	pub const fn position(self) -> usize {
+		self.0
+	}
+}
+
+/// A part of the data of a [`RawStrCursor`], with its location.
+///
+/// Created by [`RawStrCursor::span_from`] and [`RawStrCursor::take_while`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Span<'a> {
+	/// The byte range of the span in the data.
+	pub range: Range<usize>,
+	/// The bytes of the span.
+	pub text: &'a RawStr,
+}
+
+impl<'a> RawStrCursor<'a> {
+	/// Creates a cursor positioned at the start of `data`.
+	#[inline]
+	#[must_use]
+	pub const fn new(data: &'a RawStr) -> Self {
+		Self { data, pos: 0 }
+	}
+
+	/// Returns the current position in bytes from the start of the data.
+	#[inline]
+	#[must_use]
+	pub const fn position(&self) -> usize {
+		self.pos
+	}
+
+	/// Returns the data the cursor was created with.
+	#[inline]
+	#[must_use]
+	pub const fn get_ref(&self) -> &'a RawStr {
+		self.data
+	}
+
+	/// Returns the data that has not been consumed yet.
+	#[inline]
+	#[must_use]
+	pub fn remaining(&self) -> &'a RawStr {
+		RawStr::new(&self.data[self.pos..])
+	}
+
+	/// Returns `true` if all data has been consumed.
+	#[inline]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.pos == self.data.len()
+	}
+
+	/// Returns the next character, or invalid sequence, without advancing the cursor.
+	///
+	/// See [`RawStr::first_char`].
+	#[inline]
+	#[must_use]
+	pub fn peek_char(&self) -> Option<Result<char, &'a RawStr>> {
+		self.remaining().first_char()
+	}
+
+	/// Consumes and returns the next character, or invalid sequence,
+	/// or returns `None` at the end of the data.
+	#[inline]
+	pub fn pop_front_char(&mut self) -> Option<Result<char, &'a RawStr>> {
+		let unit = utf8::decode_first(&self.data[self.pos..])?;
+		self.pos += utf8::unit_len(unit);
+		Some(unit.map_err(RawStr::new))
+	}
+
+	/// Skips the next character or invalid sequence, returning `false` at the end of the data.
+	///
+	/// This is [`pop_front_char`](Self::pop_front_char) for when the character has
+	/// already been looked at with [`peek_char`](Self::peek_char).
+	#[inline]
+	pub fn bump(&mut self) -> bool {
+		self.pop_front_char().is_some()
+	}
+
+	/// Consumes `prefix` if the remaining data starts with it, returning whether it did.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawStrCursor};
+	/// let mut cursor = RawStrCursor::new(RawStr::new(b"<!--\xFF"));
+	/// assert!(!cursor.eat("<?"));
+	/// assert!(cursor.eat("<!--"));
+	/// assert!(cursor.eat(b"\xFF"));
+	/// assert!(cursor.is_empty());
+	/// ```
+	#[inline]
+	pub fn eat<B>(&mut self, prefix: &B) -> bool
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let prefix = prefix.as_ref();
+		let matches = self.data[self.pos..].starts_with(prefix);
+		if matches {
+			self.pos += prefix.len();
+		}
+		matches
+	}
+
+	/// Consumes characters and invalid sequences while `pred` returns `true`,
+	/// and returns the [`Span`] consumed.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawStrCursor};
+	/// let mut cursor = RawStrCursor::new(RawStr::new("  \u{3c0} = 3.14"));
+	/// cursor.take_while(|c| c.is_ok_and(char::is_whitespace));
+	/// let ident = cursor.take_while(|c| c.is_ok_and(char::is_alphabetic));
+	/// assert_eq!(ident.text, "\u{3c0}");
+	/// assert_eq!(ident.range, 2..4);
+	/// ```
+	pub fn take_while<F>(&mut self, mut pred: F) -> Span<'a>
+	where
+		F: FnMut(Result<char, &'a RawStr>) -> bool
+	{
+		let start = self.mark();
+		while let Some(unit) = self.peek_char() {
+			if !pred(unit) {
+				break;
+			}
+			self.pos += utf8::unit_len(unit.map_err(|invalid| &invalid.0));
+		}
+		self.span_from(start)
+	}
+
+	/// Returns a [`CursorMark`] for the current position.
+	#[inline]
+	#[must_use]
+	pub const fn mark(&self) -> CursorMark {
+		CursorMark(self.pos)
+	}
+
+	/// Moves the cursor back, or forward, to `mark`.
+	///
+	/// # Panics
+	/// Panics if `mark` is past the end of the data, which can only happen if it was made
+	/// by a cursor over different data.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawStrCursor};
+	/// let mut cursor = RawStrCursor::new(RawStr::new("0x1F"));
+	/// let start = cursor.mark();
+	/// if !(cursor.eat("0x") && cursor.eat("20")) {
+	///     cursor.reset(start);
+	/// }
+	/// assert_eq!(cursor.position(), 0);
+	/// ```
+	#[inline]
+	pub fn reset(&mut self, mark: CursorMark) {
+		assert!(mark.0 <= self.data.len(), "mark {} is past the end of the data", mark.0);
+		self.pos = mark.0;
+	}
+
+	/// Returns the [`Span`] from `mark` to the current position.
+	///
+	/// # Panics
+	/// Panics if `mark` is after the current position.
+	#[inline]
+	#[must_use]
+	pub fn span_from(&self, mark: CursorMark) -> Span<'a> {
+		let range = mark.0..self.pos;
+		Span { text: RawStr::new(&self.data[range.clone()]), range }
+	}
+}
@@ -0,0 +1,46 @@
+// rawstring::debug
+
+use std::fmt;
+
+use crate::{RawStr, raw_str_imp::fmt_debug};
+
+impl RawStr {
+	/// Returns a wrapper whose [`Debug`](fmt::Debug) output shows at most the first `max_len` bytes,
+	/// followed by the number of bytes left out.
+	///
+	/// This has the same effect as formatting with a precision (`{:.N?}`), for use where
+	/// the format string is out of your control, such as in a derived [`Debug`](fmt::Debug)
+	/// implementation or an error message.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let payload = RawStr::new(&[b'x'; 1000]);
+	/// let debug = format!("{:?}", payload.debug_truncated(4));
+	/// assert_eq!(debug, r#""xxxx"… (+996 bytes)"#);
+	///
+	/// assert_eq!(format!("{:?}", RawStr::new("short").debug_truncated(64)), r#""short""#);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn debug_truncated(&self, max_len: usize) -> DebugTruncated<'_> {
+		DebugTruncated { raw: self, max_len }
+	}
+}
+
+/// A wrapper that limits the [`Debug`](fmt::Debug) output of a [`RawStr`].
+///
+/// Created by [`RawStr::debug_truncated`].
+#[derive(Clone, Copy)]
+pub struct DebugTruncated<'a> {
+	raw: &'a RawStr,
+	max_len: usize,
+}
+
+impl fmt::Debug for DebugTruncated<'_> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let max_len = f.precision().map_or(self.max_len, |precision| precision.min(self.max_len));
+		fmt_debug(self.raw, Some(max_len), f)
+	}
+}
@@ -0,0 +1,223 @@
+// rawstring::decode
+
+use std::{error::Error, fmt, iter::FusedIterator};
+
+use crate::{RawStr, UNICODE_REPLACEMENT_CHARACTER, Utf8ErrorKind};
+
+/// An extension trait for decoding UTF-8 from any iterator of bytes.
+///
+/// This lets streaming sources, such as the bytes of a reader, be decoded without first
+/// collecting them into a [`RawString`](crate::RawString). Invalid data is split into the
+/// same sequences as [`RawStr::char_ranges`] and [`RawStr::to_utf8_lossy`], so decoding
+/// a [`RawStr`] byte by byte gives the same result as decoding it all at once.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{DecodeUtf8Ext, RawStr, Utf8ErrorKind};
+/// let bytes = b"a\xE2\x82\xAC\xFF\xE2\x82".iter().copied();
+/// let mut chars = bytes.decode_utf8();
+/// assert_eq!(chars.next(), Some(Ok('a')));
+/// assert_eq!(chars.next(), Some(Ok('€')));
+///
+/// let err = chars.next().unwrap().unwrap_err();
+/// assert_eq!(err.invalid_bytes(), RawStr::new(b"\xFF"));
+/// assert_eq!(err.position(), 4);
+/// assert_eq!(err.kind(), Utf8ErrorKind::InvalidStartByte);
+///
+/// let err = chars.next().unwrap().unwrap_err();
+/// assert_eq!(err.invalid_bytes(), RawStr::new(b"\xE2\x82"));
+/// assert_eq!(err.to_string(), "invalid UTF-8 at byte 5: truncated sequence");
+/// assert_eq!(chars.next(), None);
+/// ```
+pub trait DecodeUtf8Ext: Iterator<Item = u8> + Sized {
+	/// Decodes the bytes as UTF-8, yielding an error for each invalid sequence.
+	#[inline]
+	fn decode_utf8(self) -> DecodeUtf8<Self> {
+		DecodeUtf8 { iter: self, pending: None, position: 0 }
+	}
+
+	/// Decodes the bytes as UTF-8, replacing each invalid sequence with
+	/// [`UNICODE_REPLACEMENT_CHARACTER`].
+	///
+	/// # Panics
+	/// With the `strict` feature in debug builds, the iterator panics when it reaches
+	/// an invalid sequence.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "strict"))] {
+	/// # use rawstring::{DecodeUtf8Ext, RawStr};
+	/// let raw = RawStr::new(b"caf\xE9 \xF0\x9F\x98");
+	/// let decoded: String = raw.iter().copied().decode_utf8_lossy().collect();
+	/// assert_eq!(decoded, "caf� �");
+	/// assert_eq!(decoded, raw.to_utf8_lossy_with(|_, _| ()));
+	/// # }
+	/// ```
+	#[inline]
+	fn decode_utf8_lossy(self) -> DecodeUtf8Lossy<Self> {
+		DecodeUtf8Lossy { inner: self.decode_utf8() }
+	}
+}
+
+impl<I: Iterator<Item = u8>> DecodeUtf8Ext for I {}
+
+/// An error returned by [`DecodeUtf8`] for an invalid UTF-8 sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecodeError {
+	bytes: [u8; 3],
+	len: u8,
+	position: usize,
+	kind: Utf8ErrorKind,
+}
+
+impl DecodeError {
+	/// Returns the invalid sequence, which is between one and three bytes long.
+	#[inline]
+	#[must_use]
+	pub fn invalid_bytes(&self) -> &RawStr {
+		RawStr::new(&self.bytes[..self.len as usize])
+	}
+
+	/// Returns the offset of the invalid sequence from the start of the input.
+	#[inline]
+	#[must_use]
+	pub const fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Returns why the sequence is invalid.
+	#[inline]
+	#[must_use]
+	pub const fn kind(&self) -> Utf8ErrorKind {
+		self.kind
+	}
+}
+
+impl fmt::Display for DecodeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "invalid UTF-8 at byte {}: {}", self.position, self.kind)
+	}
+}
+
+impl Error for DecodeError {}
+
+/// An iterator that decodes UTF-8 from an iterator of bytes.
+///
+/// Created by [`DecodeUtf8Ext::decode_utf8`].
+#[derive(Debug, Clone)]
+pub struct DecodeUtf8<I> {
+	iter: I,
+	pending: Option<u8>,
+	position: usize,
+}
+
+impl<I> DecodeUtf8<I> {
+	/// Returns the underlying iterator.
+	///
+	/// A byte that was read to end an invalid sequence but is not part of it is lost.
+	#[inline]
+	#[must_use]
+	pub fn into_inner(self) -> I {
+		self.iter
+	}
+}
+
+impl<I: Iterator<Item = u8>> DecodeUtf8<I> {
+	#[inline]
+	fn next_byte(&mut self) -> Option<u8> {
+		self.pending.take().or_else(|| self.iter.next())
+	}
+
+	/// Creates an error for the `len` bytes just consumed, which end at the current position.
+	#[inline]
+	fn error(&self, bytes: [u8; 4], len: usize, kind: Utf8ErrorKind) -> DecodeError {
+		DecodeError { bytes: [bytes[0], bytes[1], bytes[2]], len: len as u8, position: self.position - len, kind }
+	}
+}
+
+impl<I: Iterator<Item = u8>> Iterator for DecodeUtf8<I> {
+	type Item = Result<char, DecodeError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let lead = self.next_byte()?;
+		// the number of continuation bytes and the valid range of the first one
+		let (needed, first) = match lead {
+			0x00..=0x7F => {
+				self.position += 1;
+				return Some(Ok(char::from(lead)));
+			}
+			0xC2..=0xDF => (1, 0x80..=0xBF),
+			0xE0 => (2, 0xA0..=0xBF),
+			0xED => (2, 0x80..=0x9F),
+			0xE1..=0xEF => (2, 0x80..=0xBF),
+			0xF0 => (3, 0x90..=0xBF),
+			0xF1..=0xF3 => (3, 0x80..=0xBF),
+			0xF4 => (3, 0x80..=0x8F),
+			_ => {
+				self.position += 1;
+				let kind = Utf8ErrorKind::classify(&[lead], None);
+				return Some(Err(self.error([lead, 0, 0, 0], 1, kind)));
+			}
+		};
+
+		let mut bytes = [lead, 0, 0, 0];
+		let mut len = 1;
+		while len <= needed {
+			let range = if len == 1 { first.clone() } else { 0x80..=0xBF };
+			match self.next_byte() {
+				Some(byte) if range.contains(&byte) => {
+					bytes[len] = byte;
+					len += 1;
+				}
+				next => {
+					self.pending = next;
+					let kind = Utf8ErrorKind::classify(&bytes[..len], next);
+					self.position += len;
+					return Some(Err(self.error(bytes, len, kind)));
+				}
+			}
+		}
+
+		self.position += len;
+		let s = std::str::from_utf8(&bytes[..len]).expect("sequence was validated");
+		s.chars().next().map(Ok)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let pending = usize::from(self.pending.is_some());
+		let (lower, upper) = self.iter.size_hint();
+		(lower.saturating_add(pending).div_ceil(4), upper.and_then(|n| n.checked_add(pending)))
+	}
+}
+
+impl<I: FusedIterator<Item = u8>> FusedIterator for DecodeUtf8<I> {}
+
+/// An iterator that lossily decodes UTF-8 from an iterator of bytes.
+///
+/// Created by [`DecodeUtf8Ext::decode_utf8_lossy`].
+#[derive(Debug, Clone)]
+pub struct DecodeUtf8Lossy<I> {
+	inner: DecodeUtf8<I>,
+}
+
+impl<I: Iterator<Item = u8>> Iterator for DecodeUtf8Lossy<I> {
+	type Item = char;
+
+	#[inline]
+	fn next(&mut self) -> Option<char> {
+		self.inner.next().map(|c| {
+			c.unwrap_or_else(|err| {
+				crate::strict::replaced_invalid(err.position(), "decode_utf8_lossy");
+				UNICODE_REPLACEMENT_CHARACTER
+			})
+		})
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+}
+
+impl<I: FusedIterator<Item = u8>> FusedIterator for DecodeUtf8Lossy<I> {}
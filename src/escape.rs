@@ -0,0 +1,214 @@
+// rawstring::escape
+
+use std::{iter::FusedIterator, str::Utf8Chunks};
+
+use crate::{RawStr, RawString};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// How [`RawStr::escape_bytes`] and related methods render bytes as printable ASCII.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum EscapeStyle {
+	/// The escapes of the [`Debug`](std::fmt::Debug) output of [`RawStr`], without the quotes:
+	/// Rust escapes for special ASCII characters, `\u{...}` for non-printable characters,
+	/// and `\xNN` for bytes of invalid sequences. Printable characters are not escaped.
+	#[default]
+	Debug,
+	/// Like [`<[u8]>::escape_ascii`]: every byte outside printable ASCII is escaped,
+	/// including those of valid characters.
+	Ascii,
+	/// Two lowercase hex digits per byte.
+	Hex,
+}
+
+/// The escaped form of one character or invalid sequence.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EscapedUnit {
+	buf: [u8; 16],
+	len: u8,
+}
+
+impl EscapedUnit {
+	const EMPTY: Self = Self { buf: [0; 16], len: 0 };
+
+	#[inline]
+	fn push(&mut self, bytes: &[u8]) {
+		let len = usize::from(self.len);
+		self.buf[len..len + bytes.len()].copy_from_slice(bytes);
+		self.len += bytes.len() as u8;
+	}
+
+	#[inline]
+	fn push_hex_escape(&mut self, byte: u8) {
+		self.push(&[b'\\', b'x', HEX_DIGITS[usize::from(byte >> 4)], HEX_DIGITS[usize::from(byte & 0xF)]]);
+	}
+
+	#[inline]
+	pub(crate) fn as_bytes(&self) -> &[u8] {
+		&self.buf[..usize::from(self.len)]
+	}
+}
+
+impl EscapeStyle {
+	/// Escapes a valid character.
+	pub(crate) fn escape_char(self, c: char) -> EscapedUnit {
+		let mut unit = EscapedUnit::EMPTY;
+		match (self, c) {
+			(Self::Debug, '\0') => unit.push(b"\\0"),
+			(Self::Debug | Self::Ascii, '\0'..='\x7F') => {
+				for b in (c as u8).escape_ascii() {
+					unit.push(&[b]);
+				}
+			}
+			(Self::Debug, _) => {
+				for e in c.escape_debug() {
+					unit.push(e.encode_utf8(&mut [0; 4]).as_bytes());
+				}
+			}
+			(Self::Ascii, _) => {
+				for &b in c.encode_utf8(&mut [0; 4]).as_bytes() {
+					unit.push_hex_escape(b);
+				}
+			}
+			(Self::Hex, _) => self.escape_invalid_into(c.encode_utf8(&mut [0; 4]).as_bytes(), &mut unit),
+		}
+		unit
+	}
+
+	/// Escapes an invalid sequence of at most three bytes.
+	pub(crate) fn escape_invalid(self, invalid: &[u8]) -> EscapedUnit {
+		let mut unit = EscapedUnit::EMPTY;
+		self.escape_invalid_into(invalid, &mut unit);
+		unit
+	}
+
+	#[inline]
+	fn escape_invalid_into(self, bytes: &[u8], unit: &mut EscapedUnit) {
+		for &b in bytes {
+			match self {
+				Self::Debug | Self::Ascii => unit.push_hex_escape(b),
+				Self::Hex => unit.push(&[HEX_DIGITS[usize::from(b >> 4)], HEX_DIGITS[usize::from(b & 0xF)]]),
+			}
+		}
+	}
+}
+
+impl RawStr {
+	/// Returns an iterator over the bytes of the [`RawStr`] escaped in the given style.
+	///
+	/// The iterator knows its exact length up front, as given by [`RawStr::escaped_len`],
+	/// so collecting it allocates once.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{EscapeStyle, RawStr, RawString};
+	/// let raw = RawStr::new(b"\t\xC3\xA9\xFF");
+	/// let escaped: RawString = raw.escape_bytes(EscapeStyle::Debug).collect();
+	/// assert_eq!(escaped, RawString::from("\\t\u{e9}\\xff"));
+	/// assert_eq!(raw.escape_bytes(EscapeStyle::Ascii).len(), 14);
+	/// assert!(raw.escape_bytes(EscapeStyle::Hex).eq(*b"09c3a9ff"));
+	/// ```
+	#[inline]
+	pub fn escape_bytes(&self, style: EscapeStyle) -> EscapeBytes<'_> {
+		EscapeBytes {
+			chunks: self.utf8_chunks(),
+			valid: "".chars(),
+			invalid: &[],
+			style,
+			unit: EscapedUnit::EMPTY,
+			pos: 0,
+			remaining: self.escaped_len(style),
+		}
+	}
+
+	/// Returns the exact number of bytes that escaping the [`RawStr`] in the given style produces.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{EscapeStyle, RawStr};
+	/// let raw = RawStr::new(b"a\"\n\xFF");
+	/// assert_eq!(raw.escaped_len(EscapeStyle::Debug), r#"a\"\n\xff"#.len());
+	/// assert_eq!(raw.escaped_len(EscapeStyle::Hex), 8);
+	/// ```
+	#[must_use]
+	pub fn escaped_len(&self, style: EscapeStyle) -> usize {
+		self.utf8_chunks()
+			.map(|chunk| {
+				let valid: usize = chunk.valid().chars().map(|c| usize::from(style.escape_char(c).len)).sum();
+				valid + usize::from(style.escape_invalid(chunk.invalid()).len)
+			})
+			.sum()
+	}
+
+	/// Appends the bytes of the [`RawStr`] escaped in the given style to `buf`,
+	/// reserving the exact space needed first.
+	///
+	/// Unlike most `_into` methods, this does not clear `buf`, so that a serializer can write
+	/// a frame header first. The number of bytes appended is [`RawStr::escaped_len`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{EscapeStyle, RawStr, RawString};
+	/// let value = RawStr::new(b"caf\xE9");
+	/// let mut frame = RawString::new();
+	/// frame.extend_from_slice(&(value.escaped_len(EscapeStyle::Ascii) as u16).to_be_bytes());
+	/// value.write_escaped_into(&mut frame, EscapeStyle::Ascii);
+	/// assert_eq!(frame, RawString::from(b"\x00\x07caf\\xe9"));
+	/// ```
+	pub fn write_escaped_into(&self, buf: &mut RawString, style: EscapeStyle) {
+		buf.reserve_exact(self.escaped_len(style));
+		for chunk in self.utf8_chunks() {
+			for c in chunk.valid().chars() {
+				buf.extend_from_slice(style.escape_char(c).as_bytes());
+			}
+			buf.extend_from_slice(style.escape_invalid(chunk.invalid()).as_bytes());
+		}
+	}
+}
+
+/// An iterator over the escaped bytes of a [`RawStr`].
+///
+/// Created by [`RawStr::escape_bytes`].
+#[derive(Debug, Clone)]
+pub struct EscapeBytes<'a> {
+	chunks: Utf8Chunks<'a>,
+	valid: std::str::Chars<'a>,
+	invalid: &'a [u8],
+	style: EscapeStyle,
+	unit: EscapedUnit,
+	pos: u8,
+	remaining: usize,
+}
+
+impl Iterator for EscapeBytes<'_> {
+	type Item = u8;
+
+	fn next(&mut self) -> Option<u8> {
+		while self.pos == self.unit.len {
+			self.unit = if let Some(c) = self.valid.next() {
+				self.style.escape_char(c)
+			} else if !self.invalid.is_empty() {
+				self.style.escape_invalid(std::mem::take(&mut self.invalid))
+			} else {
+				let chunk = self.chunks.next()?;
+				self.valid = chunk.valid().chars();
+				self.invalid = chunk.invalid();
+				continue;
+			};
+			self.pos = 0;
+		}
+		self.pos += 1;
+		self.remaining -= 1;
+		Some(self.unit.buf[usize::from(self.pos - 1)])
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl ExactSizeIterator for EscapeBytes<'_> {}
+
+impl FusedIterator for EscapeBytes<'_> {}
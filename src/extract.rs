@@ -0,0 +1,96 @@
+// rawstring::extract
+
+use std::borrow::Cow;
+
+use crate::RawStr;
+
+impl RawStr {
+	/// Matches the [`RawStr`] against a `scanf`-style `pattern`, returning the spans captured by
+	/// each `%` in the pattern.
+	///
+	/// The rest of the pattern is matched literally, with `%%` standing for a literal `%`;
+	/// in a run of an odd number of `%`s, the capture comes first.
+	/// The whole [`RawStr`] must match: the text before the first `%` must be a prefix and the
+	/// text after the last `%` must be a suffix. Each capture but the last is as short as possible,
+	/// ending at the first occurrence of the literal text that follows it; the last capture takes
+	/// everything up to the suffix.
+	///
+	/// Returns `None` if the [`RawStr`] does not match.
+	///
+	/// # Panics
+	/// Panics if the number of captures in `pattern` is not `N`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let line = RawStr::new(b"GET /caf\xE9?q=1 HTTP/1.1");
+	/// let [path, version] = line.extract("GET % HTTP/%").unwrap();
+	/// assert_eq!(path, RawStr::new(b"/caf\xE9?q=1"));
+	/// assert_eq!(version, RawStr::new("1.1"));
+	///
+	/// // earlier captures stop at the first match of the following text
+	/// let [key, value] = RawStr::new("a=b=c;").extract("%=%;").unwrap();
+	/// assert_eq!((key, value), (RawStr::new("a"), RawStr::new("b=c")));
+	///
+	/// let [done] = RawStr::new("100% done").extract("%%% done").unwrap();
+	/// assert_eq!(done, RawStr::new("100"));
+	///
+	/// assert_eq!(RawStr::new("POST / HTTP/1.1").extract::<2, _>("GET % HTTP/%"), None);
+	/// ```
+	#[must_use]
+	pub fn extract<const N: usize, P>(&self, pattern: &P) -> Option<[&RawStr; N]>
+	where
+		P: ?Sized + AsRef<[u8]>
+	{
+		let literals = pattern_literals(pattern.as_ref());
+		assert_eq!(literals.len(), N + 1, "pattern has {} captures, expected {N}", literals.len() - 1);
+
+		let mut rest = self.strip_prefix(literals[0].as_ref())?;
+		let mut captures = [RawStr::new(b""); N];
+		if let Some((last, init)) = captures.split_last_mut() {
+			for (capture, literal) in init.iter_mut().zip(&literals[1..N]) {
+				let end = RawStr::new(rest).find(literal.as_ref())?;
+				*capture = RawStr::new(&rest[..end]);
+				rest = &rest[end + literal.len()..];
+			}
+			*last = RawStr::new(rest.strip_suffix(literals[N].as_ref())?);
+		} else if !rest.is_empty() {
+			return None;
+		}
+		Some(captures)
+	}
+}
+
+/// Splits `pattern` on its captures, unescaping `%%` in the literal text between them.
+///
+/// In a run of an odd number of `%`s, the capture comes first.
+fn pattern_literals(pattern: &[u8]) -> Vec<Cow<'_, [u8]>> {
+	let mut literals = Vec::new();
+	let mut literal = Cow::Borrowed(&pattern[..0]);
+	let mut start = 0;
+	while let Some(offset) = pattern[start..].iter().position(|&b| b == b'%') {
+		let run_start = start + offset;
+		let run = pattern[run_start..].iter().take_while(|&&b| b == b'%').count();
+		literal = join(literal, &pattern[start..run_start]);
+		if run % 2 == 1 {
+			literals.push(literal);
+			literal = Cow::Borrowed(&pattern[..0]);
+		}
+		if run > 1 {
+			literal.to_mut().extend(std::iter::repeat_n(b'%', run / 2));
+		}
+		start = run_start + run;
+	}
+	literals.push(join(literal, &pattern[start..]));
+	literals
+}
+
+/// Appends `text` to `literal`, borrowing `text` if `literal` is empty.
+fn join<'a>(literal: Cow<'a, [u8]>, text: &'a [u8]) -> Cow<'a, [u8]> {
+	if literal.is_empty() {
+		return Cow::Borrowed(text);
+	}
+	let mut literal = literal.into_owned();
+	literal.extend_from_slice(text);
+	Cow::Owned(literal)
+}
@@ -0,0 +1,191 @@
+// rawstring::fixed
+
+use std::{error::Error, fmt, ops::Range};
+
+use crate::{ErrorOffset, RawStr, RawString};
+
+/// An error returned when parsing a fixed-width numeric field.
+///
+/// Created by [`RawStr::parse_fixed_ascii_u64`] and [`RawStr::parse_fixed_ascii_u64_radix`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FixedFieldError {
+	field: Range<usize>,
+	position: usize,
+	kind: FixedFieldErrorKind,
+}
+
+/// The kind of a [`FixedFieldError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FixedFieldErrorKind {
+	/// The field extends past the end of the data.
+	OutOfBounds,
+	/// The field contains only padding.
+	Empty,
+	/// The field contains a byte that is neither a digit nor padding.
+	InvalidDigit,
+	/// The value does not fit in a `u64`.
+	Overflow,
+}
+
+impl FixedFieldError {
+	/// Returns the byte range of the field.
+	#[inline]
+	#[must_use]
+	pub fn field(&self) -> Range<usize> {
+		self.field.clone()
+	}
+
+	/// Returns the position of the offending byte, or the start of the field
+	/// if the error concerns the field as a whole.
+	#[inline]
+	#[must_use]
+	pub const fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Returns the kind of error.
+	#[inline]
+	#[must_use]
+	pub const fn kind(&self) -> FixedFieldErrorKind {
+		self.kind
+	}
+}
+
+impl fmt::Display for FixedFieldError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let Range { start, end } = self.field;
+		match self.kind {
+			FixedFieldErrorKind::OutOfBounds => write!(f, "field {start}..{end} is out of bounds"),
+			FixedFieldErrorKind::Empty => write!(f, "field {start}..{end} is empty"),
+			FixedFieldErrorKind::InvalidDigit => write!(f, "invalid digit in field {start}..{end} at byte {}", self.position),
+			FixedFieldErrorKind::Overflow => write!(f, "number in field {start}..{end} overflows at byte {}", self.position),
+		}
+	}
+}
+
+impl Error for FixedFieldError {}
+
+impl ErrorOffset for FixedFieldError {
+	#[inline]
+	fn error_offset(&self) -> Option<usize> {
+		Some(self.position)
+	}
+}
+
+impl RawStr {
+	/// Parses the decimal number in the fixed-width field at `range`.
+	///
+	/// See [`RawStr::parse_fixed_ascii_u64_radix`] for the accepted padding.
+	///
+	/// # Errors
+	/// Returns a [`FixedFieldError`] with the field range if the field is out of bounds,
+	/// blank, contains anything other than digits and padding, or overflows a `u64`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{FixedFieldErrorKind, RawStr};
+	/// // a FITS header card: the value is right-justified in bytes 10..30
+	/// let card = RawStr::new("NAXIS1  =                 2048 / length of data axis 1");
+	/// assert_eq!(card.parse_fixed_ascii_u64(10..30), Ok(2048));
+	///
+	/// let record = RawStr::new(b"ACCT0042  12x4");
+	/// assert_eq!(record.parse_fixed_ascii_u64(4..8), Ok(42));
+	/// let err = record.parse_fixed_ascii_u64(8..14).unwrap_err();
+	/// assert_eq!(err.kind(), FixedFieldErrorKind::InvalidDigit);
+	/// assert_eq!(err.to_string(), "invalid digit in field 8..14 at byte 12");
+	/// ```
+	#[inline]
+	pub fn parse_fixed_ascii_u64(&self, range: Range<usize>) -> Result<u64, FixedFieldError> {
+		self.parse_fixed_ascii_u64_radix(range, 10)
+	}
+
+	/// Parses the number in base `radix` in the fixed-width field at `range`.
+	///
+	/// The digits may be preceded by spaces and followed by spaces or NUL bytes, which covers
+	/// right-justified fields padded with spaces or zeros as well as the NUL-terminated octal
+	/// fields of tar headers. Letters are accepted as digits in either case, as with
+	/// [`u64::from_str_radix`], but signs are not.
+	///
+	/// # Errors
+	/// Returns a [`FixedFieldError`] with the field range if the field is out of bounds,
+	/// blank, contains anything other than digits and padding, or overflows a `u64`.
+	///
+	/// # Panics
+	/// Panics if `radix` is not in the range from 2 to 36.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{FixedFieldErrorKind, RawStr};
+	/// // the mode and size fields of a tar header
+	/// let mut header = [0u8; 512];
+	/// header[100..108].copy_from_slice(b"0000644\0");
+	/// header[124..136].copy_from_slice(b"00000001750 ");
+	/// let header = RawStr::new(&header);
+	/// assert_eq!(header.parse_fixed_ascii_u64_radix(100..108, 8), Ok(0o644));
+	/// assert_eq!(header.parse_fixed_ascii_u64_radix(124..136, 8), Ok(1000));
+	///
+	/// let err = header.parse_fixed_ascii_u64_radix(500..520, 8).unwrap_err();
+	/// assert_eq!(err.kind(), FixedFieldErrorKind::OutOfBounds);
+	/// assert_eq!(err.field(), 500..520);
+	/// let err = header.parse_fixed_ascii_u64_radix(0..100, 8).unwrap_err();
+	/// assert_eq!(err.kind(), FixedFieldErrorKind::Empty);
+	/// ```
+	pub fn parse_fixed_ascii_u64_radix(&self, range: Range<usize>, radix: u32) -> Result<u64, FixedFieldError> {
+		assert!((2..=36).contains(&radix), "radix must be in the range from 2 to 36, got {radix}");
+		let error = |position, kind| FixedFieldError { field: range.clone(), position, kind };
+
+		let Some(field) = self.get(range.clone()) else {
+			return Err(error(range.start.min(self.len()), FixedFieldErrorKind::OutOfBounds));
+		};
+		let start = field.iter().position(|&b| b != b' ').unwrap_or(field.len());
+		let end = field[start..].iter().position(|&b| b == b' ' || b == b'\0').map_or(field.len(), |i| start + i);
+		if start == end {
+			return match field[end..].iter().position(|&b| b != b' ' && b != b'\0') {
+				Some(i) => Err(error(range.start + end + i, FixedFieldErrorKind::InvalidDigit)),
+				None => Err(error(range.start, FixedFieldErrorKind::Empty)),
+			};
+		}
+
+		let mut value = 0u64;
+		for (i, &byte) in field[start..end].iter().enumerate() {
+			let position = range.start + start + i;
+			let digit = char::from(byte).to_digit(radix)
+				.ok_or_else(|| error(position, FixedFieldErrorKind::InvalidDigit))?;
+			value = value.checked_mul(radix.into())
+				.and_then(|value| value.checked_add(digit.into()))
+				.ok_or_else(|| error(position, FixedFieldErrorKind::Overflow))?;
+		}
+		if let Some(i) = field[end..].iter().position(|&b| b != b' ' && b != b'\0') {
+			return Err(error(range.start + end + i, FixedFieldErrorKind::InvalidDigit));
+		}
+		Ok(value)
+	}
+}
+
+impl RawString {
+	/// Parses the decimal number in the fixed-width field at `range`.
+	///
+	/// See [`RawStr::parse_fixed_ascii_u64`].
+	///
+	/// # Errors
+	/// Returns a [`FixedFieldError`] if the field cannot be parsed.
+	#[inline]
+	pub fn parse_fixed_ascii_u64(&self, range: Range<usize>) -> Result<u64, FixedFieldError> {
+		self.as_ref().parse_fixed_ascii_u64(range)
+	}
+
+	/// Parses the number in base `radix` in the fixed-width field at `range`.
+	///
+	/// See [`RawStr::parse_fixed_ascii_u64_radix`].
+	///
+	/// # Errors
+	/// Returns a [`FixedFieldError`] if the field cannot be parsed.
+	///
+	/// # Panics
+	/// Panics if `radix` is not in the range from 2 to 36.
+	#[inline]
+	pub fn parse_fixed_ascii_u64_radix(&self, range: Range<usize>, radix: u32) -> Result<u64, FixedFieldError> {
+		self.as_ref().parse_fixed_ascii_u64_radix(range, radix)
+	}
+}
@@ -0,0 +1,173 @@
+// rawstring::fixture
+
+use crate::{RawStr, RawString, Utf8ErrorKind};
+
+/// A well-known invalid UTF-8 sequence, for building test inputs.
+///
+/// Used by [`RawStringBuilder::invalid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InvalidSequence {
+	/// A continuation byte with no lead byte: `80`.
+	LoneContinuation,
+	/// A byte that never appears in UTF-8: `FF`.
+	InvalidByte,
+	/// An overlong two-byte encoding of `/`: `C0 AF`.
+	Overlong,
+	/// An encoded UTF-16 surrogate, `U+D800`: `ED A0 80`.
+	Surrogate,
+	/// An encoding of `U+110000`, above the Unicode range: `F4 90 80 80`.
+	OutOfRange,
+	/// The first two bytes of the three-byte encoding of `€`: `E2 82`.
+	Truncated,
+}
+
+impl InvalidSequence {
+	/// Every invalid sequence, in declaration order.
+	pub const ALL: [Self; 6] = [
+		Self::LoneContinuation,
+		Self::InvalidByte,
+		Self::Overlong,
+		Self::Surrogate,
+		Self::OutOfRange,
+		Self::Truncated,
+	];
+
+	/// Returns the bytes of the sequence.
+	#[inline]
+	#[must_use]
+	pub const fn as_bytes(self) -> &'static [u8] {
+		match self {
+			Self::LoneContinuation => b"\x80",
+			Self::InvalidByte => b"\xFF",
+			Self::Overlong => b"\xC0\xAF",
+			Self::Surrogate => b"\xED\xA0\x80",
+			Self::OutOfRange => b"\xF4\x90\x80\x80",
+			Self::Truncated => b"\xE2\x82",
+		}
+	}
+
+	/// Returns the bytes of the sequence as a [`RawStr`].
+	#[inline]
+	#[must_use]
+	pub const fn as_raw_str(self) -> &'static RawStr {
+		RawStr::new(self.as_bytes())
+	}
+
+	/// Returns the kind of error reported for the first invalid region of the sequence
+	/// by [`RawStr::utf8_error_positions`], when it is followed by valid text.
+	#[inline]
+	#[must_use]
+	pub const fn kind(self) -> Utf8ErrorKind {
+		match self {
+			Self::LoneContinuation | Self::InvalidByte => Utf8ErrorKind::InvalidStartByte,
+			Self::Overlong => Utf8ErrorKind::Overlong,
+			Self::Surrogate => Utf8ErrorKind::Surrogate,
+			Self::OutOfRange => Utf8ErrorKind::OutOfRange,
+			Self::Truncated => Utf8ErrorKind::Truncated,
+		}
+	}
+}
+
+/// A builder for test inputs that mix valid text with specific invalid UTF-8.
+///
+/// Methods return `&mut Self` so calls can be chained, like [`RawStringWriter`](crate::RawStringWriter).
+///
+/// # Examples
+/// ```
+/// # use rawstring::{InvalidSequence, RawString, RawStringBuilder};
+/// let input = RawStringBuilder::new()
+///     .ascii("id=")
+///     .invalid(InvalidSequence::Overlong)
+///     .utf8("€")
+///     .repeat(b"ab", 2)
+///     .invalid(InvalidSequence::Truncated)
+///     .build();
+/// assert_eq!(input, RawString::from(b"id=\xC0\xAF\xE2\x82\xACabab\xE2\x82"));
+/// assert_eq!(input.to_utf8_lossy_with(|_, _| ()), "id=��€abab�");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RawStringBuilder {
+	buf: RawString,
+}
+
+impl RawStringBuilder {
+	/// Creates an empty builder.
+	#[inline]
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Appends ASCII text.
+	///
+	/// # Panics
+	/// Panics if `text` is not ASCII, to catch fixtures that accidentally contain other characters.
+	#[inline]
+	pub fn ascii(&mut self, text: &str) -> &mut Self {
+		assert!(text.is_ascii(), "fixture text is not ASCII: {text:?}");
+		self.bytes(text)
+	}
+
+	/// Appends UTF-8 text.
+	#[inline]
+	pub fn utf8(&mut self, text: &str) -> &mut Self {
+		self.bytes(text)
+	}
+
+	/// Appends an invalid UTF-8 sequence.
+	#[inline]
+	pub fn invalid(&mut self, sequence: InvalidSequence) -> &mut Self {
+		self.bytes(sequence.as_bytes())
+	}
+
+	/// Appends arbitrary bytes.
+	#[inline]
+	pub fn bytes<B>(&mut self, bytes: &B) -> &mut Self
+	where
+		B: ?Sized + AsRef<[u8]>,
+	{
+		self.buf.extend_from_slice(bytes.as_ref());
+		self
+	}
+
+	/// Appends `pattern` `n` times.
+	#[inline]
+	pub fn repeat<B>(&mut self, pattern: &B, n: usize) -> &mut Self
+	where
+		B: ?Sized + AsRef<[u8]>,
+	{
+		for _ in 0..n {
+			self.bytes(pattern);
+		}
+		self
+	}
+
+	/// Returns the number of bytes built so far.
+	#[inline]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.buf.len()
+	}
+
+	/// Returns `true` if nothing has been appended yet.
+	#[inline]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.buf.is_empty()
+	}
+
+	/// Returns a copy of the bytes built so far.
+	#[inline]
+	#[must_use]
+	pub fn build(&self) -> RawString {
+		self.buf.clone()
+	}
+
+	/// Returns the built [`RawString`].
+	#[inline]
+	#[must_use]
+	pub fn into_raw_string(self) -> RawString {
+		self.buf
+	}
+}
@@ -0,0 +1,86 @@
+// rawstring::graphviz
+
+use std::fmt::{self, Write};
+
+use crate::{EscapeStyle, RawStr, RawString, escape::EscapedUnit};
+
+impl RawStr {
+	/// Returns a wrapper that displays the [`RawStr`] as a quoted Graphviz DOT string,
+	/// for use as a node or edge label.
+	///
+	/// Quotes and backslashes are escaped, so that Graphviz does not read escapes such as `\N`
+	/// or `\l` in the data, and line feeds become `\n` line breaks. Other control characters
+	/// and the bytes of invalid sequences are shown as their [`Debug`](fmt::Debug) escapes,
+	/// such as `\t` and `\xff`, so that the output is always valid UTF-8 and the label shows
+	/// exactly which bytes the data holds.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let label = RawStr::new(b"say \"hi\"\\N\n\t\xFF");
+	/// assert_eq!(label.escape_graphviz().to_string(), r#""say \"hi\"\\N\n\\t\\xff""#);
+	/// assert_eq!(format!("n0 [label={}];", RawStr::new("a").escape_graphviz()), r#"n0 [label="a"];"#);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn escape_graphviz(&self) -> EscapeGraphviz<'_> {
+		EscapeGraphviz { raw: self }
+	}
+}
+
+impl RawString {
+	/// Returns a wrapper that displays the [`RawString`] as a quoted Graphviz DOT string.
+	///
+	/// See [`RawStr::escape_graphviz`].
+	#[inline]
+	#[must_use]
+	pub fn escape_graphviz(&self) -> EscapeGraphviz<'_> {
+		self.as_ref().escape_graphviz()
+	}
+}
+
+/// A wrapper that displays a [`RawStr`] as a quoted Graphviz DOT string.
+///
+/// Created by [`RawStr::escape_graphviz`].
+#[derive(Debug, Clone, Copy)]
+pub struct EscapeGraphviz<'a> {
+	raw: &'a RawStr,
+}
+
+/// Writes a [`Debug`](fmt::Debug) escape with its backslashes escaped in turn.
+fn write_shown_escape(f: &mut fmt::Formatter<'_>, unit: EscapedUnit) -> fmt::Result {
+	for &b in unit.as_bytes() {
+		if b == b'\\' {
+			f.write_str("\\\\")?;
+		} else {
+			f.write_char(char::from(b))?;
+		}
+	}
+	Ok(())
+}
+
+impl fmt::Display for EscapeGraphviz<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_char('"')?;
+		for chunk in self.raw.utf8_chunks() {
+			let valid = chunk.valid();
+			let mut start = 0;
+			for (i, c) in valid.char_indices().filter(|&(_, c)| matches!(c, '"' | '\\') || c.is_control()) {
+				f.write_str(&valid[start..i])?;
+				match c {
+					'"' => f.write_str("\\\"")?,
+					'\\' => f.write_str("\\\\")?,
+					'\n' => f.write_str("\\n")?,
+					_ => write_shown_escape(f, EscapeStyle::Debug.escape_char(c))?,
+				}
+				start = i + c.len_utf8();
+			}
+			f.write_str(&valid[start..])?;
+
+			if !chunk.invalid().is_empty() {
+				write_shown_escape(f, EscapeStyle::Debug.escape_invalid(chunk.invalid()))?;
+			}
+		}
+		f.write_char('"')
+	}
+}
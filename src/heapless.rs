@@ -0,0 +1,115 @@
+// rawstring::heapless
+
+//! Conversions to and from the fixed-capacity collections of the `heapless` crate.
+
+use std::{error::Error, fmt, str::Utf8Error};
+
+use heapless::CapacityError;
+
+use crate::RawStr;
+
+/// Views a `heapless::Vec<u8, N>` as a [`RawStr`] without copying.
+/// Requires the `heapless` feature.
+///
+/// # Examples
+/// ```
+/// # use rawstring::RawStr;
+/// let mut buf = heapless::Vec::<u8, 16>::new();
+/// buf.extend_from_slice(b"temp=\xFF").unwrap();
+/// let raw: &RawStr = (&buf).into();
+/// assert_eq!(raw.strip_prefix(b"temp="), Some(&b"\xFF"[..]));
+/// ```
+impl<'a, const N: usize> From<&'a heapless::Vec<u8, N>> for &'a RawStr {
+	#[inline]
+	fn from(vec: &'a heapless::Vec<u8, N>) -> Self {
+		RawStr::new(vec.as_slice())
+	}
+}
+
+/// Views a `heapless::Vec<u8, N>` as a mutable [`RawStr`] without copying.
+/// Requires the `heapless` feature.
+impl<'a, const N: usize> From<&'a mut heapless::Vec<u8, N>> for &'a mut RawStr {
+	#[inline]
+	fn from(vec: &'a mut heapless::Vec<u8, N>) -> Self {
+		RawStr::new_mut(vec.as_mut_slice())
+	}
+}
+
+/// Copies a [`RawStr`] into a `heapless::Vec<u8, N>`, failing if it is longer than `N` bytes.
+/// Requires the `heapless` feature.
+///
+/// # Examples
+/// ```
+/// # use rawstring::RawStr;
+/// let vec = heapless::Vec::<u8, 4>::try_from(RawStr::new(b"\xFF\xFE")).unwrap();
+/// assert_eq!(vec, b"\xFF\xFE");
+/// assert!(heapless::Vec::<u8, 4>::try_from(RawStr::new("too long")).is_err());
+/// ```
+impl<const N: usize> TryFrom<&RawStr> for heapless::Vec<u8, N> {
+	type Error = CapacityError;
+
+	#[inline]
+	fn try_from(raw: &RawStr) -> Result<Self, CapacityError> {
+		heapless::Vec::from_slice(raw)
+	}
+}
+
+/// Copies a [`RawStr`] into a `heapless::String<N>`, failing if it is not valid UTF-8
+/// or is longer than `N` bytes. Requires the `heapless` feature.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{HeaplessStringError, RawStr};
+/// let s = heapless::String::<8>::try_from(RawStr::new("ok")).unwrap();
+/// assert_eq!(s, "ok");
+///
+/// let err = heapless::String::<8>::try_from(RawStr::new(b"\xFF")).unwrap_err();
+/// assert!(matches!(err, HeaplessStringError::InvalidUtf8(_)));
+/// let err = heapless::String::<2>::try_from(RawStr::new("too long")).unwrap_err();
+/// assert_eq!(err.to_string(), "8 bytes do not fit in a capacity of 2");
+/// ```
+impl<const N: usize> TryFrom<&RawStr> for heapless::String<N> {
+	type Error = HeaplessStringError;
+
+	fn try_from(raw: &RawStr) -> Result<Self, HeaplessStringError> {
+		let s = raw.to_utf8_checked().map_err(HeaplessStringError::InvalidUtf8)?;
+		let mut string = heapless::String::new();
+		string.push_str(s).map_err(|_| HeaplessStringError::Capacity { len: s.len(), capacity: N })?;
+		Ok(string)
+	}
+}
+
+/// An error returned when converting a [`RawStr`] into a `heapless::String`.
+/// Requires the `heapless` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HeaplessStringError {
+	/// The [`RawStr`] is not valid UTF-8.
+	InvalidUtf8(Utf8Error),
+	/// The [`RawStr`] is longer than the capacity of the string.
+	Capacity {
+		/// The length of the [`RawStr`] in bytes.
+		len: usize,
+		/// The capacity of the string in bytes.
+		capacity: usize,
+	},
+}
+
+impl fmt::Display for HeaplessStringError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::InvalidUtf8(err) => write!(f, "invalid UTF-8: {err}"),
+			Self::Capacity { len, capacity } => write!(f, "{len} bytes do not fit in a capacity of {capacity}"),
+		}
+	}
+}
+
+impl Error for HeaplessStringError {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::InvalidUtf8(err) => Some(err),
+			Self::Capacity { .. } => None,
+		}
+	}
+}
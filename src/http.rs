@@ -0,0 +1,358 @@
+// rawstring::http
+
+//! Helpers for HTTP header names and values, following [RFC 9110], and for the chunked
+//! transfer coding of [RFC 9112].
+//!
+//! [RFC 9110]: https://www.rfc-editor.org/rfc/rfc9110
+//! [RFC 9112]: https://www.rfc-editor.org/rfc/rfc9112
+
+use std::{error::Error, fmt, iter::FusedIterator};
+
+use crate::{ErrorOffset, RawStr, RawString};
+
+/// Returns `true` if `byte` is a `tchar`, i.e. may appear in an HTTP token.
+#[inline]
+#[must_use]
+pub const fn is_tchar(byte: u8) -> bool {
+	matches!(byte,
+		b'!' | b'#' | b'$' | b'%' | b'&' | b'\'' | b'*' | b'+' | b'-' | b'.'
+		| b'^' | b'_' | b'`' | b'|' | b'~'
+		| b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z'
+	)
+}
+
+/// Returns `true` if `byte` is optional whitespace (`OWS`): a space or horizontal tab.
+#[inline]
+#[must_use]
+pub const fn is_ows(byte: u8) -> bool {
+	matches!(byte, b' ' | b'\t')
+}
+
+impl RawStr {
+	/// Returns `true` if the [`RawStr`] is a non-empty HTTP token,
+	/// such as a header name or method.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert!(RawStr::new("Content-Type").is_token());
+	/// assert!(!RawStr::new("Content Type").is_token());
+	/// assert!(!RawStr::new("").is_token());
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn is_token(&self) -> bool {
+		!self.is_empty() && self.iter().all(|&b| is_tchar(b))
+	}
+
+	/// Returns the [`RawStr`] with leading and trailing HTTP optional whitespace
+	/// (spaces and horizontal tabs) removed.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(" \tgzip ").trim_ows(), "gzip");
+	/// assert_eq!(RawStr::new(b"\t\xFF ").trim_ows(), b"\xFF");
+	/// ```
+	#[must_use]
+	pub fn trim_ows(&self) -> &RawStr {
+		let start = self.iter().position(|&b| !is_ows(b)).unwrap_or(self.len());
+		let end = self.iter().rposition(|&b| !is_ows(b)).map_or(start, |i| i + 1);
+		RawStr::new(&self[start..end])
+	}
+
+	/// Returns `true` if the [`RawStr`] equals the header name `name`, ignoring ASCII case.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert!(RawStr::new("content-LENGTH").eq_header_name("Content-Length"));
+	/// assert!(!RawStr::new("Content-Length ").eq_header_name("Content-Length"));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn eq_header_name(&self, name: &str) -> bool {
+		self.eq_ignore_ascii_case(name.as_bytes())
+	}
+
+	/// Returns `true` if the [`RawStr`] starts with `prefix`, ignoring ASCII case.
+	///
+	/// The prefix length is a compile-time constant, which lets the comparison be fully unrolled.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert!(RawStr::new("Chunked, gzip").starts_with_ignore_ascii_case(b"chunked"));
+	/// assert!(!RawStr::new("gzip").starts_with_ignore_ascii_case(b"chunked"));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn starts_with_ignore_ascii_case<const N: usize>(&self, prefix: &[u8; N]) -> bool {
+		if self.0.len() < N {
+			return false;
+		}
+		let mut i = 0;
+		while i < N {
+			if !self.0[i].eq_ignore_ascii_case(&prefix[i]) {
+				return false;
+			}
+			i += 1;
+		}
+		true
+	}
+
+	/// Splits a comma-separated HTTP header value list into its elements.
+	///
+	/// Commas inside quoted strings are ignored, elements are trimmed of optional whitespace,
+	/// and empty elements are skipped, as required by the list syntax of RFC 9110.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let value = RawStr::new(r#"gzip, , foo="a, \"b\"" ,br"#);
+	/// let elements: Vec<_> = value.split_header_value_list().collect();
+	/// assert_eq!(elements, [
+	///     RawStr::new("gzip"),
+	///     RawStr::new(r#"foo="a, \"b\"""#),
+	///     RawStr::new("br"),
+	/// ]);
+	/// ```
+	#[inline]
+	pub fn split_header_value_list(&self) -> ValueList<'_> {
+		ValueList { rest: &self.0 }
+	}
+}
+
+/// An iterator over the elements of a comma-separated HTTP header value list.
+///
+/// Created by [`RawStr::split_header_value_list`].
+#[derive(Debug, Clone)]
+pub struct ValueList<'a> {
+	rest: &'a [u8],
+}
+
+impl<'a> Iterator for ValueList<'a> {
+	type Item = &'a RawStr;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while !self.rest.is_empty() {
+			let mut quoted = false;
+			let mut escaped = false;
+			let end = self.rest.iter()
+				.position(|&b| {
+					match b {
+						_ if escaped => escaped = false,
+						b'\\' if quoted => escaped = true,
+						b'"' => quoted = !quoted,
+						b',' if !quoted => return true,
+						_ => {}
+					}
+					false
+				})
+				.unwrap_or(self.rest.len());
+			let element = RawStr::new(&self.rest[..end]).trim_ows();
+			self.rest = self.rest.get(end + 1..).unwrap_or_default();
+			if !element.is_empty() {
+				return Some(element);
+			}
+		}
+		None
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		// non-empty elements are separated by commas
+		(0, Some(self.rest.len().div_ceil(2)))
+	}
+}
+
+impl FusedIterator for ValueList<'_> {}
+
+/// An error returned when decoding a message body in the chunked transfer coding.
+///
+/// Created by [`RawStr::decode_chunked`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChunkedError {
+	position: usize,
+	kind: ChunkedErrorKind,
+}
+
+/// The kind of a [`ChunkedError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChunkedErrorKind {
+	/// The input ends before the last chunk and the trailer section; more data is needed.
+	Incomplete,
+	/// A chunk size line does not start with hex digits, or has something other than
+	/// whitespace or chunk extensions after them.
+	InvalidChunkSize,
+	/// A chunk size does not fit in a `usize`.
+	Overflow,
+	/// The data of a chunk is not followed by `\r\n`.
+	MissingCrlf,
+	/// A line ends in a bare `\n`. This is rejected rather than tolerated because servers
+	/// that disagree about it can be exploited by request smuggling.
+	BareLf,
+}
+
+impl ChunkedError {
+	/// Returns the position at which the error was found, which is the length of the input
+	/// for [`ChunkedErrorKind::Incomplete`].
+	#[inline]
+	#[must_use]
+	pub const fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Returns the kind of error.
+	#[inline]
+	#[must_use]
+	pub const fn kind(&self) -> ChunkedErrorKind {
+		self.kind
+	}
+}
+
+impl fmt::Display for ChunkedError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let message = match self.kind {
+			ChunkedErrorKind::Incomplete => "incomplete chunked body",
+			ChunkedErrorKind::InvalidChunkSize => "invalid chunk size",
+			ChunkedErrorKind::Overflow => "chunk size too large",
+			ChunkedErrorKind::MissingCrlf => "missing CRLF after chunk data",
+			ChunkedErrorKind::BareLf => "bare LF in chunked body",
+		};
+		write!(f, "{message} at byte {}", self.position)
+	}
+}
+
+impl Error for ChunkedError {}
+
+impl ErrorOffset for ChunkedError {
+	#[inline]
+	fn error_offset(&self) -> Option<usize> {
+		Some(self.position)
+	}
+}
+
+/// Returns the end of the line starting at `start`, which is the position of its `\r\n`.
+fn line_end(bytes: &[u8], start: usize) -> Result<usize, ChunkedError> {
+	let error = |position, kind| ChunkedError { position, kind };
+	let lf = bytes[start..]
+		.iter()
+		.position(|&b| b == b'\n')
+		.ok_or(error(bytes.len(), ChunkedErrorKind::Incomplete))?;
+	match (start + lf).checked_sub(1) {
+		Some(cr) if cr >= start && bytes[cr] == b'\r' => Ok(cr),
+		_ => Err(error(start + lf, ChunkedErrorKind::BareLf)),
+	}
+}
+
+/// Parses the chunk size at the start of `line`, which starts at `start` in the input.
+fn parse_chunk_size(line: &[u8], start: usize) -> Result<usize, ChunkedError> {
+	let error = |position, kind| ChunkedError { position, kind };
+	let digits = line.iter().take_while(|b| b.is_ascii_hexdigit()).count();
+	if digits == 0 {
+		return Err(error(start, ChunkedErrorKind::InvalidChunkSize));
+	}
+	let mut size: usize = 0;
+	for &b in &line[..digits] {
+		let digit = char::from(b).to_digit(16).unwrap_or_default() as usize;
+		size = size
+			.checked_mul(16)
+			.and_then(|size| size.checked_add(digit))
+			.ok_or(error(start, ChunkedErrorKind::Overflow))?;
+	}
+	let ows = line[digits..].iter().take_while(|&&b| is_ows(b)).count();
+	match line.get(digits + ows) {
+		None | Some(b';') => Ok(size),
+		Some(_) => Err(error(start + digits + ows, ChunkedErrorKind::InvalidChunkSize)),
+	}
+}
+
+impl RawStr {
+	/// Decodes a message body in the chunked transfer coding, returning the body and the rest
+	/// of the input after it, such as the next pipelined message.
+	///
+	/// Chunk extensions are ignored, and the trailer section is checked for line endings
+	/// but discarded. Lines must end in `\r\n`.
+	///
+	/// # Errors
+	/// Returns a [`ChunkedError`] of kind [`ChunkedErrorKind::Incomplete`] if the input ends
+	/// before the body does, so that the caller can read more and retry, and of another kind
+	/// if the framing is invalid.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString, http::ChunkedErrorKind};
+	/// let input = RawStr::new(b"4\r\nWiki\r\n6;note=x\r\n \xFFpedi\r\n0\r\nExpires: never\r\n\r\nGET /");
+	/// let (body, rest) = input.decode_chunked().unwrap();
+	/// assert_eq!(body, RawString::from(b"Wiki \xFFpedi"));
+	/// assert_eq!(rest, "GET /");
+	///
+	/// let err = RawStr::new("4\r\nWi").decode_chunked().unwrap_err();
+	/// assert_eq!(err.kind(), ChunkedErrorKind::Incomplete);
+	/// let err = RawStr::new("4\nWiki\r\n0\r\n\r\n").decode_chunked().unwrap_err();
+	/// assert_eq!((err.kind(), err.position()), (ChunkedErrorKind::BareLf, 1));
+	/// ```
+	pub fn decode_chunked(&self) -> Result<(RawString, &RawStr), ChunkedError> {
+		let bytes = &self.0;
+		let mut body = Vec::new();
+		let mut pos = 0;
+		loop {
+			let end = line_end(bytes, pos)?;
+			let size = parse_chunk_size(&bytes[pos..end], pos)?;
+			pos = end + 2;
+			if size == 0 {
+				break;
+			}
+			if bytes.len() - pos < size.saturating_add(2) {
+				return Err(ChunkedError { position: bytes.len(), kind: ChunkedErrorKind::Incomplete });
+			}
+			body.extend_from_slice(&bytes[pos..pos + size]);
+			pos += size;
+			if &bytes[pos..pos + 2] != b"\r\n" {
+				return Err(ChunkedError { position: pos, kind: ChunkedErrorKind::MissingCrlf });
+			}
+			pos += 2;
+		}
+
+		// the trailer section ends with an empty line
+		loop {
+			let end = line_end(bytes, pos)?;
+			let empty = end == pos;
+			pos = end + 2;
+			if empty {
+				break;
+			}
+		}
+		Ok((RawString::from(body), RawStr::new(&bytes[pos..])))
+	}
+
+	/// Encodes the [`RawStr`] as a message body in the chunked transfer coding, in chunks of
+	/// at most `max_chunk_len` bytes, followed by the last chunk and an empty trailer section.
+	///
+	/// # Panics
+	/// Panics if `max_chunk_len` is zero.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// let body = RawStr::new(b"Wikipedia \xFF");
+	/// let encoded = body.encode_chunked(8);
+	/// assert_eq!(encoded, RawString::from(b"8\r\nWikipedi\r\n3\r\na \xFF\r\n0\r\n\r\n"));
+	/// assert_eq!(encoded.as_ref().decode_chunked().unwrap().0.as_ref(), body);
+	/// ```
+	#[must_use]
+	pub fn encode_chunked(&self, max_chunk_len: usize) -> RawString {
+		assert!(max_chunk_len > 0, "chunks must be at least one byte long");
+		let chunks = self.len().div_ceil(max_chunk_len);
+		let mut out = Vec::with_capacity(self.len() + chunks * 12 + 5);
+		for chunk in self.chunks(max_chunk_len) {
+			out.extend_from_slice(format!("{:x}\r\n", chunk.len()).as_bytes());
+			out.extend_from_slice(chunk);
+			out.extend_from_slice(b"\r\n");
+		}
+		out.extend_from_slice(b"0\r\n\r\n");
+		RawString::from(out)
+	}
+}
@@ -0,0 +1,370 @@
+// rawstring::idna
+
+use std::{error::Error, fmt};
+
+use crate::{ErrorOffset, RawStr, RawString};
+
+// Punycode parameters, from RFC 3492, section 5.
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+
+/// The prefix of labels encoded with Punycode.
+const ACE_PREFIX: &str = "xn--";
+
+/// The maximum length of a label in a domain name, from RFC 1035.
+const MAX_LABEL_LEN: usize = 63;
+
+/// The maximum length of a domain name without its trailing `.`, from RFC 1035.
+const MAX_HOST_LEN: usize = 253;
+
+/// An error returned when converting a host name to or from its ASCII form.
+///
+/// Created by [`RawStr::to_ascii_idna`] and [`RawStr::from_ascii_idna`].
+/// Requires the `idna` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IdnaError {
+	position: usize,
+	kind: IdnaErrorKind,
+}
+
+/// The kind of an [`IdnaError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IdnaErrorKind {
+	/// The host name is not valid UTF-8.
+	InvalidUtf8,
+	/// A label other than the last is empty.
+	EmptyLabel,
+	/// A label is longer than 63 bytes in its ASCII form.
+	LabelTooLong,
+	/// The host name is longer than 253 bytes in its ASCII form, not counting a trailing `.`.
+	HostTooLong,
+	/// The Punycode encoding of a label overflowed.
+	Overflow,
+	/// A label starting with `xn--` is not valid Punycode.
+	InvalidPunycode,
+}
+
+impl IdnaError {
+	/// Returns the position of the first invalid byte for [`IdnaErrorKind::InvalidUtf8`],
+	/// the first byte of the ASCII form past the limit for [`IdnaErrorKind::HostTooLong`],
+	/// and the start of the offending label otherwise.
+	#[inline]
+	#[must_use]
+	pub const fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Returns the kind of error.
+	#[inline]
+	#[must_use]
+	pub const fn kind(&self) -> IdnaErrorKind {
+		self.kind
+	}
+}
+
+impl fmt::Display for IdnaError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.kind {
+			IdnaErrorKind::InvalidUtf8 => write!(f, "invalid UTF-8 in host name at byte {}", self.position),
+			IdnaErrorKind::EmptyLabel => write!(f, "empty label in host name at byte {}", self.position),
+			IdnaErrorKind::LabelTooLong => write!(f, "label at byte {} is longer than {MAX_LABEL_LEN} bytes", self.position),
+			IdnaErrorKind::HostTooLong => write!(f, "host name is longer than {MAX_HOST_LEN} bytes"),
+			IdnaErrorKind::Overflow => write!(f, "Punycode overflow in label at byte {}", self.position),
+			IdnaErrorKind::InvalidPunycode => write!(f, "invalid Punycode in label at byte {}", self.position),
+		}
+	}
+}
+
+impl Error for IdnaError {}
+
+impl ErrorOffset for IdnaError {
+	#[inline]
+	fn error_offset(&self) -> Option<usize> {
+		Some(self.position)
+	}
+}
+
+/// Returns the bias for the next delta, from RFC 3492, section 6.1.
+fn adapt(delta: u32, points: u32, first: bool) -> u32 {
+	let mut delta = if first { delta / DAMP } else { delta / 2 };
+	delta += delta / points;
+	let mut k = 0;
+	while delta > (BASE - T_MIN) * T_MAX / 2 {
+		delta /= BASE - T_MIN;
+		k += BASE;
+	}
+	k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+}
+
+/// Returns the threshold for the digit at position `k`.
+#[inline]
+fn threshold(k: u32, bias: u32) -> u32 {
+	k.saturating_sub(bias).clamp(T_MIN, T_MAX)
+}
+
+#[inline]
+fn encode_digit(d: u32) -> char {
+	char::from(if d < 26 { b'a' + d as u8 } else { b'0' + (d - 26) as u8 })
+}
+
+#[inline]
+fn decode_digit(b: u8) -> Option<u32> {
+	match b {
+		b'a'..=b'z' => Some(u32::from(b - b'a')),
+		b'A'..=b'Z' => Some(u32::from(b - b'A')),
+		b'0'..=b'9' => Some(u32::from(b - b'0') + 26),
+		_ => None,
+	}
+}
+
+/// Appends the Punycode encoding of `label` to `out`, or returns `None` on overflow.
+fn punycode_encode(label: &[char], out: &mut String) -> Option<()> {
+	out.extend(label.iter().filter(|c| c.is_ascii()));
+	let basic = label.iter().filter(|c| c.is_ascii()).count() as u32;
+	let mut handled = basic;
+	if basic > 0 {
+		out.push('-');
+	}
+
+	let mut n = INITIAL_N;
+	let mut delta: u32 = 0;
+	let mut bias = INITIAL_BIAS;
+	while (handled as usize) < label.len() {
+		let m = label.iter().map(|&c| u32::from(c)).filter(|&c| c >= n).min()?;
+		delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+		n = m;
+		for c in label.iter().map(|&c| u32::from(c)) {
+			if c < n {
+				delta = delta.checked_add(1)?;
+			}
+			if c == n {
+				let mut q = delta;
+				let mut k = BASE;
+				loop {
+					let t = threshold(k, bias);
+					if q < t {
+						break;
+					}
+					out.push(encode_digit(t + (q - t) % (BASE - t)));
+					q = (q - t) / (BASE - t);
+					k += BASE;
+				}
+				out.push(encode_digit(q));
+				bias = adapt(delta, handled + 1, handled == basic);
+				delta = 0;
+				handled += 1;
+			}
+		}
+		delta = delta.checked_add(1)?;
+		n = n.checked_add(1)?;
+	}
+	Some(())
+}
+
+/// Decodes the Punycode `label`, without its prefix, or returns `None` if it is invalid.
+fn punycode_decode(label: &[u8]) -> Option<String> {
+	let (basic, digits) = match label.iter().rposition(|&b| b == b'-') {
+		Some(i) => (&label[..i], &label[i + 1..]),
+		None => (&[][..], label),
+	};
+	if !basic.is_ascii() {
+		return None;
+	}
+	let mut output: Vec<char> = basic.iter().map(|&b| char::from(b)).collect();
+
+	let mut n = INITIAL_N;
+	let mut i: u32 = 0;
+	let mut bias = INITIAL_BIAS;
+	let mut digits = digits.iter();
+	while !digits.as_slice().is_empty() {
+		let old_i = i;
+		let mut weight: u32 = 1;
+		let mut k = BASE;
+		loop {
+			let digit = decode_digit(*digits.next()?)?;
+			i = i.checked_add(digit.checked_mul(weight)?)?;
+			let t = threshold(k, bias);
+			if digit < t {
+				break;
+			}
+			weight = weight.checked_mul(BASE - t)?;
+			k += BASE;
+		}
+		let len = output.len() as u32 + 1;
+		bias = adapt(i - old_i, len, old_i == 0);
+		n = n.checked_add(i / len)?;
+		i %= len;
+		output.insert(i as usize, char::from_u32(n).filter(|c| !c.is_ascii())?);
+		i += 1;
+	}
+	Some(output.into_iter().collect())
+}
+
+/// Returns `true` if `c` separates labels, as the full stops of UTS #46 do.
+#[inline]
+fn is_label_separator(c: char) -> bool {
+	matches!(c, '.' | '\u{3002}' | '\u{FF0E}' | '\u{FF61}')
+}
+
+impl RawStr {
+	/// Converts the host name in the [`RawStr`] to its ASCII form for DNS, encoding each
+	/// label that is not ASCII with Punycode and the `xn--` prefix. Requires the `idna` feature.
+	///
+	/// Labels are lowercased, and the ideographic and full-width full stops separate labels
+	/// like `.`. This is the core of the IDNA `ToASCII` operation, but without the Unicode
+	/// normalization and mapping tables of UTS #46, so host names should be in NFC already,
+	/// and disallowed characters are not rejected. A trailing `.` is kept.
+	///
+	/// # Errors
+	/// Returns an [`IdnaError`] if the host name is not valid UTF-8, has an empty label
+	/// before the last, has a label longer than 63 bytes once encoded, which is checked
+	/// before encoding where possible, or is longer than 253 bytes once encoded, not
+	/// counting a trailing `.`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{IdnaErrorKind, RawStr, RawString};
+	/// let host = RawStr::new("B\u{fc}cher.Example\u{3002}");
+	/// assert_eq!(host.to_ascii_idna(), Ok(RawString::from("xn--bcher-kva.example.")));
+	/// assert_eq!(RawStr::new("\u{4f8b}\u{3048}.jp").to_ascii_idna(), Ok(RawString::from("xn--r8jz45g.jp")));
+	///
+	/// let err = RawStr::new(b"b\xFCcher.example").to_ascii_idna().unwrap_err();
+	/// assert_eq!((err.kind(), err.position()), (IdnaErrorKind::InvalidUtf8, 1));
+	///
+	/// let err = RawStr::new(format!("a.{}", "\u{e9}".repeat(60)).as_str()).to_ascii_idna().unwrap_err();
+	/// assert_eq!((err.kind(), err.position()), (IdnaErrorKind::LabelTooLong, 2));
+	/// ```
+	pub fn to_ascii_idna(&self) -> Result<RawString, IdnaError> {
+		let host = self
+			.to_utf8_checked()
+			.map_err(|err| IdnaError { position: err.valid_up_to(), kind: IdnaErrorKind::InvalidUtf8 })?;
+
+		let mut out = String::with_capacity(host.len());
+		let mut labels = host.split(is_label_separator).peekable();
+		let mut start = 0;
+		while let Some(label) = labels.next() {
+			let error = |kind| IdnaError { position: start, kind };
+			let is_last = labels.peek().is_none();
+			if label.is_empty() && !is_last {
+				return Err(error(IdnaErrorKind::EmptyLabel));
+			}
+
+			let label_start = out.len();
+			if label.is_ascii() {
+				out.push_str(&label.to_ascii_lowercase());
+			} else {
+				out.push_str(ACE_PREFIX);
+				let chars: Vec<char> = label.chars().flat_map(char::to_lowercase).collect();
+				// each character encodes to at least one byte, so this bounds the encoding work
+				if ACE_PREFIX.len() + chars.len() > MAX_LABEL_LEN {
+					return Err(error(IdnaErrorKind::LabelTooLong));
+				}
+				punycode_encode(&chars, &mut out).ok_or(error(IdnaErrorKind::Overflow))?;
+			}
+			if out.len() - label_start > MAX_LABEL_LEN {
+				return Err(error(IdnaErrorKind::LabelTooLong));
+			}
+
+			start += label.len();
+			if !is_last {
+				let separator = host[start..].chars().next().map_or(1, char::len_utf8);
+				start += separator;
+				out.push('.');
+			}
+		}
+		if out.strip_suffix('.').unwrap_or(&out).len() > MAX_HOST_LEN {
+			return Err(IdnaError { position: MAX_HOST_LEN, kind: IdnaErrorKind::HostTooLong });
+		}
+		Ok(RawString::from(out))
+	}
+
+	/// Converts the host name in the [`RawStr`] from its ASCII form for DNS, decoding each
+	/// label that starts with `xn--` from Punycode. Requires the `idna` feature.
+	///
+	/// Other labels are kept as they are, including any that are not ASCII, so this can be
+	/// used on host names of unknown form. This is the inverse of [`RawStr::to_ascii_idna`]
+	/// for host names that were lowercase.
+	///
+	/// # Errors
+	/// Returns an [`IdnaError`] if the host name is longer than 253 bytes, not counting a
+	/// trailing `.`, or has a label longer than 63 bytes, both checked before any decoding,
+	/// or if a label starting with `xn--` is not valid Punycode.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{IdnaErrorKind, RawStr, RawString};
+	/// let host = RawStr::new("www.XN--bcher-kva.example");
+	/// assert_eq!(host.from_ascii_idna(), Ok(RawString::from("www.b\u{fc}cher.example")));
+	///
+	/// let err = RawStr::new("ok.xn--a-\u{e9}.example").from_ascii_idna().unwrap_err();
+	/// assert_eq!((err.kind(), err.position()), (IdnaErrorKind::InvalidPunycode, 3));
+	///
+	/// let err = RawStr::new(format!("a.xn--{}", "a".repeat(60)).as_str()).from_ascii_idna().unwrap_err();
+	/// assert_eq!((err.kind(), err.position()), (IdnaErrorKind::LabelTooLong, 2));
+	/// ```
+	pub fn from_ascii_idna(&self) -> Result<RawString, IdnaError> {
+		let host = self.0.strip_suffix(b".").unwrap_or(&self.0);
+		if host.len() > MAX_HOST_LEN {
+			return Err(IdnaError { position: MAX_HOST_LEN, kind: IdnaErrorKind::HostTooLong });
+		}
+		// checked up front, since decoding a label takes time quadratic in its length
+		let mut label_start = 0;
+		for label in self.0.split(|&b| b == b'.') {
+			if label.len() > MAX_LABEL_LEN {
+				return Err(IdnaError { position: label_start, kind: IdnaErrorKind::LabelTooLong });
+			}
+			label_start += label.len() + 1;
+		}
+
+		let mut out = Vec::with_capacity(self.len());
+		let mut start = 0;
+		for (i, label) in self.0.split(|&b| b == b'.').enumerate() {
+			if i > 0 {
+				out.push(b'.');
+			}
+			match label.get(..ACE_PREFIX.len()) {
+				Some(prefix) if prefix.eq_ignore_ascii_case(ACE_PREFIX.as_bytes()) => {
+					let decoded = punycode_decode(&label[ACE_PREFIX.len()..])
+						.ok_or(IdnaError { position: start, kind: IdnaErrorKind::InvalidPunycode })?;
+					out.extend_from_slice(decoded.as_bytes());
+				}
+				_ => out.extend_from_slice(label),
+			}
+			start += label.len() + 1;
+		}
+		Ok(RawString::from(out))
+	}
+}
+
+impl RawString {
+	/// Converts the host name in the [`RawString`] to its ASCII form for DNS.
+	/// Requires the `idna` feature.
+	///
+	/// See [`RawStr::to_ascii_idna`].
+	///
+	/// # Errors
+	/// Returns an [`IdnaError`] if the host name cannot be converted.
+	#[inline]
+	pub fn to_ascii_idna(&self) -> Result<RawString, IdnaError> {
+		self.as_ref().to_ascii_idna()
+	}
+
+	/// Converts the host name in the [`RawString`] from its ASCII form for DNS.
+	/// Requires the `idna` feature.
+	///
+	/// See [`RawStr::from_ascii_idna`].
+	///
+	/// # Errors
+	/// Returns an [`IdnaError`] if the host name or a label is too long, or if a label
+	/// starting with `xn--` is not valid Punycode.
+	#[inline]
+	pub fn from_ascii_idna(&self) -> Result<RawString, IdnaError> {
+		self.as_ref().from_ascii_idna()
+	}
+}
@@ -0,0 +1,82 @@
+// rawstring::in_place
+
+use std::ops::Range;
+
+use crate::{RawStr, SliceError};
+
+impl RawStr {
+	/// Copies `src` into the start of the [`RawStr`], leaving any remaining bytes unchanged.
+	///
+	/// Unlike [`copy_from_slice`](slice::copy_from_slice), `src` may be shorter than the
+	/// [`RawStr`], which suits writing into fixed-size buffers. To set every byte, use
+	/// [`fill`](slice::fill).
+	///
+	/// # Errors
+	/// Returns an error, copying nothing, if `src` is longer than the [`RawStr`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let mut field = [0u8; 8];
+	/// let buf = RawStr::new_mut(&mut field);
+	/// buf.fill(b' ');
+	/// buf.copy_from_raw(RawStr::new("name")).unwrap();
+	/// assert_eq!(buf, "name    ");
+	///
+	/// let err = buf.copy_from_raw(RawStr::new("much too long")).unwrap_err();
+	/// assert_eq!(err.to_string(), "range 0..13 out of bounds for length 8");
+	/// ```
+	pub fn copy_from_raw(&mut self, src: &RawStr) -> Result<(), SliceError> {
+		if src.len() > self.len() {
+			return Err(SliceError::new(0, src.len(), self.len()));
+		}
+		self[..src.len()].copy_from_slice(src);
+		Ok(())
+	}
+
+	/// Swaps the bytes in two non-overlapping ranges of the same length.
+	///
+	/// # Panics
+	/// Panics if the ranges have different lengths, overlap, or are out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let mut data = *b"key=val";
+	/// RawStr::new_mut(&mut data).swap_ranges(0..3, 4..7);
+	/// assert_eq!(&data, b"val=key");
+	/// ```
+	pub fn swap_ranges(&mut self, a: Range<usize>, b: Range<usize>) {
+		assert_eq!(a.len(), b.len(), "ranges to swap have different lengths");
+		let (first, second) = if a.start <= b.start { (a, b) } else { (b, a) };
+		assert!(first.end <= second.start, "ranges to swap overlap");
+		let (head, tail) = self.split_at_mut(second.start);
+		head[first].swap_with_slice(&mut tail[..second.len()]);
+	}
+
+	/// Converts the [`RawStr`] to ASCII title case in place.
+	///
+	/// The first ASCII letter of each word is converted to uppercase and the rest of the word
+	/// to lowercase. A word starts at a byte that does not follow an ASCII letter or digit,
+	/// an apostrophe, or a non-ASCII byte, so non-ASCII characters are treated as letters.
+	/// Non-ASCII bytes are never changed. See [`RawStr::to_title_case`] for Unicode title casing.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let mut data = *b"they're HERE-now \xFFok";
+	/// RawStr::new_mut(&mut data).make_ascii_titlecase();
+	/// assert_eq!(&data, b"They're Here-Now \xFFok");
+	/// ```
+	pub fn make_ascii_titlecase(&mut self) {
+		let mut in_word = false;
+		for byte in self.iter_mut() {
+			if in_word {
+				byte.make_ascii_lowercase();
+			} else {
+				byte.make_ascii_uppercase();
+			}
+			in_word = byte.is_ascii_alphanumeric() || *byte == b'\'' || !byte.is_ascii();
+		}
+	}
+}
@@ -0,0 +1,132 @@
+// rawstring::indent
+
+use std::borrow::Cow;
+
+use crate::{RawStr, RawString};
+
+/// Splits `bytes` into lines, keeping each line's terminating `\n`.
+fn lines_inclusive(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
+	bytes.split_inclusive(|&b| b == b'\n')
+}
+
+/// Returns `true` if the line contains nothing but whitespace and its terminator.
+fn is_blank(line: &[u8]) -> bool {
+	line.iter().all(u8::is_ascii_whitespace)
+}
+
+impl RawStr {
+	/// Replaces each tab with spaces up to the next multiple of `width` columns.
+	///
+	/// Columns restart after each `\n`. Each valid character occupies one column,
+	/// as does each byte of an invalid sequence, which is otherwise copied unchanged.
+	/// A `width` of zero removes tabs.
+	///
+	/// Returns the [`RawStr`] unchanged, without copying, if it contains no tabs.
+	///
+	/// # Examples
+	/// ```
+	/// # use std::borrow::Cow;
+	/// # use rawstring::RawStr;
+	/// assert_eq!(*RawStr::new("a\tb\n\tc").expand_tabs(4), "a   b\n    c");
+	/// assert_eq!(*RawStr::new("é\tx").expand_tabs(4), "é   x");
+	/// assert_eq!(*RawStr::new(b"\xFF\xFF\tx").expand_tabs(4), b"\xFF\xFF  x");
+	/// assert!(matches!(RawStr::new("no tabs").expand_tabs(4), Cow::Borrowed(_)));
+	/// ```
+	#[must_use]
+	pub fn expand_tabs(&self, width: usize) -> Cow<'_, RawStr> {
+		if !self.contains(&b'\t') {
+			return Cow::Borrowed(self);
+		}
+
+		let mut expanded = RawString::from(Vec::with_capacity(self.len()));
+		let mut column = 0;
+		for chunk in self.utf8_chunks() {
+			for c in chunk.valid().chars() {
+				match c {
+					'\t' => {
+						let spaces = if width == 0 { 0 } else { width - column % width };
+						expanded.extend(std::iter::repeat_n(b' ', spaces));
+						column += spaces;
+					}
+					'\n' => {
+						expanded.push(b'\n');
+						column = 0;
+					}
+					c => {
+						expanded.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+						column += 1;
+					}
+				}
+			}
+			expanded.extend_from_slice(chunk.invalid());
+			column += chunk.invalid().len();
+		}
+		Cow::Owned(expanded)
+	}
+
+	/// Removes the longest run of leading spaces and tabs common to all non-blank lines.
+	///
+	/// Lines consisting only of whitespace are ignored when computing the common indentation,
+	/// and are reduced to their line terminator.
+	///
+	/// Returns the [`RawStr`] unchanged, without copying, if there is nothing to remove.
+	///
+	/// # Examples
+	/// ```
+	/// # use std::borrow::Cow;
+	/// # use rawstring::RawStr;
+	/// let code = RawStr::new(b"    fn f() {\n  \n        \xFF\n    }\n");
+	/// assert_eq!(*code.dedent(), b"fn f() {\n\n    \xFF\n}\n");
+	/// assert!(matches!(RawStr::new("a\n\n  b\n").dedent(), Cow::Borrowed(_)));
+	/// ```
+	#[must_use]
+	pub fn dedent(&self) -> Cow<'_, RawStr> {
+		let margin = lines_inclusive(self)
+			.filter(|line| !is_blank(line))
+			.map(|line| &line[..line.iter().take_while(|&&b| b == b' ' || b == b'\t').count()])
+			.reduce(|common, indent| {
+				let len = common.iter().zip(indent).take_while(|(a, b)| a == b).count();
+				&common[..len]
+			})
+			.map_or(0, <[u8]>::len);
+		let reduces_blank = |line: &[u8]| is_blank(line) && line != b"\n" && line != b"\r\n";
+		if margin == 0 && !lines_inclusive(self).any(reduces_blank) {
+			return Cow::Borrowed(self);
+		}
+
+		let mut dedented = RawString::from(Vec::with_capacity(self.len()));
+		for line in lines_inclusive(self) {
+			if is_blank(line) {
+				let terminator = if line.ends_with(b"\r\n") { 2 } else { usize::from(line.ends_with(b"\n")) };
+				dedented.extend_from_slice(&line[line.len() - terminator..]);
+			} else {
+				dedented.extend_from_slice(&line[margin..]);
+			}
+		}
+		Cow::Owned(dedented)
+	}
+
+	/// Prepends `prefix` to every line that is not blank.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// let text = RawStr::new(b"a\n\n\xFF\n");
+	/// assert_eq!(text.indent("> "), RawString::from(b"> a\n\n> \xFF\n"));
+	/// ```
+	#[must_use]
+	pub fn indent<B>(&self, prefix: &B) -> RawString
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let prefix = prefix.as_ref();
+		let mut indented = RawString::from(Vec::with_capacity(self.len()));
+		for line in lines_inclusive(self) {
+			if !is_blank(line) {
+				indented.extend_from_slice(prefix);
+			}
+			indented.extend_from_slice(line);
+		}
+		indented
+	}
+}
@@ -0,0 +1,118 @@
+// rawstring::json
+
+use std::fmt::{self, Write};
+
+use crate::{RawStr, RawString};
+
+/// How [`EscapeJson`] writes the bytes of invalid sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum JsonInvalid {
+	/// Writes `\ufffd`, the escape of `�`, for each invalid sequence, as lossy conversion does.
+	#[default]
+	Replace,
+	/// Writes `\u00XX` for each invalid byte, so the bytes can be recovered by a reader that
+	/// knows to map code points below U+0100 back to bytes.
+	///
+	/// Note that such a reader cannot tell these apart from valid characters below U+0100.
+	Byte,
+}
+
+impl RawStr {
+	/// Returns a wrapper that displays the [`RawStr`] as a JSON string literal, including the quotes.
+	///
+	/// Valid characters are written unchanged, except for `"`, `\` and control characters
+	/// below U+0020, which are escaped. Invalid sequences are written as set by
+	/// [`EscapeJson::invalid`], as `\ufffd` by default, so the output is always valid JSON.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{JsonInvalid, RawStr};
+	/// let raw = RawStr::new(b"say \"hi\"\x01\n\xFF\xFE");
+	/// assert_eq!(raw.escape_json().to_string(), r#""say \"hi\"\u0001\n\ufffd\ufffd""#);
+	/// assert_eq!(raw.escape_json().invalid(JsonInvalid::Byte).to_string(), r#""say \"hi\"\u0001\n\u00ff\u00fe""#);
+	/// assert_eq!(format!("{{\"msg\":{}}}", RawStr::new("ok").escape_json()), r#"{"msg":"ok"}"#);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn escape_json(&self) -> EscapeJson<'_> {
+		EscapeJson { raw: self, invalid: JsonInvalid::Replace }
+	}
+}
+
+impl RawString {
+	/// Returns a wrapper that displays the [`RawString`] as a JSON string literal, including the quotes.
+	///
+	/// See [`RawStr::escape_json`].
+	#[inline]
+	#[must_use]
+	pub fn escape_json(&self) -> EscapeJson<'_> {
+		self.as_ref().escape_json()
+	}
+}
+
+/// A wrapper that displays a [`RawStr`] as a JSON string literal.
+///
+/// Created by [`RawStr::escape_json`].
+#[derive(Debug, Clone, Copy)]
+pub struct EscapeJson<'a> {
+	raw: &'a RawStr,
+	invalid: JsonInvalid,
+}
+
+impl EscapeJson<'_> {
+	/// Sets how invalid sequences are written.
+	#[inline]
+	#[must_use]
+	pub const fn invalid(mut self, invalid: JsonInvalid) -> Self {
+		self.invalid = invalid;
+		self
+	}
+}
+
+/// Returns `true` if `c` must be escaped in a JSON string.
+#[inline]
+fn needs_escape(c: char) -> bool {
+	matches!(c, '"' | '\\' | '\0'..='\x1F')
+}
+
+/// Writes the escape of a character for which [`needs_escape`] is `true`.
+fn write_escape(f: &mut fmt::Formatter<'_>, c: char) -> fmt::Result {
+	match c {
+		'"' => f.write_str("\\\""),
+		'\\' => f.write_str("\\\\"),
+		'\x08' => f.write_str("\\b"),
+		'\x0C' => f.write_str("\\f"),
+		'\n' => f.write_str("\\n"),
+		'\r' => f.write_str("\\r"),
+		'\t' => f.write_str("\\t"),
+		_ => write!(f, "\\u{:04x}", u32::from(c)),
+	}
+}
+
+impl fmt::Display for EscapeJson<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_char('"')?;
+		for chunk in self.raw.utf8_chunks() {
+			let valid = chunk.valid();
+			let mut start = 0;
+			for (i, c) in valid.char_indices().filter(|&(_, c)| needs_escape(c)) {
+				f.write_str(&valid[start..i])?;
+				write_escape(f, c)?;
+				start = i + 1;
+			}
+			f.write_str(&valid[start..])?;
+
+			let invalid = chunk.invalid();
+			match self.invalid {
+				JsonInvalid::Replace if !invalid.is_empty() => f.write_str("\\ufffd")?,
+				JsonInvalid::Replace => {}
+				JsonInvalid::Byte => {
+					for &b in invalid {
+						write!(f, "\\u{b:04x}")?;
+					}
+				}
+			}
+		}
+		f.write_char('"')
+	}
+}
@@ -0,0 +1,121 @@
+// rawstring::kv
+
+//! Line-oriented `key = value` parsing for INI-style configs and `/proc` files.
+//!
+//! Each line is split at the first separator byte into a key and a value.
+//! Blank lines, comment lines, and lines without a separator (such as INI `[section]` headers)
+//! are skipped. Keys and values are borrowed from the input and may contain invalid UTF-8.
+//!
+//! # Examples
+//! ```
+//! # use rawstring::RawStr;
+//! use rawstring::kv::{self, Options};
+//!
+//! let config = RawStr::new(b"# comment\nname = caf\xE9\n\n[section]\npath=/tmp\r\n");
+//! let pairs: Vec<_> = kv::parse_lines(config).collect();
+//! assert_eq!(pairs, [
+//!     (RawStr::new("name"), RawStr::new(b"caf\xE9")),
+//!     (RawStr::new("path"), RawStr::new("/tmp")),
+//! ]);
+//!
+//! let meminfo = RawStr::new("MemTotal:  16303428 kB\nMemFree:    493124 kB\n");
+//! let options = Options { separators: b":", ..Options::default() };
+//! let mut pairs = kv::parse_lines_with(meminfo, options);
+//! assert_eq!(pairs.next(), Some((RawStr::new("MemTotal"), RawStr::new("16303428 kB"))));
+//!
+//! // later lines override earlier ones, so search from the end
+//! let last = kv::parse_lines(RawStr::new("a=1\nb=2\na=3\n")).rev().find(|(k, _)| *k == "a");
+//! assert_eq!(last, Some((RawStr::new("a"), RawStr::new("3"))));
+//! ```
+
+use std::iter::FusedIterator;
+
+use crate::RawStr;
+
+/// Options controlling how [`parse_lines_with`] splits lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Options<'o> {
+	/// Bytes separating a key from its value. A line is split at the first occurrence of any of them.
+	pub separators: &'o [u8],
+	/// Bytes that start a comment line when they are the first non-whitespace byte of the line.
+	pub comments: &'o [u8],
+	/// Whether to trim ASCII whitespace around keys and values.
+	pub trim: bool,
+}
+
+impl Options<'_> {
+	/// The default options: `=` separates keys from values, `#` and `;` start comments,
+	/// and whitespace is trimmed.
+	pub const DEFAULT: Options<'static> = Options {
+		separators: b"=",
+		comments: b"#;",
+		trim: true,
+	};
+}
+
+impl Default for Options<'_> {
+	#[inline]
+	fn default() -> Self {
+		Options::DEFAULT
+	}
+}
+
+/// Parses `key = value` lines from `raw` using the [default options](Options::DEFAULT).
+#[inline]
+pub fn parse_lines(raw: &RawStr) -> Pairs<'_, 'static> {
+	parse_lines_with(raw, Options::DEFAULT)
+}
+
+/// Parses key-value lines from `raw` using the given options.
+#[inline]
+pub fn parse_lines_with<'a, 'o>(raw: &'a RawStr, options: Options<'o>) -> Pairs<'a, 'o> {
+	Pairs { lines: raw.split(|&b| b == b'\n'), options }
+}
+
+/// An iterator over the key-value pairs of some text.
+///
+/// Created by [`parse_lines`] and [`parse_lines_with`].
+#[derive(Debug, Clone)]
+pub struct Pairs<'a, 'o> {
+	lines: std::slice::Split<'a, u8, fn(&u8) -> bool>,
+	options: Options<'o>,
+}
+
+impl<'a> Iterator for Pairs<'a, '_> {
+	type Item = (&'a RawStr, &'a RawStr);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let options = self.options;
+		self.lines.by_ref().find_map(|line| parse_line(line, options))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, self.lines.size_hint().1)
+	}
+}
+
+impl DoubleEndedIterator for Pairs<'_, '_> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let options = self.options;
+		self.lines.by_ref().rev().find_map(|line| parse_line(line, options))
+	}
+}
+
+impl FusedIterator for Pairs<'_, '_> {}
+
+/// Parses a single line, returning `None` for comments and lines without a separator.
+fn parse_line<'a>(line: &'a [u8], options: Options<'_>) -> Option<(&'a RawStr, &'a RawStr)> {
+	let Options { separators, comments, trim } = options;
+	let line = line.strip_suffix(b"\r").unwrap_or(line);
+	if line.trim_ascii_start().first().is_some_and(|b| comments.contains(b)) {
+		return None;
+	}
+	let sep = line.iter().position(|b| separators.contains(b))?;
+	let (key, value) = (&line[..sep], &line[sep + 1..]);
+	Some(if trim {
+		(RawStr::new(key.trim_ascii()), RawStr::new(value.trim_ascii()))
+	} else {
+		(RawStr::new(key), RawStr::new(value))
+	})
+}
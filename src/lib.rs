@@ -1,15 +1,274 @@
 // rawstring
 #![doc = include_str!("../README.md")]
 #![feature(const_trait_impl, const_convert, const_cmp)]
+#![cfg_attr(feature = "nightly", feature(pattern))]
 
 mod raw_str_imp;
 mod raw_string_imp;
+mod utf8_raw_string_imp;
+mod ansi;
+#[cfg(feature = "quickcheck")]
+mod arbitrary;
+#[cfg(feature = "arc")]
+mod arc;
+mod between;
+mod bits;
+mod bom;
+mod case;
+mod cdc;
+mod chain;
+mod char_index;
+mod chars;
+mod charset;
+mod classify;
+mod collect;
+#[cfg(any(feature = "zstd", feature = "gzip"))]
+mod compress;
+mod const_ops;
+mod cow;
+mod cursor;
+mod context;
+mod debug;
+mod decode;
+mod escape;
+mod extract;
+mod fixed;
+mod fixture;
+mod graphviz;
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "idna")]
+mod idna;
+mod in_place;
+mod indent;
+mod json;
+mod line_index;
+mod line_reader;
+mod literal;
+mod logging;
+mod markup;
+mod maybe_utf8;
+mod mime;
+mod newline;
+#[cfg(unix)]
+mod os;
+mod pad;
+mod parse;
+#[cfg(feature = "nightly")]
+mod pattern;
+mod pool;
+mod prefix;
+mod push;
+#[cfg(feature = "pyo3")]
+mod python;
+#[cfg(feature = "quoted-printable")]
+mod quoted_printable;
+#[cfg(feature = "rand")]
+mod random;
+mod reader;
+mod replace;
+mod replacement;
+mod report;
+#[cfg(feature = "zeroize")]
+mod secret;
+mod secure;
+mod segment;
+mod shell;
+mod slice;
+mod sort;
+mod sql;
+mod strict;
+mod table;
+mod template;
+mod truncate;
+mod url;
+mod utf8;
+mod validate;
+#[cfg(feature = "wasm-bindgen")]
+mod wasm;
+mod wide;
+mod width;
+mod wrap;
+mod writer;
+
+pub mod csv;
+pub mod http;
+pub mod kv;
+pub mod search;
+#[cfg(feature = "testdata")]
+pub mod testdata;
 
 #[doc(inline)]
 pub use raw_str_imp::RawStr;
 
 #[doc(inline)]
-pub use raw_string_imp::RawString;
+pub use raw_string_imp::{IntoStringError, RawString};
+
+#[doc(inline)]
+pub use utf8_raw_string_imp::Utf8RawString;
+
+#[doc(inline)]
+pub use ansi::{AnsiToken, AnsiTokens};
+
+#[cfg(feature = "arc")]
+#[doc(inline)]
+pub use arc::RawStrArc;
+
+#[doc(inline)]
+pub use between::BetweenAll;
+
+#[doc(inline)]
+pub use bits::{BitOrder, Bits};
+
+#[doc(inline)]
+pub use bom::Bom;
+
+#[doc(inline)]
+pub use cdc::{CdcChunks, CdcOptions};
+
+#[doc(inline)]
+pub use chain::{ChainBytes, RawStrChain};
+
+#[doc(inline)]
+pub use char_index::CharIndex;
+
+#[doc(inline)]
+pub use chars::{CharBoundaries, CharRanges, SafeChunks};
+
+#[doc(inline)]
+pub use charset::CharSet;
+
+#[doc(inline)]
+pub use classify::{BinaryDetector, BinaryReason, ContentClass, Detection};
+
+#[doc(inline)]
+pub use collect::CollectRawExt;
+
+#[cfg(any(feature = "zstd", feature = "gzip"))]
+#[doc(inline)]
+pub use compress::Codec;
+
+#[doc(inline)]
+pub use context::{ErrorOffset, RawContextError, ResultExt};
+
+#[doc(inline)]
+pub use cursor::{CursorMark, RawStrCursor, Span};
+
+#[doc(inline)]
+pub use debug::DebugTruncated;
+
+#[doc(inline)]
+pub use decode::{DecodeError, DecodeUtf8, DecodeUtf8Ext, DecodeUtf8Lossy};
+
+#[doc(inline)]
+pub use escape::{EscapeBytes, EscapeStyle};
+
+#[doc(inline)]
+pub use fixed::{FixedFieldError, FixedFieldErrorKind};
+
+#[doc(inline)]
+pub use fixture::{InvalidSequence, RawStringBuilder};
+
+#[doc(inline)]
+pub use graphviz::EscapeGraphviz;
+
+#[cfg(feature = "heapless")]
+#[doc(inline)]
+pub use heapless::HeaplessStringError;
+
+#[cfg(feature = "idna")]
+#[doc(inline)]
+pub use idna::{IdnaError, IdnaErrorKind};
+
+#[doc(inline)]
+pub use json::{EscapeJson, JsonInvalid};
+
+#[doc(inline)]
+pub use line_index::LineIndex;
+
+#[doc(inline)]
+pub use line_reader::{LineTooLongError, OverlongLine, RawLineReader};
+
+#[doc(inline)]
+pub use literal::{LiteralError, LiteralErrorKind};
+
+#[doc(inline)]
+pub use logging::LogValue;
+
+#[doc(inline)]
+pub use markup::{EscapeMarkup, MarkupContext, MarkupInvalid};
+
+#[doc(inline)]
+pub use maybe_utf8::MaybeUtf8;
+
+#[doc(inline)]
+pub use mime::{MultipartPart, MultipartParts};
+
+#[doc(inline)]
+pub use newline::Newline;
+
+#[doc(inline)]
+pub use parse::ParsePrefix;
+
+#[cfg(feature = "nightly")]
+#[doc(inline)]
+pub use pattern::RawStrSearcher;
+
+#[doc(inline)]
+pub use pool::RawStringPool;
+
+#[cfg(feature = "rand")]
+#[doc(inline)]
+pub use random::{Alphabet, RandomRawString};
+
+#[doc(inline)]
+pub use reader::{RawStrReader, ReadError, ReadErrorKind};
+
+#[doc(inline)]
+pub use replacement::DisplayReplacing;
+
+#[doc(inline)]
+pub use report::ValidityReport;
+
+#[cfg(feature = "zeroize")]
+#[doc(inline)]
+pub use secret::SecretRawString;
+
+#[doc(inline)]
+pub use segment::{Utf8Segment, ValidatedSegments};
+
+#[doc(inline)]
+pub use shell::ShellSplitError;
+
+#[doc(inline)]
+pub use slice::SliceError;
+
+#[doc(inline)]
+pub use sort::{CaseInsensitive, Natural, RawOrder, RawSliceExt, RawVecExt};
+
+#[doc(inline)]
+pub use sql::{EscapeSql, SqlDialect};
+
+#[doc(inline)]
+pub use table::{DisplayTable, TableOptions, display_table, display_table_with};
+
+#[doc(inline)]
+pub use template::{RawTemplate, TemplateError, TemplateErrorKind};
+
+#[doc(inline)]
+pub use url::UrlComponents;
+
+#[doc(inline)]
+pub use validate::{Utf8ErrorKind, Utf8ErrorPositions};
+
+#[doc(inline)]
+pub use wide::{RawWideStr, RawWideString};
+
+#[doc(inline)]
+pub use wrap::{Wrap, WrapOptions};
+
+#[doc(inline)]
+pub use writer::{LengthPrefix, RawStringWriter};
 
 /// The Unicode replacement character: `�`.
 /// 
@@ -31,12 +290,14 @@ pub const UNICODE_REPLACEMENT_CHARACTER: char = '�';
 /// assert_eq!(format!("{:?}", raw), "\"Hello, world!\"");
 /// ```
 /// ```
+/// # #[cfg(not(feature = "strict"))] {
 /// # use rawstring::{RawStr, raw_str};
 /// // printing an invalid utf8 string
 /// let raw: &RawStr = raw_str!(&[b'a', 0xFF, b'b']);
 /// assert_eq!(raw, b"a\xffb");
 /// assert_eq!(format!("{}", raw), "a�b");
 /// assert_eq!(format!("{:?}", raw), "\"a\\xffb\"");
+/// # }
 /// ```
 #[macro_export]
 macro_rules! raw_str {
@@ -0,0 +1,168 @@
+// rawstring::line_index
+
+use std::ops::Range;
+
+use crate::{RawStr, RawString};
+
+/// An index of line start offsets over a [`RawStr`], allowing random access to lines
+/// and conversion between byte offsets and `(line, column)` positions in `O(log n)`.
+///
+/// Lines are separated by `\n`; a `\r` preceding the `\n` is considered part of the line terminator.
+/// Columns are measured in bytes from the start of the line.
+///
+/// The index does not borrow the text it was built from, so the text must be passed to
+/// methods that return line contents. Use [`LineIndex::replace_range`] to edit a [`RawString`]
+/// while keeping its index up to date without rescanning the whole text.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{LineIndex, RawStr};
+/// let text = RawStr::new(b"foo\r\nb\xFFr\nbaz");
+/// let index = LineIndex::new(text);
+/// assert_eq!(index.line_count(), 3);
+/// assert_eq!(index.line(text, 1), Some(RawStr::new(b"b\xFFr")));
+/// assert_eq!(index.offset_to_line_col(6), Some((1, 1)));
+/// assert_eq!(index.line_col_to_offset(2, 2), Some(11));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LineIndex {
+	/// Byte offset at which each line starts. Always non-empty, with `starts[0] == 0`.
+	starts: Vec<usize>,
+	/// Length of the indexed text in bytes.
+	len: usize,
+}
+
+impl LineIndex {
+	/// Builds a [`LineIndex`] for the given text.
+	#[must_use]
+	pub fn new(text: &RawStr) -> Self {
+		let mut starts = vec![0];
+		starts.extend(newline_starts(text, 0));
+		Self { starts, len: text.len() }
+	}
+
+	/// Returns the number of lines in the indexed text.
+	///
+	/// This is always at least one, and text ending with a newline has an empty last line.
+	#[inline]
+	#[must_use]
+	pub fn line_count(&self) -> usize {
+		self.starts.len()
+	}
+
+	/// Returns the length in bytes of the indexed text.
+	#[inline]
+	#[must_use]
+	pub fn text_len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns the byte range of line `n`, excluding its line terminator,
+	/// or `None` if `n` is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{LineIndex, RawStr};
+	/// let text = RawStr::new("ab\r\ncd");
+	/// let index = LineIndex::new(text);
+	/// assert_eq!(index.line_range(text, 0), Some(0..2));
+	/// assert_eq!(index.line_range(text, 1), Some(4..6));
+	/// assert_eq!(index.line_range(text, 2), None);
+	/// ```
+	#[must_use]
+	pub fn line_range(&self, text: &RawStr, n: usize) -> Option<Range<usize>> {
+		debug_assert_eq!(text.len(), self.len, "text does not match the line index");
+		let start = *self.starts.get(n)?;
+		let end = match self.starts.get(n + 1) {
+			Some(&next) if next - 1 > start && text[next - 2] == b'\r' => next - 2,
+			Some(&next) => next - 1,
+			None => self.len,
+		};
+		Some(start..end)
+	}
+
+	/// Returns the contents of line `n`, excluding its line terminator,
+	/// or `None` if `n` is out of bounds.
+	#[must_use]
+	pub fn line<'a>(&self, text: &'a RawStr, n: usize) -> Option<&'a RawStr> {
+		self.line_range(text, n).map(|range| RawStr::new(&text[range]))
+	}
+
+	/// Converts a byte offset into a zero-based `(line, column)` pair.
+	///
+	/// Returns `None` if `offset` is greater than the length of the text.
+	#[must_use]
+	pub fn offset_to_line_col(&self, offset: usize) -> Option<(usize, usize)> {
+		if offset > self.len {
+			return None;
+		}
+		let line = self.starts.partition_point(|&start| start <= offset) - 1;
+		Some((line, offset - self.starts[line]))
+	}
+
+	/// Converts a zero-based `(line, column)` pair into a byte offset.
+	///
+	/// Returns `None` if the line does not exist or the column lies past the end of the line.
+	/// The position of the line's terminating `\n` is considered part of the line.
+	#[must_use]
+	pub fn line_col_to_offset(&self, line: usize, col: usize) -> Option<usize> {
+		let start = *self.starts.get(line)?;
+		let end = self.starts.get(line + 1).map_or(self.len, |&next| next - 1);
+		let offset = start.checked_add(col)?;
+		(offset <= end).then_some(offset)
+	}
+
+	/// Replaces the given byte range of `text` with `replacement`,
+	/// updating the index incrementally.
+	///
+	/// Only the line starts inside the edited range are recomputed;
+	/// the starts after it are shifted by the change in length.
+	///
+	/// # Panics
+	/// Panics if the range is out of bounds or if `text` does not match the index.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{LineIndex, RawStr, RawString};
+	/// let mut text = RawString::from("one\ntwo\nthree");
+	/// let mut index = LineIndex::new(text.as_ref());
+	/// index.replace_range(&mut text, 4..7, RawStr::new("2\n2"));
+	/// assert_eq!(text, RawString::from("one\n2\n2\nthree"));
+	/// assert_eq!(index, LineIndex::new(text.as_ref()));
+	/// assert_eq!(index.line(text.as_ref(), 3), Some(RawStr::new("three")));
+	/// ```
+	pub fn replace_range(&mut self, text: &mut RawString, range: Range<usize>, replacement: &RawStr) {
+		assert_eq!(text.len(), self.len, "text does not match the line index");
+		assert!(range.start <= range.end && range.end <= self.len, "range out of bounds");
+
+		// line starts strictly after `range.start` and at most `range.end`
+		// come from newlines inside the replaced range
+		let first = self.starts.partition_point(|&start| start <= range.start);
+		let last = self.starts.partition_point(|&start| start <= range.end);
+		let added = replacement.len();
+		let removed = range.end - range.start;
+
+		for start in &mut self.starts[last..] {
+			*start = *start - removed + added;
+		}
+		self.starts.splice(first..last, newline_starts(replacement, range.start));
+
+		text.splice(range, replacement.iter().copied());
+		self.len = self.len - removed + added;
+	}
+}
+
+impl From<&RawStr> for LineIndex {
+	#[inline]
+	fn from(text: &RawStr) -> Self {
+		Self::new(text)
+	}
+}
+
+/// Returns the offsets following each newline in `text`, shifted by `base`.
+fn newline_starts(text: &RawStr, base: usize) -> impl Iterator<Item = usize> + '_ {
+	text.iter()
+		.enumerate()
+		.filter(|&(_, &byte)| byte == b'\n')
+		.map(move |(i, _)| base + i + 1)
+}
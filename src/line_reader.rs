@@ -0,0 +1,269 @@
+// rawstring::line_reader
+
+use std::{
+	error::Error,
+	fmt,
+	io::{self, BufRead},
+};
+
+use crate::RawString;
+
+/// What a [`RawLineReader`] does with a line longer than its maximum length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OverlongLine {
+	/// Returns an [`io::Error`] of kind [`InvalidData`](io::ErrorKind::InvalidData) wrapping a
+	/// [`LineTooLongError`], and skips the rest of the line without buffering it.
+	#[default]
+	Error,
+	/// Returns the line in pieces of the maximum length, so no line is lost.
+	///
+	/// Pieces are split at byte boundaries, which may fall inside a multi-byte character.
+	Split,
+}
+
+/// An error returned by [`RawLineReader`] when a line is longer than the maximum length.
+///
+/// It is returned as the inner error of an [`io::Error`] of kind
+/// [`InvalidData`](io::ErrorKind::InvalidData).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LineTooLongError {
+	line: usize,
+	max_len: usize,
+}
+
+impl LineTooLongError {
+	/// Returns the line number of the overlong line, starting from 1.
+	#[inline]
+	#[must_use]
+	pub const fn line(&self) -> usize {
+		self.line
+	}
+
+	/// Returns the maximum line length of the reader.
+	#[inline]
+	#[must_use]
+	pub const fn max_len(&self) -> usize {
+		self.max_len
+	}
+}
+
+impl fmt::Display for LineTooLongError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "line {} is longer than the maximum of {} bytes", self.line, self.max_len)
+	}
+}
+
+impl Error for LineTooLongError {}
+
+/// A reader of lines from a [`BufRead`] that never buffers more than a fixed number of bytes
+/// per line.
+///
+/// Unlike [`BufRead::lines`], lines are not required to be valid UTF-8, and a line without a
+/// newline cannot grow without bound, which makes it safe to use on untrusted input such as
+/// network requests. Lines end at `\n` or `\r\n`, which are not included; the last line
+/// may end without a newline.
+///
+/// The maximum length does not include the line terminator. What happens to longer lines
+/// is set by [`RawLineReader::overlong`].
+///
+/// # Examples
+/// ```
+/// # use std::io;
+/// # use rawstring::{LineTooLongError, OverlongLine, RawLineReader, RawString};
+/// let input = io::Cursor::new(b"GET / HTTP/1.1\r\nX-Junk: aaaaaaaaaaaaaaaaaaaaaa\r\nHost: \xFF\r\n");
+/// let mut lines = RawLineReader::new(input, 16);
+/// assert_eq!(lines.next().unwrap().unwrap(), RawString::from("GET / HTTP/1.1"));
+///
+/// let err = lines.next().unwrap().unwrap_err();
+/// assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+/// let err = err.get_ref().unwrap().downcast_ref::<LineTooLongError>().unwrap();
+/// assert_eq!(err.line(), 2);
+///
+/// // the rest of the overlong line is skipped
+/// assert_eq!(lines.next().unwrap().unwrap(), RawString::from(b"Host: \xFF"));
+/// assert!(lines.next().is_none());
+///
+/// let input = io::Cursor::new("abcdefgh\nij");
+/// let pieces: Vec<_> = RawLineReader::new(input, 3).overlong(OverlongLine::Split).collect::<io::Result<_>>().unwrap();
+/// assert_eq!(pieces, ["abc", "def", "gh", "ij"].map(RawString::from));
+/// ```
+#[derive(Debug)]
+pub struct RawLineReader<R> {
+	reader: R,
+	max_len: usize,
+	overlong: OverlongLine,
+	line: usize,
+	/// A byte split off the end of the last piece, and whether it ends its line.
+	carry: Option<(u8, bool)>,
+	discarding: bool,
+}
+
+impl<R: BufRead> RawLineReader<R> {
+	/// Creates a reader of lines of at most `max_len` bytes that returns an error
+	/// for longer lines.
+	#[inline]
+	#[must_use]
+	pub fn new(reader: R, max_len: usize) -> Self {
+		Self { reader, max_len, overlong: OverlongLine::Error, line: 0, carry: None, discarding: false }
+	}
+
+	/// Sets what to do with lines longer than the maximum length.
+	///
+	/// # Panics
+	/// Panics if `overlong` is [`OverlongLine::Split`] and the maximum length is zero.
+	#[inline]
+	#[must_use]
+	pub fn overlong(mut self, overlong: OverlongLine) -> Self {
+		assert!(overlong != OverlongLine::Split || self.max_len > 0, "cannot split lines into pieces of zero bytes");
+		self.overlong = overlong;
+		self
+	}
+
+	/// Returns the maximum line length.
+	#[inline]
+	#[must_use]
+	pub fn max_len(&self) -> usize {
+		self.max_len
+	}
+
+	/// Returns a reference to the underlying reader.
+	#[inline]
+	#[must_use]
+	pub fn get_ref(&self) -> &R {
+		&self.reader
+	}
+
+	/// Returns a mutable reference to the underlying reader.
+	///
+	/// Reading from it directly may leave the [`RawLineReader`] in the middle of a line.
+	#[inline]
+	#[must_use]
+	pub fn get_mut(&mut self) -> &mut R {
+		&mut self.reader
+	}
+
+	/// Returns the underlying reader.
+	#[inline]
+	#[must_use]
+	pub fn into_inner(self) -> R {
+		self.reader
+	}
+
+	/// Reads the next line into `buf`, after clearing it, and returns `false` at the end of input.
+	///
+	/// This reuses the allocation of `buf`, unlike iterating.
+	///
+	/// # Errors
+	/// Returns any error of the underlying reader other than
+	/// [`Interrupted`](io::ErrorKind::Interrupted), and an error wrapping a
+	/// [`LineTooLongError`] for overlong lines if the reader was created with
+	/// [`OverlongLine::Error`].
+	pub fn read_line(&mut self, buf: &mut RawString) -> io::Result<bool> {
+		buf.clear();
+		if self.discarding {
+			self.discard_line()?;
+		}
+		let mut read_any = false;
+		if let Some((byte, ended)) = self.carry.take() {
+			buf.push(byte);
+			if ended {
+				return self.finish_line(buf, true);
+			}
+			read_any = true;
+		}
+
+		loop {
+			let available = match self.reader.fill_buf() {
+				Ok(available) => available,
+				Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+				Err(err) => return Err(err),
+			};
+			if available.is_empty() {
+				if !read_any {
+					return Ok(false);
+				}
+				return self.finish_line(buf, true);
+			}
+			read_any = true;
+
+			let newline = available.iter().position(|&b| b == b'\n');
+			let chunk = &available[..newline.unwrap_or(available.len())];
+			// one byte more than the maximum is kept, as it may be the `\r` of a `\r\n`
+			let take = chunk.len().min(self.max_len.saturating_add(1) - buf.len());
+			buf.extend_from_slice(&chunk[..take]);
+
+			if take < chunk.len() {
+				self.reader.consume(take);
+				return self.finish_line(buf, false);
+			}
+			if let Some(i) = newline {
+				self.reader.consume(i + 1);
+				if buf.ends_with(b"\r") {
+					buf.pop();
+				}
+				return self.finish_line(buf, true);
+			}
+			self.reader.consume(take);
+		}
+	}
+
+	/// Checks the length of the line in `buf`, which is complete if `ended` is `true`.
+	fn finish_line(&mut self, buf: &mut RawString, ended: bool) -> io::Result<bool> {
+		if buf.len() <= self.max_len {
+			self.line += 1;
+			return Ok(true);
+		}
+		match self.overlong {
+			OverlongLine::Error => {
+				self.line += 1;
+				self.discarding = !ended;
+				buf.clear();
+				let err = LineTooLongError { line: self.line, max_len: self.max_len };
+				Err(io::Error::new(io::ErrorKind::InvalidData, err))
+			}
+			OverlongLine::Split => {
+				self.carry = buf.pop().map(|byte| (byte, ended));
+				Ok(true)
+			}
+		}
+	}
+
+	/// Skips the rest of the current line, including its terminator.
+	fn discard_line(&mut self) -> io::Result<()> {
+		loop {
+			let available = match self.reader.fill_buf() {
+				Ok(available) => available,
+				Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+				Err(err) => return Err(err),
+			};
+			if available.is_empty() {
+				break;
+			}
+			match available.iter().position(|&b| b == b'\n') {
+				Some(i) => {
+					self.reader.consume(i + 1);
+					break;
+				}
+				None => {
+					let len = available.len();
+					self.reader.consume(len);
+				}
+			}
+		}
+		self.discarding = false;
+		Ok(())
+	}
+}
+
+impl<R: BufRead> Iterator for RawLineReader<R> {
+	type Item = io::Result<RawString>;
+
+	fn next(&mut self) -> Option<io::Result<RawString>> {
+		let mut buf = RawString::new();
+		match self.read_line(&mut buf) {
+			Ok(true) => Some(Ok(buf)),
+			Ok(false) => None,
+			Err(err) => Some(Err(err)),
+		}
+	}
+}
@@ -0,0 +1,231 @@
+// rawstring::literal
+
+use std::{error::Error, fmt};
+
+use crate::{ErrorOffset, RawString};
+
+/// An error returned by [`RawString::from_literal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LiteralError {
+	position: usize,
+	kind: LiteralErrorKind,
+}
+
+/// The kind of a [`LiteralError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LiteralErrorKind {
+	/// The input does not start with a string literal.
+	ExpectedQuote,
+	/// The closing quote is missing.
+	Unterminated,
+	/// A backslash is followed by a character that does not start an escape.
+	InvalidEscape,
+	/// A `\x` escape is not followed by two hex digits.
+	InvalidHexEscape,
+	/// A `\x` escape above `\x7F` appears in a string literal rather than a byte string literal.
+	HexEscapeOutOfRange,
+	/// A `\u{...}` escape is malformed or does not name a character.
+	InvalidUnicodeEscape,
+	/// A byte string literal contains a non-ASCII character.
+	NonAscii,
+	/// There are characters after the closing quote.
+	TrailingCharacters,
+}
+
+impl LiteralError {
+	/// Returns the byte offset in the literal at which the error occurred.
+	#[inline]
+	#[must_use]
+	pub const fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Returns the kind of error.
+	#[inline]
+	#[must_use]
+	pub const fn kind(&self) -> LiteralErrorKind {
+		self.kind
+	}
+}
+
+impl fmt::Display for LiteralErrorKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::ExpectedQuote => "expected a string literal",
+			Self::Unterminated => "unterminated string literal",
+			Self::InvalidEscape => "invalid escape",
+			Self::InvalidHexEscape => "invalid hex escape",
+			Self::HexEscapeOutOfRange => "hex escape above \\x7F in a string literal",
+			Self::InvalidUnicodeEscape => "invalid unicode escape",
+			Self::NonAscii => "non-ASCII character in a byte string literal",
+			Self::TrailingCharacters => "unexpected characters after the literal",
+		})
+	}
+}
+
+impl fmt::Display for LiteralError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} at byte {}", self.kind, self.position)
+	}
+}
+
+impl Error for LiteralError {}
+
+impl ErrorOffset for LiteralError {
+	#[inline]
+	fn error_offset(&self) -> Option<usize> {
+		Some(self.position)
+	}
+}
+
+impl RawString {
+	/// Parses a string literal written in Rust syntax.
+	///
+	/// The literal may be a string (`"..."`), a byte string (`b"..."`), or a raw form of either
+	/// (`r#"..."#`, `br#"..."#`) with any number of `#`s. Escapes follow Rust's rules:
+	/// `\n`, `\r`, `\t`, `\\`, `\0`, `\'`, `\"`, `\xHH`, `\u{...}` (strings only) and a backslash
+	/// at the end of a line, which skips the line break and any leading whitespace on the next line.
+	/// `\xHH` may produce any byte in a byte string, but only ASCII in a string.
+	///
+	/// # Errors
+	/// Returns a [`LiteralError`] with the byte offset of the problem if `literal` is not exactly
+	/// one valid literal.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{LiteralErrorKind, RawString};
+	/// assert_eq!(RawString::from_literal(r#"b"\xFF\0abc""#), Ok(RawString::from(b"\xFF\0abc")));
+	/// assert_eq!(RawString::from_literal(r#""caf\u{e9} \t""#), Ok(RawString::from("caf\u{e9} \t")));
+	/// assert_eq!(RawString::from_literal(r##"br#"C:\path "quoted""#"##), Ok(RawString::from(r#"C:\path "quoted""#)));
+	///
+	/// let err = RawString::from_literal(r#""\xFF""#).unwrap_err();
+	/// assert_eq!(err.kind(), LiteralErrorKind::HexEscapeOutOfRange);
+	/// assert_eq!(err.to_string(), "hex escape above \\x7F in a string literal at byte 1");
+	///
+	/// let err = RawString::from_literal(r#"b"abc" x"#).unwrap_err();
+	/// assert_eq!((err.kind(), err.position()), (LiteralErrorKind::TrailingCharacters, 6));
+	/// ```
+	pub fn from_literal(literal: &str) -> Result<RawString, LiteralError> {
+		let bytes = literal.as_bytes();
+		let error = |position, kind| LiteralError { position, kind };
+
+		let is_bytes = bytes.first() == Some(&b'b');
+		let mut pos = usize::from(is_bytes);
+		let is_raw = bytes.get(pos) == Some(&b'r');
+		pos += usize::from(is_raw);
+		let hashes = if is_raw { bytes[pos..].iter().take_while(|&&b| b == b'#').count() } else { 0 };
+		pos += hashes;
+		if bytes.get(pos) != Some(&b'"') {
+			return Err(error(pos, LiteralErrorKind::ExpectedQuote));
+		}
+		let open = pos;
+		pos += 1;
+
+		let (value, end) = if is_raw {
+			parse_raw(bytes, pos, hashes).ok_or(error(open, LiteralErrorKind::Unterminated))?
+		} else {
+			parse_escaped(bytes, pos, is_bytes).map_err(|err| err.unwrap_or(error(open, LiteralErrorKind::Unterminated)))?
+		};
+
+		if is_bytes && is_raw && let Some(offset) = value.iter().position(|b| !b.is_ascii()) {
+			return Err(error(pos + offset, LiteralErrorKind::NonAscii));
+		}
+		if end != bytes.len() {
+			return Err(error(end, LiteralErrorKind::TrailingCharacters));
+		}
+		Ok(value)
+	}
+}
+
+/// Parses the body of a raw literal starting at `start`, returning it and the offset just
+/// past the closing delimiter, or `None` if the literal is unterminated.
+fn parse_raw(bytes: &[u8], start: usize, hashes: usize) -> Option<(RawString, usize)> {
+	let body = &bytes[start..];
+	let close = (0..body.len()).find(|&i| {
+		body[i] == b'"' && body.get(i + 1..i + 1 + hashes).is_some_and(|h| h.iter().all(|&b| b == b'#'))
+	})?;
+	Some((RawString::from(&body[..close]), start + close + 1 + hashes))
+}
+
+/// Parses the body of an escaped literal starting at `start`, returning it and the offset just
+/// past the closing quote.
+///
+/// Returns `Err(None)` if the literal is unterminated.
+fn parse_escaped(bytes: &[u8], start: usize, is_bytes: bool) -> Result<(RawString, usize), Option<LiteralError>> {
+	let error = |position, kind| Some(LiteralError { position, kind });
+	let mut value = RawString::new();
+	let mut pos = start;
+	loop {
+		match *bytes.get(pos).ok_or(None)? {
+			b'"' => return Ok((value, pos + 1)),
+			b'\\' => {
+				let escape = pos;
+				let (byte, len) = match *bytes.get(pos + 1).ok_or(None)? {
+					b'n' => (b'\n', 2),
+					b'r' => (b'\r', 2),
+					b't' => (b'\t', 2),
+					b'\\' => (b'\\', 2),
+					b'0' => (b'\0', 2),
+					b'\'' => (b'\'', 2),
+					b'"' => (b'"', 2),
+					b'x' => {
+						let byte = match bytes.get(pos + 2..pos + 4) {
+							Some(&[hi, lo]) => hex_digit(hi).zip(hex_digit(lo)).map(|(hi, lo)| hi << 4 | lo),
+							_ => None,
+						}
+						.ok_or(error(escape, LiteralErrorKind::InvalidHexEscape))?;
+						if !is_bytes && !byte.is_ascii() {
+							return Err(error(escape, LiteralErrorKind::HexEscapeOutOfRange));
+						}
+						(byte, 4)
+					}
+					b'u' if !is_bytes => {
+						let (c, len) = parse_unicode_escape(&bytes[pos + 2..])
+							.ok_or(error(escape, LiteralErrorKind::InvalidUnicodeEscape))?;
+						value.push_char(c);
+						pos += 2 + len;
+						continue;
+					}
+					b'\n' | b'\r' => {
+						pos += 1 + bytes[pos + 1..].iter().take_while(|b| b.is_ascii_whitespace()).count();
+						continue;
+					}
+					_ => return Err(error(escape, LiteralErrorKind::InvalidEscape)),
+				};
+				value.push(byte);
+				pos += len;
+			}
+			byte => {
+				if is_bytes && !byte.is_ascii() {
+					return Err(error(pos, LiteralErrorKind::NonAscii));
+				}
+				value.push(byte);
+				pos += 1;
+			}
+		}
+	}
+}
+
+/// Parses the `{...}` part of a `\u{...}` escape, returning the character and the length of the braces.
+fn parse_unicode_escape(bytes: &[u8]) -> Option<(char, usize)> {
+	let rest = bytes.strip_prefix(b"{")?;
+	let digits = rest.iter().position(|&b| b == b'}')?;
+	if !(1..=6).contains(&digits) {
+		return None;
+	}
+	let code = rest[..digits]
+		.iter()
+		.try_fold(0, |code, &b| hex_digit(b).map(|digit| code << 4 | u32::from(digit)))?;
+	Some((char::from_u32(code)?, digits + 2))
+}
+
+/// Returns the value of the hex digit `b`.
+const fn hex_digit(b: u8) -> Option<u8> {
+	match b {
+		b'0'..=b'9' => Some(b - b'0'),
+		b'a'..=b'f' => Some(b - b'a' + 10),
+		b'A'..=b'F' => Some(b - b'A' + 10),
+		_ => None,
+	}
+}
@@ -0,0 +1,132 @@
+// rawstring::logging
+
+use std::{borrow::Cow, fmt};
+
+use crate::{RawStr, RawString};
+
+impl RawStr {
+	/// Returns a wrapper for recording the [`RawStr`] as a structured logging field.
+	///
+	/// The wrapper displays valid UTF-8 as is, and invalid data as lossy text followed by its
+	/// length and a flag, so a log line shows that a payload was not text without needing a
+	/// separate field. Unlike [`Display`](fmt::Display) for [`RawStr`], the wrapper never panics
+	/// in `strict` mode, since showing invalid data is its purpose.
+	///
+	/// With the `log` feature the wrapper implements `log::kv::ToValue`, and with the `tracing`
+	/// feature `LogValue::as_tracing_value` adapts it for `tracing` macros.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("GET /").as_log_value().to_string(), "GET /");
+	/// assert_eq!(
+	///     RawStr::new(b"GET /\xFF").as_log_value().to_string(),
+	///     "GET /� [6 bytes, invalid UTF-8]",
+	/// );
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn as_log_value(&self) -> LogValue<'_> {
+		LogValue { raw: self }
+	}
+}
+
+impl RawString {
+	/// Returns a wrapper for recording the [`RawString`] as a structured logging field.
+	///
+	/// See [`RawStr::as_log_value`].
+	#[inline]
+	#[must_use]
+	pub fn as_log_value(&self) -> LogValue<'_> {
+		self.as_ref().as_log_value()
+	}
+}
+
+/// A wrapper for recording a [`RawStr`] as a structured logging field.
+///
+/// Created by [`RawStr::as_log_value`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LogValue<'a> {
+	raw: &'a RawStr,
+}
+
+impl<'a> LogValue<'a> {
+	/// Returns the value as lossy text, without the length and flag.
+	#[inline]
+	#[must_use]
+	pub fn text(&self) -> Cow<'a, str> {
+		String::from_utf8_lossy(self.raw)
+	}
+
+	/// Returns the length of the value in bytes.
+	#[inline]
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.raw.0.len()
+	}
+
+	/// Returns `true` if the value is empty.
+	#[inline]
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.raw.0.is_empty()
+	}
+
+	/// Returns `true` if the value is valid UTF-8.
+	#[inline]
+	#[must_use]
+	pub const fn is_utf8(&self) -> bool {
+		self.raw.is_utf8()
+	}
+
+	/// Adapts the value for use as a field in `tracing` macros.
+	///
+	/// `tracing` does not allow other crates to implement its `Value` trait,
+	/// so this records the [`Display`](fmt::Display) output of the wrapper.
+	/// Requires the `tracing` feature.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let payload = RawStr::new(b"\xFF\xFE");
+	/// tracing::info!(payload = payload.as_log_value().as_tracing_value(), "received");
+	/// ```
+	#[cfg(feature = "tracing")]
+	#[inline]
+	#[must_use]
+	pub fn as_tracing_value(self) -> tracing::field::DisplayValue<Self> {
+		tracing::field::display(self)
+	}
+}
+
+impl fmt::Display for LogValue<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.raw.fmt_lossy(f, crate::raw_str_imp::REPLACEMENT_STR)?;
+		if !self.is_utf8() {
+			write!(f, " [{} bytes, invalid UTF-8]", self.len())?;
+		}
+		Ok(())
+	}
+}
+
+/// Records valid UTF-8 as a string, and anything else as the [`Display`](fmt::Display) output
+/// of the wrapper. Requires the `log` feature.
+///
+/// # Examples
+/// ```
+/// # use rawstring::RawStr;
+/// use log::kv::ToValue;
+///
+/// let value = RawStr::new("text").as_log_value();
+/// assert_eq!(value.to_value().to_borrowed_str(), Some("text"));
+/// log::info!(payload = RawStr::new(b"\xFF").as_log_value(); "received");
+/// ```
+#[cfg(feature = "log")]
+impl log::kv::ToValue for LogValue<'_> {
+	fn to_value(&self) -> log::kv::Value<'_> {
+		match self.raw.to_utf8_checked() {
+			Ok(s) => log::kv::Value::from(s),
+			Err(_) => log::kv::Value::from_display(self),
+		}
+	}
+}
@@ -0,0 +1,181 @@
+// rawstring::markup
+
+use std::fmt;
+
+use crate::{RawStr, RawString};
+
+/// Where in a document an [`EscapeMarkup`] is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MarkupContext {
+	/// Element content: `&`, `<` and `>` are escaped. In XML, carriage returns are escaped too,
+	/// because line-end normalization would otherwise remove them.
+	#[default]
+	Text,
+	/// A quoted attribute value: quotes of both kinds are escaped as well, so the value
+	/// may be put in either. In XML, tabs and line breaks are also escaped, because attribute
+	/// value normalization would otherwise turn them into spaces.
+	Attribute,
+}
+
+/// How [`EscapeMarkup`] writes the bytes of invalid sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MarkupInvalid {
+	/// Writes `&#xFFFD;`, the reference to `�`, for each invalid sequence.
+	#[default]
+	Replace,
+	/// Writes a character reference such as `&#xFF;` for each invalid byte, naming the code point
+	/// of the same value.
+	///
+	/// Note that HTML parsers read references to U+0080 through U+009F as the characters of
+	/// windows-1252 at those bytes.
+	CharRef,
+}
+
+/// The markup language of an [`EscapeMarkup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Language {
+	Html,
+	Xml,
+}
+
+impl RawStr {
+	/// Returns a wrapper that displays the [`RawStr`] escaped for HTML.
+	///
+	/// By default the output is safe in element content. Use [`EscapeMarkup::context`] for
+	/// attribute values and [`EscapeMarkup::invalid`] to choose how invalid sequences are written.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{MarkupContext, MarkupInvalid, RawStr};
+	/// let raw = RawStr::new(b"<b>\"Tom\" & 'Jerry'</b>\xFF");
+	/// assert_eq!(raw.escape_html().to_string(), "&lt;b&gt;\"Tom\" &amp; 'Jerry'&lt;/b&gt;&#xFFFD;");
+	/// assert_eq!(
+	///     raw.escape_html().context(MarkupContext::Attribute).invalid(MarkupInvalid::CharRef).to_string(),
+	///     "&lt;b&gt;&quot;Tom&quot; &amp; &#39;Jerry&#39;&lt;/b&gt;&#xFF;",
+	/// );
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn escape_html(&self) -> EscapeMarkup<'_> {
+		EscapeMarkup::new(self, Language::Html)
+	}
+
+	/// Returns a wrapper that displays the [`RawStr`] escaped for XML 1.0.
+	///
+	/// This works like [`RawStr::escape_html`], except that `&apos;` is used for `'`, and control
+	/// characters that XML 1.0 does not allow, even as references, are written as `&#xFFFD;`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{MarkupContext, RawStr};
+	/// let raw = RawStr::new("a\x01b\n'c'");
+	/// assert_eq!(raw.escape_xml().to_string(), "a&#xFFFD;b\n'c'");
+	/// assert_eq!(raw.escape_xml().context(MarkupContext::Attribute).to_string(), "a&#xFFFD;b&#xA;&apos;c&apos;");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn escape_xml(&self) -> EscapeMarkup<'_> {
+		EscapeMarkup::new(self, Language::Xml)
+	}
+}
+
+impl RawString {
+	/// Returns a wrapper that displays the [`RawString`] escaped for HTML.
+	///
+	/// See [`RawStr::escape_html`].
+	#[inline]
+	#[must_use]
+	pub fn escape_html(&self) -> EscapeMarkup<'_> {
+		self.as_ref().escape_html()
+	}
+
+	/// Returns a wrapper that displays the [`RawString`] escaped for XML 1.0.
+	///
+	/// See [`RawStr::escape_xml`].
+	#[inline]
+	#[must_use]
+	pub fn escape_xml(&self) -> EscapeMarkup<'_> {
+		self.as_ref().escape_xml()
+	}
+}
+
+/// A wrapper that displays a [`RawStr`] escaped for HTML or XML.
+///
+/// Created by [`RawStr::escape_html`] and [`RawStr::escape_xml`].
+#[derive(Debug, Clone, Copy)]
+pub struct EscapeMarkup<'a> {
+	raw: &'a RawStr,
+	language: Language,
+	context: MarkupContext,
+	invalid: MarkupInvalid,
+}
+
+impl<'a> EscapeMarkup<'a> {
+	#[inline]
+	const fn new(raw: &'a RawStr, language: Language) -> Self {
+		Self { raw, language, context: MarkupContext::Text, invalid: MarkupInvalid::Replace }
+	}
+
+	/// Sets where in the document the output is written.
+	#[inline]
+	#[must_use]
+	pub const fn context(mut self, context: MarkupContext) -> Self {
+		self.context = context;
+		self
+	}
+
+	/// Sets how invalid sequences are written.
+	#[inline]
+	#[must_use]
+	pub const fn invalid(mut self, invalid: MarkupInvalid) -> Self {
+		self.invalid = invalid;
+		self
+	}
+
+	/// Returns the escape of `c`, or `None` if it is written unchanged.
+	fn escape(&self, c: char) -> Option<&'static str> {
+		let attribute = self.context == MarkupContext::Attribute;
+		match (self.language, c) {
+			(_, '&') => Some("&amp;"),
+			(_, '<') => Some("&lt;"),
+			(_, '>') => Some("&gt;"),
+			(_, '"') if attribute => Some("&quot;"),
+			(Language::Html, '\'') if attribute => Some("&#39;"),
+			(Language::Xml, '\'') if attribute => Some("&apos;"),
+			(Language::Xml, '\t') if attribute => Some("&#x9;"),
+			(Language::Xml, '\n') if attribute => Some("&#xA;"),
+			(Language::Xml, '\r') => Some("&#xD;"),
+			(Language::Xml, '\0'..='\x08' | '\x0B' | '\x0C' | '\x0E'..='\x1F' | '\u{FFFE}' | '\u{FFFF}') => Some("&#xFFFD;"),
+			_ => None,
+		}
+	}
+}
+
+impl fmt::Display for EscapeMarkup<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for chunk in self.raw.utf8_chunks() {
+			let valid = chunk.valid();
+			let mut start = 0;
+			for (i, c) in valid.char_indices() {
+				if let Some(escape) = self.escape(c) {
+					f.write_str(&valid[start..i])?;
+					f.write_str(escape)?;
+					start = i + c.len_utf8();
+				}
+			}
+			f.write_str(&valid[start..])?;
+
+			let invalid = chunk.invalid();
+			match self.invalid {
+				MarkupInvalid::Replace if !invalid.is_empty() => f.write_str("&#xFFFD;")?,
+				MarkupInvalid::Replace => {}
+				MarkupInvalid::CharRef => {
+					for &b in invalid {
+						write!(f, "&#x{b:X};")?;
+					}
+				}
+			}
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,155 @@
+// rawstring::maybe_utf8
+
+use std::fmt;
+
+use crate::RawStr;
+
+/// The result of validating a [`RawStr`] once: either a [`str`] or raw data.
+///
+/// Created by [`RawStr::classify`]. Downstream code can branch on the variant
+/// instead of validating the same buffer again.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{MaybeUtf8, RawStr};
+/// fn describe(raw: &RawStr) -> String {
+///     match raw.classify() {
+///         MaybeUtf8::Str(s) => format!("text with {} chars", s.chars().count()),
+///         MaybeUtf8::Raw(r) => format!("{} bytes of data", r.len()),
+///     }
+/// }
+///
+/// assert_eq!(describe(RawStr::new("héllo")), "text with 5 chars");
+/// assert_eq!(describe(RawStr::new(b"\xFFhello")), "6 bytes of data");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaybeUtf8<'a> {
+	/// The data is valid UTF-8.
+	Str(&'a str),
+	/// The data is not valid UTF-8.
+	Raw(&'a RawStr),
+}
+
+impl<'a> MaybeUtf8<'a> {
+	/// Returns `true` if the data is valid UTF-8.
+	#[inline]
+	#[must_use]
+	pub const fn is_str(self) -> bool {
+		matches!(self, Self::Str(_))
+	}
+
+	/// Returns the data as a [`str`] if it is valid UTF-8.
+	#[inline]
+	#[must_use]
+	pub const fn as_str(self) -> Option<&'a str> {
+		match self {
+			Self::Str(s) => Some(s),
+			Self::Raw(_) => None,
+		}
+	}
+
+	/// Returns the data as a [`RawStr`], regardless of validity.
+	#[inline]
+	#[must_use]
+	pub const fn as_raw_str(self) -> &'a RawStr {
+		match self {
+			Self::Str(s) => RawStr::from_bytes(s.as_bytes()),
+			Self::Raw(r) => r,
+		}
+	}
+
+	/// Applies `f` to valid UTF-8 data, passing invalid data through as the error.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("42").classify().map_str(str::len), Ok(2));
+	/// assert_eq!(RawStr::new(b"\xFF").classify().map_str(str::len), Err(RawStr::new(b"\xFF")));
+	/// ```
+	#[inline]
+	pub fn map_str<T, F>(self, f: F) -> Result<T, &'a RawStr>
+	where
+		F: FnOnce(&'a str) -> T
+	{
+		match self {
+			Self::Str(s) => Ok(f(s)),
+			Self::Raw(r) => Err(r),
+		}
+	}
+
+	/// Applies `f` to invalid data, passing valid UTF-8 data through.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let lossy = |raw: &RawStr| raw.len();
+	/// assert_eq!(RawStr::new("ok").classify().map_raw(lossy), Ok("ok"));
+	/// assert_eq!(RawStr::new(b"\xFF\xFE").classify().map_raw(lossy), Err(2));
+	/// ```
+	#[inline]
+	pub fn map_raw<T, F>(self, f: F) -> Result<&'a str, T>
+	where
+		F: FnOnce(&'a RawStr) -> T
+	{
+		match self {
+			Self::Str(s) => Ok(s),
+			Self::Raw(r) => Err(f(r)),
+		}
+	}
+
+	/// Applies `on_str` or `on_raw` depending on the variant.
+	#[inline]
+	pub fn either<T>(self, on_str: impl FnOnce(&'a str) -> T, on_raw: impl FnOnce(&'a RawStr) -> T) -> T {
+		match self {
+			Self::Str(s) => on_str(s),
+			Self::Raw(r) => on_raw(r),
+		}
+	}
+}
+
+impl fmt::Debug for MaybeUtf8<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Str(s) => f.debug_tuple("Str").field(s).finish(),
+			Self::Raw(r) => f.debug_tuple("Raw").field(r).finish(),
+		}
+	}
+}
+
+impl fmt::Display for MaybeUtf8<'_> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Str(s) => fmt::Display::fmt(s, f),
+			Self::Raw(r) => fmt::Display::fmt(r, f),
+		}
+	}
+}
+
+impl<'a> From<&'a str> for MaybeUtf8<'a> {
+	#[inline]
+	fn from(s: &'a str) -> Self {
+		Self::Str(s)
+	}
+}
+
+impl<'a> From<&'a RawStr> for MaybeUtf8<'a> {
+	#[inline]
+	fn from(raw: &'a RawStr) -> Self {
+		raw.classify()
+	}
+}
+
+impl RawStr {
+	/// Validates the [`RawStr`] once, returning it as a [`str`] if it is valid UTF-8.
+	///
+	/// See [`MaybeUtf8`].
+	#[inline]
+	#[must_use]
+	pub const fn classify(&self) -> MaybeUtf8<'_> {
+		match self.to_utf8_checked() {
+			Ok(s) => MaybeUtf8::Str(s),
+			Err(_) => MaybeUtf8::Raw(self),
+		}
+	}
+}
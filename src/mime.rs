@@ -0,0 +1,437 @@
+// rawstring::mime
+
+use std::iter::FusedIterator;
+
+use crate::{RawStr, RawString};
+
+/// The standard Base64 alphabet used by the `B` encoding.
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// The maximum length of an encoded word, from RFC 2047, section 2.
+const MAX_WORD_LEN: usize = 75;
+
+/// The encodings of encoded words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+	/// Base64.
+	B,
+	/// Quoted-printable with `_` for spaces.
+	Q,
+}
+
+/// Returns `true` if `b` may appear unencoded in `Q`-encoded text anywhere in a header,
+/// following the most restrictive rules of RFC 2047, section 5.
+#[inline]
+fn is_q_literal(b: u8) -> bool {
+	b.is_ascii_alphanumeric() || matches!(b, b'!' | b'*' | b'+' | b'-' | b'/')
+}
+
+/// Returns the length of `b` in `Q`-encoded text.
+#[inline]
+fn q_len(b: u8) -> usize {
+	if b == b' ' || is_q_literal(b) { 1 } else { 3 }
+}
+
+/// Returns the length of `n` bytes in `B`-encoded text.
+#[inline]
+fn b_len(n: usize) -> usize {
+	n.div_ceil(3) * 4
+}
+
+fn encode_q(bytes: &[u8], out: &mut String) {
+	for &b in bytes {
+		if b == b' ' {
+			out.push('_');
+		} else if is_q_literal(b) {
+			out.push(char::from(b));
+		} else {
+			out.push('=');
+			out.push(char::from(HEX_DIGITS[usize::from(b >> 4)]));
+			out.push(char::from(HEX_DIGITS[usize::from(b & 0xF)]));
+		}
+	}
+}
+
+fn encode_b(bytes: &[u8], out: &mut String) {
+	for group in bytes.chunks(3) {
+		let n = group.iter().enumerate().fold(0u32, |n, (i, &b)| n | u32::from(b) << (16 - 8 * i));
+		for i in 0..4 {
+			if i <= group.len() {
+				out.push(char::from(BASE64[(n >> (18 - 6 * i) & 0x3F) as usize]));
+			} else {
+				out.push('=');
+			}
+		}
+	}
+}
+
+/// Decodes `Q`-encoded text, or returns `None` if it is malformed.
+fn decode_q(text: &[u8], out: &mut Vec<u8>) -> Option<()> {
+	let mut i = 0;
+	while i < text.len() {
+		match text[i] {
+			b'_' => out.push(b' '),
+			b'=' => {
+				let hex = text.get(i + 1..i + 3)?;
+				let digit = |d: u8| char::from(d).to_digit(16);
+				out.push((digit(hex[0])? << 4 | digit(hex[1])?) as u8);
+				i += 2;
+			}
+			b => out.push(b),
+		}
+		i += 1;
+	}
+	Some(())
+}
+
+/// Decodes `B`-encoded text, with or without padding, or returns `None` if it is malformed.
+fn decode_b(text: &[u8], out: &mut Vec<u8>) -> Option<()> {
+	let unpadded = text.strip_suffix(b"==").or_else(|| text.strip_suffix(b"=")).unwrap_or(text);
+	if unpadded.len() % 4 == 1 || (unpadded.len() != text.len() && !text.len().is_multiple_of(4)) {
+		return None;
+	}
+	for group in unpadded.chunks(4) {
+		let mut n = 0u32;
+		for (i, &c) in group.iter().enumerate() {
+			let value = BASE64.iter().position(|&d| d == c)?;
+			n |= (value as u32) << (18 - 6 * i);
+		}
+		out.extend_from_slice(&n.to_be_bytes()[1..group.len()]);
+	}
+	Some(())
+}
+
+/// Parses the encoded word at the start of `input`, which starts with `=?`, and appends
+/// its decoded bytes to `out`. Returns the length of the encoded word, or `None` if there
+/// is no well-formed encoded word there.
+fn decode_word(input: &[u8], out: &mut Vec<u8>) -> Option<usize> {
+	let mut parts = input[2..].splitn(4, |&b| b == b'?');
+	let charset = parts.next().filter(|charset| !charset.is_empty())?;
+	let encoding = match parts.next()? {
+		b"B" | b"b" => Encoding::B,
+		b"Q" | b"q" => Encoding::Q,
+		_ => return None,
+	};
+	let text = parts.next()?;
+	if !parts.next()?.starts_with(b"=") || input[..charset.len() + text.len() + 6].iter().any(u8::is_ascii_whitespace) {
+		return None;
+	}
+
+	let len = charset.len() + text.len() + 7;
+	let start = out.len();
+	let decoded = match encoding {
+		Encoding::B => decode_b(text, out),
+		Encoding::Q => decode_q(text, out),
+	};
+	if decoded.is_none() {
+		out.truncate(start);
+		return None;
+	}
+
+	// RFC 2231 allows a language after the charset, as in `utf-8*en`
+	let charset = charset.split(|&b| b == b'*').next().unwrap_or(charset);
+	if [&b"iso-8859-1"[..], b"iso_8859-1", b"latin1"].iter().any(|name| charset.eq_ignore_ascii_case(name)) {
+		for b in out.split_off(start) {
+			out.extend_from_slice(char::from(b).encode_utf8(&mut [0; 4]).as_bytes());
+		}
+	}
+	Some(len)
+}
+
+impl RawStr {
+	/// Encodes the [`RawStr`] as RFC 2047 encoded words for a mail header, labeled with `charset`.
+	///
+	/// The bytes are not converted, so `charset` must name the encoding they are in, usually
+	/// `"utf-8"`. The `Q` encoding is used if it is no longer than the `B` (Base64) encoding.
+	/// Text too long for one encoded word of 75 characters is split between characters into
+	/// several words separated by spaces, which decoders remove; each invalid sequence is kept
+	/// whole. Returns an empty string if the [`RawStr`] is empty.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("Caf\u{e9} ouvert").encode_mime_word("utf-8"), "=?utf-8?Q?Caf=C3=A9_ouvert?=");
+	/// assert_eq!(RawStr::new("\u{65e5}\u{672c}").encode_mime_word("utf-8"), "=?utf-8?B?5pel5pys?=");
+	/// assert_eq!(RawStr::new(b"caf\xE9").encode_mime_word("iso-8859-1"), "=?iso-8859-1?Q?caf=E9?=");
+	///
+	/// let long = RawStr::new("\u{e9}".repeat(30).as_str()).encode_mime_word("utf-8");
+	/// assert_eq!(long.split(' ').count(), 2);
+	/// assert!(long.split(' ').all(|word| word.len() <= 75));
+	/// ```
+	#[must_use]
+	pub fn encode_mime_word(&self, charset: &str) -> String {
+		let q_total: usize = self.iter().map(|&b| q_len(b)).sum();
+		let encoding = if q_total <= b_len(self.len()) { Encoding::Q } else { Encoding::B };
+		let budget = MAX_WORD_LEN.saturating_sub(charset.len() + 7);
+
+		let mut out = String::new();
+		let mut ranges = self.char_ranges().map(|(range, _)| range).peekable();
+		while let Some(first) = ranges.next() {
+			let mut end = first.end;
+			let mut q_used: usize = self[first.clone()].iter().map(|&b| q_len(b)).sum();
+			while let Some(next) = ranges.peek() {
+				let q_next: usize = self[next.clone()].iter().map(|&b| q_len(b)).sum();
+				let len = match encoding {
+					Encoding::B => b_len(next.end - first.start),
+					Encoding::Q => q_used + q_next,
+				};
+				if len > budget {
+					break;
+				}
+				end = next.end;
+				q_used += q_next;
+				ranges.next();
+			}
+
+			if !out.is_empty() {
+				out.push(' ');
+			}
+			out.push_str("=?");
+			out.push_str(charset);
+			match encoding {
+				Encoding::B => {
+					out.push_str("?B?");
+					encode_b(&self[first.start..end], &mut out);
+				}
+				Encoding::Q => {
+					out.push_str("?Q?");
+					encode_q(&self[first.start..end], &mut out);
+				}
+			}
+			out.push_str("?=");
+		}
+		out
+	}
+
+	/// Decodes the RFC 2047 encoded words in a mail header value, such as a subject.
+	///
+	/// Text outside encoded words is kept, except for whitespace between two encoded words,
+	/// which is removed as the RFC requires. Malformed encoded words are kept as they are.
+	/// The decoded bytes of words labeled ISO-8859-1 are converted to UTF-8; those of all
+	/// other charsets are kept unconverted, so the result is not necessarily UTF-8, and headers
+	/// with mislabeled charsets, common from misconfigured mailers, still decode.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// let subject = RawStr::new("Re: =?utf-8?Q?Caf=C3=A9?= =?UTF-8?B?IOKYlQ==?= menu");
+	/// assert_eq!(subject.decode_mime_words(), RawString::from("Re: Caf\u{e9} \u{2615} menu"));
+	///
+	/// let subject = RawStr::new("=?iso-8859-1?q?d=E9j=E0_vu?= =?x-bad?Z?oops?=");
+	/// assert_eq!(subject.decode_mime_words(), RawString::from("d\u{e9}j\u{e0} vu =?x-bad?Z?oops?="));
+	///
+	/// // bytes in an unknown charset are kept as they are
+	/// let subject = RawStr::new("=?koi8-r?B?8NLJ18XU?=");
+	/// assert_eq!(subject.decode_mime_words(), RawString::from(b"\xF0\xD2\xC9\xD7\xC5\xD4"));
+	/// ```
+	#[must_use]
+	pub fn decode_mime_words(&self) -> RawString {
+		let mut out = Vec::with_capacity(self.len());
+		let mut rest = &self.0;
+		let mut after_word = false;
+		while let Some(i) = rest.windows(2).position(|pair| pair == b"=?") {
+			let text = &rest[..i];
+			let joins = after_word && text.iter().all(u8::is_ascii_whitespace);
+			if !joins {
+				out.extend_from_slice(text);
+			}
+			match decode_word(&rest[i..], &mut out) {
+				Some(len) => {
+					rest = &rest[i + len..];
+					after_word = true;
+				}
+				None => {
+					if joins {
+						out.extend_from_slice(text);
+					}
+					out.extend_from_slice(b"=?");
+					rest = &rest[i + 2..];
+					after_word = false;
+				}
+			}
+		}
+		out.extend_from_slice(rest);
+		RawString::from(out)
+	}
+}
+
+impl RawString {
+	/// Encodes the [`RawString`] as RFC 2047 encoded words for a mail header, labeled with `charset`.
+	///
+	/// See [`RawStr::encode_mime_word`].
+	#[inline]
+	#[must_use]
+	pub fn encode_mime_word(&self, charset: &str) -> String {
+		self.as_ref().encode_mime_word(charset)
+	}
+
+	/// Decodes the RFC 2047 encoded words in a mail header value.
+	///
+	/// See [`RawStr::decode_mime_words`].
+	#[inline]
+	#[must_use]
+	pub fn decode_mime_words(&self) -> RawString {
+		self.as_ref().decode_mime_words()
+	}
+}
+
+/// A part of a MIME multipart body, as split by [`RawStr::split_multipart`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MultipartPart<'a> {
+	/// The header lines of the part, without the blank line that ends them.
+	pub headers: &'a RawStr,
+	/// The body of the part, without the line break before the next boundary.
+	pub body: &'a RawStr,
+}
+
+impl RawStr {
+	/// Returns an iterator over the parts of the MIME multipart body in the [`RawStr`],
+	/// delimited by `boundary`, as given by the `boundary` parameter of its `Content-Type`.
+	///
+	/// Parts borrow the body without copying, so binary bodies are returned exactly. The
+	/// preamble before the first boundary and the epilogue after the closing boundary are
+	/// skipped. Parsing is tolerant: lines may end in a bare `\n`, the line break after a
+	/// boundary may be missing, and if the closing boundary is missing, the last part extends
+	/// to the end of the input. A part without a blank line after its headers is returned
+	/// with all of it as headers and an empty body.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let body = RawStr::new(b"preamble\r\n--XyZ\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\nvalue\r\n\
+	///     --XyZ\r\nContent-Type: image/png\r\n\r\n\x89PNG\r\n\x1A\n\r\n--XyZ--\r\nepilogue");
+	/// let parts: Vec<_> = body.split_multipart("XyZ").collect();
+	/// assert_eq!(parts.len(), 2);
+	/// assert_eq!(parts[0].headers, r#"Content-Disposition: form-data; name="a""#);
+	/// assert_eq!(parts[0].body, "value");
+	/// assert_eq!(parts[1].body, b"\x89PNG\r\n\x1A\n");
+	///
+	/// // bare line feeds and a missing closing boundary
+	/// let body = RawStr::new("--b\nX: 1\n\nhello\n--b\n\ntruncated");
+	/// let bodies: Vec<_> = body.split_multipart("b").map(|part| part.body).collect();
+	/// assert_eq!(bodies, ["hello", "truncated"].map(RawStr::new));
+	/// ```
+	#[must_use]
+	pub fn split_multipart<B>(&self, boundary: &B) -> MultipartParts<'_>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let mut delimiter = b"--".to_vec();
+		delimiter.extend_from_slice(boundary.as_ref());
+		let mut parts = MultipartParts { data: &self.0, delimiter, pos: None };
+		parts.pos = parts.find_delimiter(0).and_then(|at| parts.after_delimiter(at));
+		parts
+	}
+}
+
+impl RawString {
+	/// Returns an iterator over the parts of the MIME multipart body in the [`RawString`].
+	///
+	/// See [`RawStr::split_multipart`].
+	#[inline]
+	#[must_use]
+	pub fn split_multipart<B>(&self, boundary: &B) -> MultipartParts<'_>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		self.as_ref().split_multipart(boundary)
+	}
+}
+
+/// An iterator over the parts of a MIME multipart body.
+///
+/// Created by [`RawStr::split_multipart`].
+#[derive(Debug, Clone)]
+pub struct MultipartParts<'a> {
+	data: &'a [u8],
+	/// The boundary with its leading `--`.
+	delimiter: Vec<u8>,
+	/// The start of the next part, or `None` after the last part.
+	pos: Option<usize>,
+}
+
+impl MultipartParts<'_> {
+	/// Returns the position of the next delimiter at or after `from` that starts a line,
+	/// or starts at `from` itself, and is followed by what may follow a boundary.
+	fn find_delimiter(&self, from: usize) -> Option<usize> {
+		let mut i = from;
+		while let Some(found) = RawStr::new(&self.data[i..]).find(&self.delimiter) {
+			let at = i + found;
+			let after = &self.data[at + self.delimiter.len()..];
+			let starts_line = at == from || self.data[at - 1] == b'\n';
+			let ends = after.is_empty() || after.starts_with(b"--") || matches!(after[0], b' ' | b'\t' | b'\r' | b'\n');
+			if starts_line && ends {
+				return Some(at);
+			}
+			i = at + 1;
+		}
+		None
+	}
+
+	/// Returns the start of the part after the delimiter at `at`, or `None` if it is the
+	/// closing delimiter.
+	fn after_delimiter(&self, at: usize) -> Option<usize> {
+		let mut pos = at + self.delimiter.len();
+		if self.data[pos..].starts_with(b"--") {
+			return None;
+		}
+		pos += self.data[pos..].iter().take_while(|&&b| matches!(b, b' ' | b'\t')).count();
+		if self.data[pos..].starts_with(b"\r\n") {
+			pos += 2;
+		} else if self.data[pos..].starts_with(b"\n") {
+			pos += 1;
+		}
+		Some(pos)
+	}
+}
+
+/// Splits a part into its headers and body at the first blank line.
+fn split_part(part: &[u8]) -> MultipartPart<'_> {
+	let blank_line = part.iter().enumerate().find_map(|(i, &b)| {
+		let line_start = i == 0 || part[i - 1] == b'\n';
+		match (line_start, b) {
+			(true, b'\n') => Some((i, i + 1)),
+			(true, b'\r') if part.get(i + 1) == Some(&b'\n') => Some((i, i + 2)),
+			_ => None,
+		}
+	});
+	let Some((end, body_start)) = blank_line else {
+		return MultipartPart { headers: RawStr::new(part), body: RawStr::new(&[]) };
+	};
+	// the line break of the last header line is not part of the headers
+	let headers = &part[..end];
+	let headers = headers.strip_suffix(b"\n").map_or(headers, |h| h.strip_suffix(b"\r").unwrap_or(h));
+	MultipartPart { headers: RawStr::new(headers), body: RawStr::new(&part[body_start..]) }
+}
+
+impl<'a> Iterator for MultipartParts<'a> {
+	type Item = MultipartPart<'a>;
+
+	fn next(&mut self) -> Option<MultipartPart<'a>> {
+		let start = self.pos?;
+		let (end, next) = match self.find_delimiter(start) {
+			Some(at) => {
+				// the line break before a delimiter belongs to the delimiter
+				let body = &self.data[start..at];
+				let body = body.strip_suffix(b"\n").map_or(body, |b| b.strip_suffix(b"\r").unwrap_or(b));
+				(start + body.len(), self.after_delimiter(at))
+			}
+			None => (self.data.len(), None),
+		};
+		self.pos = next;
+		Some(split_part(&self.data[start..end]))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		match self.pos {
+			// every part after the next is preceded by a delimiter
+			Some(pos) => (1, Some((self.data.len() - pos) / self.delimiter.len() + 1)),
+			None => (0, Some(0)),
+		}
+	}
+}
+
+impl FusedIterator for MultipartParts<'_> {}
@@ -0,0 +1,129 @@
+// rawstring::newline
+
+use std::borrow::Cow;
+
+use crate::{RawStr, RawString};
+
+/// A line terminator style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Newline {
+	/// `\n`, as used on Unix.
+	#[default]
+	Lf,
+	/// `\r\n`, as used on Windows and in many network protocols.
+	CrLf,
+	/// `\r`, as used on classic Mac OS.
+	Cr,
+}
+
+impl Newline {
+	/// Returns the bytes of the line terminator.
+	#[inline]
+	#[must_use]
+	pub const fn as_bytes(self) -> &'static [u8] {
+		match self {
+			Self::Lf => b"\n",
+			Self::CrLf => b"\r\n",
+			Self::Cr => b"\r",
+		}
+	}
+}
+
+impl RawStr {
+	/// Returns `true` if every line terminator in the [`RawStr`] is `style`.
+	///
+	/// `\r\n`, lone `\r` and lone `\n` are all recognized as line terminators.
+	#[must_use]
+	pub fn has_newline_style(&self, style: Newline) -> bool {
+		match style {
+			Newline::Lf => !self.contains(&b'\r'),
+			Newline::Cr => !self.contains(&b'\n'),
+			Newline::CrLf => self.iter().enumerate().all(|(i, &b)| match b {
+				b'\r' => self.get(i + 1) == Some(&b'\n'),
+				b'\n' => i > 0 && self[i - 1] == b'\r',
+				_ => true,
+			}),
+		}
+	}
+
+	/// Converts every line terminator (`\r\n`, `\r` or `\n`) in the [`RawStr`] to `style`.
+	///
+	/// Returns the [`RawStr`] unchanged, without copying, if it is already normalized.
+	///
+	/// # Examples
+	/// ```
+	/// # use std::borrow::Cow;
+	/// # use rawstring::{Newline, RawStr};
+	/// let mixed = RawStr::new(b"a\r\nb\rc\n\xFF");
+	/// assert_eq!(*mixed.normalize_newlines(Newline::Lf), b"a\nb\nc\n\xFF");
+	/// assert_eq!(*mixed.normalize_newlines(Newline::CrLf), b"a\r\nb\r\nc\r\n\xFF");
+	///
+	/// let unix = RawStr::new("a\nb\n");
+	/// assert!(matches!(unix.normalize_newlines(Newline::Lf), Cow::Borrowed(_)));
+	/// ```
+	#[must_use]
+	pub fn normalize_newlines(&self, style: Newline) -> Cow<'_, RawStr> {
+		if self.has_newline_style(style) {
+			return Cow::Borrowed(self);
+		}
+
+		let terminator = style.as_bytes();
+		let mut normalized = RawString::from(Vec::with_capacity(self.len()));
+		let mut i = 0;
+		while let Some(&b) = self.get(i) {
+			match b {
+				b'\r' if self.get(i + 1) == Some(&b'\n') => {
+					normalized.extend_from_slice(terminator);
+					i += 1;
+				}
+				b'\r' | b'\n' => normalized.extend_from_slice(terminator),
+				b => normalized.push(b),
+			}
+			i += 1;
+		}
+		Cow::Owned(normalized)
+	}
+}
+
+impl RawString {
+	/// Converts every line terminator in the [`RawString`] to `style` in place.
+	///
+	/// Converting to [`Newline::Lf`] or [`Newline::Cr`] never reallocates.
+	/// See [`RawStr::normalize_newlines`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{Newline, RawString};
+	/// let mut text = RawString::from("a\r\nb\rc");
+	/// text.normalize_newlines_in_place(Newline::Lf);
+	/// assert_eq!(text, RawString::from("a\nb\nc"));
+	/// ```
+	pub fn normalize_newlines_in_place(&mut self, style: Newline) {
+		match style {
+			Newline::Lf | Newline::Cr => {
+				let terminator = style.as_bytes()[0];
+				let mut write = 0;
+				let mut read = 0;
+				while let Some(&b) = self.get(read) {
+					self[write] = match b {
+						b'\r' | b'\n' => {
+							if b == b'\r' && self.get(read + 1) == Some(&b'\n') {
+								read += 1;
+							}
+							terminator
+						}
+						b => b,
+					};
+					write += 1;
+					read += 1;
+				}
+				self.truncate(write);
+			}
+			Newline::CrLf => {
+				if let Cow::Owned(normalized) = RawString::as_ref(self).normalize_newlines(style) {
+					*self = normalized;
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,69 @@
+// rawstring::os
+
+//! Conversions to and from platform strings, which are arbitrary bytes on Unix.
+
+use std::{
+	ffi::OsStr,
+	os::unix::ffi::OsStrExt,
+	path::Path,
+};
+
+use crate::{RawStr, RawString};
+
+/// Views the [`RawStr`] as an [`OsStr`] without copying. Only available on Unix.
+///
+/// # Examples
+/// ```
+/// # use rawstring::RawStr;
+/// # use std::path::Path;
+/// let raw = RawStr::new(b"/tmp/caf\xE9.txt");
+/// let path: &Path = raw.as_ref();
+/// assert_eq!(path.extension().unwrap(), "txt");
+/// assert!(!std::fs::exists(raw).unwrap());
+/// ```
+impl AsRef<OsStr> for RawStr {
+	#[inline]
+	fn as_ref(&self) -> &OsStr {
+		OsStr::from_bytes(&self.0)
+	}
+}
+
+/// Views the [`RawStr`] as a [`Path`] without copying. Only available on Unix.
+impl AsRef<Path> for RawStr {
+	#[inline]
+	fn as_ref(&self) -> &Path {
+		Path::new(OsStr::from_bytes(&self.0))
+	}
+}
+
+/// Views the [`RawString`] as an [`OsStr`] without copying. Only available on Unix.
+impl AsRef<OsStr> for RawString {
+	#[inline]
+	fn as_ref(&self) -> &OsStr {
+		OsStr::from_bytes(&self.0)
+	}
+}
+
+/// Views the [`RawString`] as a [`Path`] without copying. Only available on Unix.
+impl AsRef<Path> for RawString {
+	#[inline]
+	fn as_ref(&self) -> &Path {
+		Path::new(OsStr::from_bytes(&self.0))
+	}
+}
+
+/// Views an [`OsStr`] as a [`RawStr`] without copying. Only available on Unix.
+///
+/// # Examples
+/// ```
+/// # use rawstring::RawStr;
+/// # use std::ffi::OsStr;
+/// let raw: &RawStr = OsStr::new("file.txt").into();
+/// assert_eq!(raw, "file.txt");
+/// ```
+impl<'a> From<&'a OsStr> for &'a RawStr {
+	#[inline]
+	fn from(os: &'a OsStr) -> Self {
+		RawStr::new(os.as_bytes())
+	}
+}
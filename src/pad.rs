@@ -0,0 +1,126 @@
+// rawstring::pad
+
+use std::borrow::Cow;
+
+use crate::{RawStr, RawString};
+
+impl RawStr {
+	/// Pads the [`RawStr`] to `width` bytes by adding `fill` to the start, aligning it to the right.
+	///
+	/// Padding counts bytes, not display columns, for writing fixed-width records.
+	/// Returns the [`RawStr`] unchanged, without copying, if it is at least `width` bytes long.
+	///
+	/// # Examples
+	/// ```
+	/// # use std::borrow::Cow;
+	/// # use rawstring::RawStr;
+	/// assert_eq!(*RawStr::new("42").pad_left(5, b'0'), "00042");
+	/// assert_eq!(*RawStr::new(b"\xFF").pad_left(3, b' '), b"  \xFF");
+	/// assert!(matches!(RawStr::new("123456").pad_left(5, b'0'), Cow::Borrowed(_)));
+	/// ```
+	#[must_use]
+	pub fn pad_left(&self, width: usize, fill: u8) -> Cow<'_, RawStr> {
+		self.pad(width.saturating_sub(self.len()), 0, fill)
+	}
+
+	/// Pads the [`RawStr`] to `width` bytes by adding `fill` to the end, aligning it to the left.
+	///
+	/// Returns the [`RawStr`] unchanged, without copying, if it is at least `width` bytes long.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(*RawStr::new("NAME").pad_right(8, b' '), "NAME    ");
+	/// assert_eq!(*RawStr::new("名").pad_right(4, b'.'), "名.");
+	/// ```
+	#[must_use]
+	pub fn pad_right(&self, width: usize, fill: u8) -> Cow<'_, RawStr> {
+		self.pad(0, width.saturating_sub(self.len()), fill)
+	}
+
+	/// Pads the [`RawStr`] to `width` bytes by adding `fill` to both ends, centering it.
+	///
+	/// If the padding cannot be split evenly, the extra byte goes at the end.
+	/// Returns the [`RawStr`] unchanged, without copying, if it is at least `width` bytes long.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(*RawStr::new("ab").pad_center(5, b'*'), "*ab**");
+	/// ```
+	#[must_use]
+	pub fn pad_center(&self, width: usize, fill: u8) -> Cow<'_, RawStr> {
+		let total = width.saturating_sub(self.len());
+		self.pad(total / 2, total - total / 2, fill)
+	}
+
+	fn pad(&self, left: usize, right: usize, fill: u8) -> Cow<'_, RawStr> {
+		if left == 0 && right == 0 {
+			return Cow::Borrowed(self);
+		}
+		let mut padded = RawString::from(Vec::with_capacity(left + self.len() + right));
+		padded.append_fill(fill, left);
+		padded.extend_from_slice(self);
+		padded.append_fill(fill, right);
+		Cow::Owned(padded)
+	}
+}
+
+impl RawString {
+	/// Appends `n` copies of `byte` to the end of the [`RawString`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut record = RawString::from("id");
+	/// record.append_fill(b' ', 3);
+	/// assert_eq!(record, RawString::from("id   "));
+	/// ```
+	#[inline]
+	pub fn append_fill(&mut self, byte: u8, n: usize) {
+		let len = self.len();
+		self.resize(len + n, byte);
+	}
+
+	/// Pads the [`RawString`] to `width` bytes in place by adding `fill` to the start.
+	///
+	/// See [`RawStr::pad_left`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut amount = RawString::from("42");
+	/// amount.pad_left_in_place(5, b'0');
+	/// assert_eq!(amount, RawString::from("00042"));
+	/// ```
+	pub fn pad_left_in_place(&mut self, width: usize, fill: u8) {
+		let n = width.saturating_sub(self.len());
+		self.splice(..0, std::iter::repeat_n(fill, n));
+	}
+
+	/// Pads the [`RawString`] to `width` bytes in place by adding `fill` to the end.
+	///
+	/// See [`RawStr::pad_right`].
+	#[inline]
+	pub fn pad_right_in_place(&mut self, width: usize, fill: u8) {
+		let len = self.len();
+		self.resize(width.max(len), fill);
+	}
+
+	/// Pads the [`RawString`] to `width` bytes in place by adding `fill` to both ends.
+	///
+	/// See [`RawStr::pad_center`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut title = RawString::from(b"\xFF".as_slice());
+	/// title.pad_center_in_place(4, b'-');
+	/// assert_eq!(title, RawString::from(b"-\xFF--".as_slice()));
+	/// ```
+	pub fn pad_center_in_place(&mut self, width: usize, fill: u8) {
+		let total = width.saturating_sub(self.len());
+		self.pad_left_in_place(self.len() + total / 2, fill);
+		self.pad_right_in_place(width, fill);
+	}
+}
@@ -0,0 +1,141 @@
+// rawstring::parse
+
+use crate::RawStr;
+
+/// Types that can be parsed from the start of a [`RawStr`].
+///
+/// This trait is implemented for all primitive integer and floating-point types,
+/// and is used by [`RawStr::parse_prefix`].
+pub trait ParsePrefix: Sized {
+	/// Parses the longest prefix of `raw` that forms a valid value of this type,
+	/// returning the value along with the remaining bytes.
+	///
+	/// Returns `None` if `raw` does not start with a valid value,
+	/// or if the numeric prefix does not fit in this type.
+	fn parse_prefix(raw: &RawStr) -> Option<(Self, &RawStr)>;
+}
+
+impl RawStr {
+	/// Parses a value from the longest valid prefix of the [`RawStr`],
+	/// returning the value and the remaining bytes.
+	///
+	/// Integers accept an optional sign (`-` only for signed types) followed by decimal digits.
+	/// Floats additionally accept a fractional part, an exponent,
+	/// and the special values `inf`, `infinity` and `nan` (case-insensitive).
+	///
+	/// Returns `None` if no valid prefix exists, or if the prefix does not fit in `T`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"1234 rest\xFF");
+	/// let (n, rest) = raw.parse_prefix::<u64>().unwrap();
+	/// assert_eq!(n, 1234);
+	/// assert_eq!(rest, b" rest\xFF");
+	///
+	/// assert_eq!(RawStr::new("-17px").parse_prefix::<i32>(), Some((-17, RawStr::new("px"))));
+	/// assert_eq!(RawStr::new("-17px").parse_prefix::<u32>(), None);
+	/// assert_eq!(RawStr::new("300").parse_prefix::<u8>(), None);
+	///
+	/// assert_eq!(RawStr::new("2.5e3x").parse_prefix::<f64>(), Some((2500.0, RawStr::new("x"))));
+	/// assert_eq!(RawStr::new("1.e").parse_prefix::<f64>(), Some((1.0, RawStr::new("e"))));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn parse_prefix<T: ParsePrefix>(&self) -> Option<(T, &RawStr)> {
+		T::parse_prefix(self)
+	}
+}
+
+/// Splits `raw` at `len` and parses the head, which must be ASCII.
+fn parse_split<T: std::str::FromStr>(raw: &RawStr, len: usize) -> Option<(T, &RawStr)> {
+	let (head, rest) = raw.split_at(len);
+	// SAFETY: callers only measure ASCII bytes
+	let head = unsafe { str::from_utf8_unchecked(head) };
+	head.parse().ok().map(|value| (value, RawStr::new(rest)))
+}
+
+/// Returns the number of leading ASCII digits in `bytes`.
+fn count_digits(bytes: &[u8]) -> usize {
+	bytes.iter().take_while(|b| b.is_ascii_digit()).count()
+}
+
+/// Returns the length of the integer prefix of `bytes`, or 0 if there is none.
+fn int_prefix_len(bytes: &[u8], signed: bool) -> usize {
+	let sign = match bytes.first() {
+		Some(b'+') => 1,
+		Some(b'-') if signed => 1,
+		_ => 0,
+	};
+	match count_digits(&bytes[sign..]) {
+		0 => 0,
+		digits => sign + digits,
+	}
+}
+
+/// Returns the length of the floating-point prefix of `bytes`, or 0 if there is none.
+fn float_prefix_len(bytes: &[u8]) -> usize {
+	let sign = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+	let body = &bytes[sign..];
+
+	for special in [&b"infinity"[..], b"inf", b"nan"] {
+		if body.len() >= special.len() && body[..special.len()].eq_ignore_ascii_case(special) {
+			return sign + special.len();
+		}
+	}
+
+	let int = count_digits(body);
+	let mut len = int;
+	let mut frac = 0;
+	if body.get(len) == Some(&b'.') {
+		frac = count_digits(&body[len + 1..]);
+		if int > 0 || frac > 0 {
+			len += 1 + frac;
+		}
+	}
+	if int == 0 && frac == 0 {
+		return 0;
+	}
+
+	if matches!(body.get(len), Some(b'e' | b'E')) {
+		let exp_sign = usize::from(matches!(body.get(len + 1), Some(b'+' | b'-')));
+		let exp = count_digits(&body[len + 1 + exp_sign..]);
+		if exp > 0 {
+			len += 1 + exp_sign + exp;
+		}
+	}
+	sign + len
+}
+
+macro_rules! impl_parse_prefix_int {
+	($signed:literal => $($t:ty),*) => {$(
+		impl ParsePrefix for $t {
+			#[inline]
+			fn parse_prefix(raw: &RawStr) -> Option<(Self, &RawStr)> {
+				match int_prefix_len(raw, $signed) {
+					0 => None,
+					len => parse_split(raw, len),
+				}
+			}
+		}
+	)*};
+}
+
+impl_parse_prefix_int!(false => u8, u16, u32, u64, u128, usize);
+impl_parse_prefix_int!(true => i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_parse_prefix_float {
+	($($t:ty),*) => {$(
+		impl ParsePrefix for $t {
+			#[inline]
+			fn parse_prefix(raw: &RawStr) -> Option<(Self, &RawStr)> {
+				match float_prefix_len(raw) {
+					0 => None,
+					len => parse_split(raw, len),
+				}
+			}
+		}
+	)*};
+}
+
+impl_parse_prefix_float!(f32, f64);
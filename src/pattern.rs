@@ -0,0 +1,93 @@
+// rawstring::pattern
+
+use std::str::pattern::{Pattern, ReverseSearcher, SearchStep, Searcher, StrSearcher, Utf8Pattern};
+
+use crate::RawStr;
+
+/// Searches a [`str`] for a [`RawStr`], so it can be used with [`str::find`], [`str::split`]
+/// and the other pattern-taking methods of [`str`]. Requires the `nightly` feature.
+///
+/// A [`RawStr`] that is valid UTF-8 matches like the equivalent `&str`.
+/// One that is not can never occur in a [`str`], so it matches nowhere.
+///
+/// # Examples
+/// ```
+/// # #![feature(pattern)]
+/// # use rawstring::RawStr;
+/// let text = "key=value; other=thing";
+/// assert_eq!(text.find(RawStr::new("other")), Some(11));
+/// assert_eq!(text.split(RawStr::new("; ")).collect::<Vec<_>>(), ["key=value", "other=thing"]);
+/// assert_eq!(text.rfind(RawStr::new("=")), Some(16));
+///
+/// assert_eq!(text.find(RawStr::new(b"key\xFF")), None);
+/// assert!(!text.contains(RawStr::new(b"\xFF")));
+/// ```
+impl<'b> Pattern for &'b RawStr {
+	type Searcher<'a> = RawStrSearcher<'a, 'b>;
+
+	#[inline]
+	fn into_searcher(self, haystack: &str) -> RawStrSearcher<'_, 'b> {
+		match self.to_utf8_checked() {
+			Ok(needle) => RawStrSearcher::Str(needle.into_searcher(haystack)),
+			Err(_) => RawStrSearcher::Never { haystack, done: false },
+		}
+	}
+
+	#[inline]
+	fn as_utf8_pattern(&self) -> Option<Utf8Pattern<'_>> {
+		self.to_utf8_checked().ok().map(Utf8Pattern::StringPattern)
+	}
+}
+
+/// The searcher for a [`RawStr`] pattern in a [`str`]. Requires the `nightly` feature.
+#[derive(Debug, Clone)]
+pub enum RawStrSearcher<'a, 'b> {
+	/// The needle is valid UTF-8 and is searched for as a `&str`.
+	Str(StrSearcher<'a, 'b>),
+	/// The needle is not valid UTF-8 and rejects the whole haystack.
+	Never {
+		/// The haystack being searched.
+		haystack: &'a str,
+		/// Whether the haystack has been rejected.
+		done: bool,
+	},
+}
+
+unsafe impl<'a> Searcher<'a> for RawStrSearcher<'a, '_> {
+	#[inline]
+	fn haystack(&self) -> &'a str {
+		match self {
+			Self::Str(searcher) => searcher.haystack(),
+			Self::Never { haystack, .. } => haystack,
+		}
+	}
+
+	#[inline]
+	fn next(&mut self) -> SearchStep {
+		match self {
+			Self::Str(searcher) => searcher.next(),
+			Self::Never { haystack, done } => reject_all(haystack, done),
+		}
+	}
+}
+
+unsafe impl<'a> ReverseSearcher<'a> for RawStrSearcher<'a, '_> {
+	#[inline]
+	fn next_back(&mut self) -> SearchStep {
+		match self {
+			Self::Str(searcher) => searcher.next_back(),
+			Self::Never { haystack, done } => reject_all(haystack, done),
+		}
+	}
+}
+
+/// Rejects the whole haystack the first time it is called, then reports that the search is done.
+#[inline]
+fn reject_all(haystack: &str, done: &mut bool) -> SearchStep {
+	if *done || haystack.is_empty() {
+		*done = true;
+		return SearchStep::Done;
+	}
+	*done = true;
+	SearchStep::Reject(0, haystack.len())
+}
@@ -0,0 +1,97 @@
+// rawstring::pool
+
+use crate::RawString;
+
+/// A pool of empty [`RawString`] buffers, for reusing allocations.
+///
+/// [`get`](Self::get) hands out a buffer from the pool, or a new one if the pool is empty,
+/// and [`clear_and_recycle`](Self::clear_and_recycle) clears a buffer and keeps it for reuse
+/// with its capacity intact. The pool holds at most `max_buffers` buffers, and buffers that
+/// have grown beyond `max_capacity` bytes are dropped instead of kept, so one large message
+/// does not pin its memory forever.
+///
+/// The pool is not synchronized; share it between threads by wrapping it in a
+/// [`Mutex`](std::sync::Mutex), or keep one per connection or thread.
+///
+/// # Examples
+/// ```
+/// # use rawstring::RawStringPool;
+/// let mut pool = RawStringPool::new(4, 64 * 1024);
+///
+/// let mut buf = pool.get();
+/// buf.extend_from_slice(b"GET / HTTP/1.1\r\n");
+/// let capacity = buf.capacity();
+/// pool.clear_and_recycle(buf);
+/// assert_eq!(pool.len(), 1);
+///
+/// let buf = pool.get();
+/// assert!(buf.is_empty());
+/// assert_eq!(buf.capacity(), capacity);
+/// assert!(pool.is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RawStringPool {
+	buffers: Vec<RawString>,
+	max_buffers: usize,
+	max_capacity: usize,
+}
+
+impl RawStringPool {
+	/// Creates an empty pool that keeps at most `max_buffers` buffers
+	/// with a capacity of at most `max_capacity` bytes each.
+	#[inline]
+	#[must_use]
+	pub const fn new(max_buffers: usize, max_capacity: usize) -> Self {
+		Self { buffers: Vec::new(), max_buffers, max_capacity }
+	}
+
+	/// Takes an empty buffer from the pool, or creates a new one if the pool is empty.
+	#[inline]
+	#[must_use]
+	pub fn get(&mut self) -> RawString {
+		self.buffers.pop().unwrap_or_default()
+	}
+
+	/// Clears `buf` and returns it to the pool, keeping its capacity.
+	///
+	/// The buffer is dropped instead if the pool is full or its capacity exceeds the maximum.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawString, RawStringPool};
+	/// let mut pool = RawStringPool::new(1, 16);
+	/// pool.clear_and_recycle(RawString::from(Vec::with_capacity(1024)));
+	/// assert!(pool.is_empty());
+	///
+	/// pool.clear_and_recycle(RawString::from("small"));
+	/// pool.clear_and_recycle(RawString::from("pool is full"));
+	/// assert_eq!(pool.len(), 1);
+	/// ```
+	#[inline]
+	pub fn clear_and_recycle(&mut self, mut buf: RawString) {
+		if self.buffers.len() < self.max_buffers && buf.capacity() <= self.max_capacity {
+			buf.clear();
+			self.buffers.push(buf);
+		}
+	}
+
+	/// Returns the number of buffers in the pool.
+	#[inline]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.buffers.len()
+	}
+
+	/// Returns `true` if the pool holds no buffers.
+	#[inline]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.buffers.is_empty()
+	}
+
+	/// Drops all buffers in the pool, releasing their memory.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.buffers.clear();
+	}
+}
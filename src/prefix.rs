@@ -0,0 +1,73 @@
+// rawstring::prefix
+
+use crate::{RawStr, RawString};
+
+impl RawStr {
+	/// Returns the index of the first pattern in `patterns` that the [`RawStr`] starts with,
+	/// or `None` if it starts with none of them.
+	///
+	/// Patterns are tried in order, so put longer patterns first when one is a prefix of another.
+	/// Candidates are filtered by their first byte before being compared in full.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let magic: [&[u8]; 4] = [b"\x89PNG", b"GIF87a", b"GIF89a", b"\xFF\xD8\xFF"];
+	/// assert_eq!(RawStr::new(b"GIF89a\x01\x00").starts_with_any(&magic), Some(2));
+	/// assert_eq!(RawStr::new(b"\xFF\xD8\xFF\xE0").starts_with_any(&magic), Some(3));
+	/// assert_eq!(RawStr::new("plain text").starts_with_any(&magic), None);
+	/// ```
+	#[must_use]
+	pub fn starts_with_any<P: AsRef<[u8]>>(&self, patterns: &[P]) -> Option<usize> {
+		let first = self.first();
+		patterns.iter().position(|p| {
+			let p = p.as_ref();
+			// an empty pattern has no first byte, and matches anything
+			p.first().is_none_or(|b| Some(b) == first && self.starts_with(p))
+		})
+	}
+
+	/// Returns the index of the first pattern in `patterns` that the [`RawStr`] ends with,
+	/// or `None` if it ends with none of them.
+	///
+	/// Patterns are tried in order, so put longer patterns first when one is a suffix of another.
+	/// Candidates are filtered by their last byte before being compared in full.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let endings = ["\r\n", "\n", "\r"];
+	/// assert_eq!(RawStr::new("line\r\n").ends_with_any(&endings), Some(0));
+	/// assert_eq!(RawStr::new(b"\xFF\n").ends_with_any(&endings), Some(1));
+	/// assert_eq!(RawStr::new("no newline").ends_with_any(&endings), None);
+	/// ```
+	#[must_use]
+	pub fn ends_with_any<P: AsRef<[u8]>>(&self, patterns: &[P]) -> Option<usize> {
+		let last = self.last();
+		patterns.iter().position(|p| {
+			let p = p.as_ref();
+			// an empty pattern has no last byte, and matches anything
+			p.last().is_none_or(|b| Some(b) == last && self.ends_with(p))
+		})
+	}
+}
+
+impl RawString {
+	/// Returns the index of the first pattern in `patterns` that the [`RawString`] starts with.
+	///
+	/// See [`RawStr::starts_with_any`].
+	#[inline]
+	#[must_use]
+	pub fn starts_with_any<P: AsRef<[u8]>>(&self, patterns: &[P]) -> Option<usize> {
+		self.as_ref().starts_with_any(patterns)
+	}
+
+	/// Returns the index of the first pattern in `patterns` that the [`RawString`] ends with.
+	///
+	/// See [`RawStr::ends_with_any`].
+	#[inline]
+	#[must_use]
+	pub fn ends_with_any<P: AsRef<[u8]>>(&self, patterns: &[P]) -> Option<usize> {
+		self.as_ref().ends_with_any(patterns)
+	}
+}
@@ -0,0 +1,107 @@
+// rawstring::push
+
+use std::mem::MaybeUninit;
+
+use crate::RawString;
+
+impl RawString {
+	/// Reserves capacity for at least `additional` more characters of any length,
+	/// so that that many calls to [`RawString::push_char`] will not reallocate.
+	///
+	/// # Panics
+	/// Panics if the new capacity exceeds `isize::MAX` bytes.
+	#[inline]
+	pub fn reserve_for_push_char(&mut self, additional: usize) {
+		self.0.reserve(additional.checked_mul(4).expect("capacity overflow"));
+	}
+
+	/// Appends `c` encoded as UTF-8.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"\xFF");
+	/// raw.push_char('a');
+	/// raw.push_char('\u{e9}');
+	/// raw.push_char('\u{1F980}');
+	/// assert_eq!(raw, RawString::from(b"\xFFa\xC3\xA9\xF0\x9F\xA6\x80"));
+	/// ```
+	#[inline]
+	pub fn push_char(&mut self, c: char) {
+		if c.is_ascii() {
+			self.0.push(c as u8);
+			return;
+		}
+		self.0.reserve(4);
+		let len = self.0.len();
+		let written = encode_utf8(c, self.0.spare_capacity_mut());
+		// SAFETY: `encode_utf8` initialized the first `written` bytes of the spare capacity
+		unsafe { self.0.set_len(len + written) };
+	}
+
+	/// Appends every character of `chars` encoded as UTF-8.
+	///
+	/// Capacity is reserved once up front from the iterator's size hint, which makes this
+	/// faster than calling [`RawString::push_char`] in a loop.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let mut raw = RawString::from(b"\xFE ");
+	/// raw.extend_chars("na\u{ef}ve".chars().rev());
+	/// assert_eq!(raw, RawString::from(b"\xFE ev\xC3\xAFan"));
+	/// ```
+	pub fn extend_chars<I>(&mut self, chars: I)
+	where
+		I: IntoIterator<Item = char>
+	{
+		let chars = chars.into_iter();
+		self.0.reserve(chars.size_hint().0);
+		chars.for_each(|c| self.push_char(c));
+	}
+}
+
+impl FromIterator<char> for RawString {
+	#[inline]
+	fn from_iter<I: IntoIterator<Item = char>>(chars: I) -> Self {
+		let mut raw = RawString::new();
+		raw.extend_chars(chars);
+		raw
+	}
+}
+
+/// Writes the UTF-8 encoding of `c` to the start of `dst`, returning the number of bytes written.
+///
+/// # Panics
+/// Panics if `dst` is shorter than the encoding.
+#[inline]
+fn encode_utf8(c: char, dst: &mut [MaybeUninit<u8>]) -> usize {
+	let code = u32::from(c);
+	match c.len_utf8() {
+		1 => {
+			dst[0].write(code as u8);
+			1
+		}
+		2 => {
+			let dst = &mut dst[..2];
+			dst[0].write(0xC0 | (code >> 6) as u8);
+			dst[1].write(0x80 | (code & 0x3F) as u8);
+			2
+		}
+		3 => {
+			let dst = &mut dst[..3];
+			dst[0].write(0xE0 | (code >> 12) as u8);
+			dst[1].write(0x80 | ((code >> 6) & 0x3F) as u8);
+			dst[2].write(0x80 | (code & 0x3F) as u8);
+			3
+		}
+		_ => {
+			let dst = &mut dst[..4];
+			dst[0].write(0xF0 | (code >> 18) as u8);
+			dst[1].write(0x80 | ((code >> 12) & 0x3F) as u8);
+			dst[2].write(0x80 | ((code >> 6) & 0x3F) as u8);
+			dst[3].write(0x80 | (code & 0x3F) as u8);
+			4
+		}
+	}
+}
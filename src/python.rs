@@ -0,0 +1,94 @@
+// rawstring::python
+
+use std::convert::Infallible;
+
+use pyo3::{
+	exceptions::PyTypeError,
+	prelude::*,
+	types::{PyByteArray, PyBytes, PyString},
+};
+
+use crate::{RawStr, RawString};
+
+/// Converts the [`RawString`] into Python `bytes`. Requires the `pyo3` feature.
+///
+/// # Examples
+/// ```
+/// # use rawstring::RawString;
+/// use pyo3::{prelude::*, types::PyBytes};
+///
+/// Python::initialize();
+/// Python::attach(|py| {
+///     let bytes = RawString::from(b"caf\xE9".as_slice()).into_pyobject(py).unwrap();
+///     assert_eq!(bytes.as_bytes(), b"caf\xE9");
+///
+///     let raw: RawString = py.eval(c"bytearray(b'\\xff')", None, None).unwrap().extract().unwrap();
+///     assert_eq!(raw, RawString::from(b"\xFF".as_slice()));
+///     let raw: RawString = py.eval(c"'h\\u00e9'", None, None).unwrap().extract().unwrap();
+///     assert_eq!(raw, RawString::from("hé"));
+///     assert!(py.eval(c"42", None, None).unwrap().extract::<RawString>().is_err());
+/// });
+/// ```
+impl<'py> IntoPyObject<'py> for RawString {
+	type Target = PyBytes;
+	type Output = Bound<'py, PyBytes>;
+	type Error = Infallible;
+
+	#[inline]
+	fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+		Ok(PyBytes::new(py, &self.0))
+	}
+}
+
+/// Copies the [`RawString`] into Python `bytes`. Requires the `pyo3` feature.
+impl<'py> IntoPyObject<'py> for &RawString {
+	type Target = PyBytes;
+	type Output = Bound<'py, PyBytes>;
+	type Error = Infallible;
+
+	#[inline]
+	fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+		Ok(PyBytes::new(py, &self.0))
+	}
+}
+
+/// Copies the [`RawStr`] into Python `bytes`. Requires the `pyo3` feature.
+impl<'py> IntoPyObject<'py> for &RawStr {
+	type Target = PyBytes;
+	type Output = Bound<'py, PyBytes>;
+	type Error = Infallible;
+
+	#[inline]
+	fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+		Ok(PyBytes::new(py, &self.0))
+	}
+}
+
+/// Extracts a [`RawString`] from Python `bytes`, `bytearray`, or `str`,
+/// which is encoded as UTF-8. Requires the `pyo3` feature.
+impl FromPyObject<'_, '_> for RawString {
+	type Error = PyErr;
+
+	fn extract(obj: Borrowed<'_, '_, PyAny>) -> PyResult<Self> {
+		if let Ok(bytes) = obj.cast::<PyBytes>() {
+			Ok(RawString::from(bytes.as_bytes()))
+		} else if let Ok(bytearray) = obj.cast::<PyByteArray>() {
+			Ok(RawString::from(bytearray.to_vec()))
+		} else if let Ok(string) = obj.cast::<PyString>() {
+			Ok(RawString::from(string.to_cow()?.into_owned()))
+		} else {
+			Err(PyTypeError::new_err("expected bytes, bytearray or str"))
+		}
+	}
+}
+
+/// Borrows a [`RawStr`] from Python `bytes` without copying. Requires the `pyo3` feature.
+impl<'a> FromPyObject<'a, '_> for &'a RawStr {
+	type Error = PyErr;
+
+	#[inline]
+	fn extract(obj: Borrowed<'a, '_, PyAny>) -> PyResult<Self> {
+		let bytes: &'a [u8] = obj.extract()?;
+		Ok(RawStr::new(bytes))
+	}
+}
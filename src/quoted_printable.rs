@@ -0,0 +1,156 @@
+// rawstring::quoted_printable
+
+use crate::{RawStr, RawString};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// The maximum length of an encoded line, without its line break, from RFC 2045, section 6.7.
+const MAX_LINE_LEN: usize = 76;
+
+/// Returns the number of spaces and tabs at the start of `bytes`.
+#[inline]
+fn whitespace_len(bytes: &[u8]) -> usize {
+	bytes.iter().take_while(|&&b| matches!(b, b' ' | b'\t')).count()
+}
+
+/// Returns `true` if `bytes` starts with a line break or is empty.
+#[inline]
+fn at_line_end(bytes: &[u8]) -> bool {
+	bytes.is_empty() || bytes.starts_with(b"\r\n") || bytes.starts_with(b"\n")
+}
+
+impl RawStr {
+	/// Encodes the [`RawStr`] in the quoted-printable encoding of RFC 2045.
+	/// Requires the `quoted-printable` feature.
+	///
+	/// `\r\n` line breaks are kept as line breaks; all other control bytes, including lone `\r`
+	/// and `\n`, are encoded, so any bytes round-trip through [`RawStr::decode_quoted_printable`].
+	/// Spaces and tabs at the end of a line are encoded, and lines longer than 76 characters
+	/// are split with soft line breaks (`=\r\n`), never inside an escape.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"caf\xE9 = 1 \r\nend\t");
+	/// assert_eq!(raw.encode_quoted_printable(), "caf=E9 =3D 1=20\r\nend=09");
+	///
+	/// let long = RawStr::new("x".repeat(100).as_str()).encode_quoted_printable();
+	/// assert_eq!(long, format!("{}=\r\n{}", "x".repeat(75), "x".repeat(25)));
+	/// ```
+	#[must_use]
+	pub fn encode_quoted_printable(&self) -> String {
+		let mut out = String::with_capacity(self.len() + self.len() / 2);
+		let mut line_len = 0;
+		let mut i = 0;
+		while i < self.len() {
+			if self[i..].starts_with(b"\r\n") {
+				out.push_str("\r\n");
+				line_len = 0;
+				i += 2;
+				continue;
+			}
+
+			let b = self[i];
+			let ends_line = self[i + 1..].is_empty() || self[i + 1..].starts_with(b"\r\n");
+			let literal = matches!(b, b'!'..=b'<' | b'>'..=b'~') || (matches!(b, b' ' | b'\t') && !ends_line);
+			let len = if literal { 1 } else { 3 };
+			// a line that continues needs room for the `=` of its soft line break
+			let max_len = if ends_line { MAX_LINE_LEN } else { MAX_LINE_LEN - 1 };
+			if line_len + len > max_len {
+				out.push_str("=\r\n");
+				line_len = 0;
+			}
+
+			if literal {
+				out.push(char::from(b));
+			} else {
+				out.push('=');
+				out.push(char::from(HEX_DIGITS[usize::from(b >> 4)]));
+				out.push(char::from(HEX_DIGITS[usize::from(b & 0xF)]));
+			}
+			line_len += len;
+			i += 1;
+		}
+		out
+	}
+
+	/// Decodes the [`RawStr`] from the quoted-printable encoding of RFC 2045.
+	/// Requires the `quoted-printable` feature.
+	///
+	/// Soft line breaks are removed, whether they end in `\r\n` or in a bare `\n`, as are spaces
+	/// and tabs at the end of lines, which the RFC requires decoders to ignore. Hard line breaks
+	/// are kept as they are. As the RFC recommends, decoding is lenient: an `=` that does not
+	/// start a valid escape is kept as it is, and lowercase hex digits are accepted.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// let encoded = RawStr::new("caf=E9 =3D 1=20\r\nend=09 \r\nsoft=\r\nbreak, 100=");
+	/// assert_eq!(encoded.decode_quoted_printable(), RawString::from(b"caf\xE9 = 1 \r\nend\t\r\nsoftbreak, 100"));
+	/// assert_eq!(RawStr::new("a=ZZ=e9").decode_quoted_printable(), RawString::from(b"a=ZZ\xE9"));
+	/// ```
+	#[must_use]
+	pub fn decode_quoted_printable(&self) -> RawString {
+		let mut out = Vec::with_capacity(self.len());
+		let mut i = 0;
+		while i < self.len() {
+			match self[i] {
+				b'=' => {
+					let rest = &self[i + 1..];
+					let ws = whitespace_len(rest);
+					let after = &rest[ws..];
+					if at_line_end(after) {
+						let line_break = if after.starts_with(b"\r\n") { 2 } else { usize::from(!after.is_empty()) };
+						i += 1 + ws + line_break;
+						continue;
+					}
+					let digit = |d: u8| char::from(d).to_digit(16);
+					match (digit(rest[0]), rest.get(1).and_then(|&d| digit(d))) {
+						(Some(high), Some(low)) => {
+							out.push((high << 4 | low) as u8);
+							i += 3;
+						}
+						_ => {
+							out.push(b'=');
+							i += 1;
+						}
+					}
+				}
+				b' ' | b'\t' => {
+					let ws = whitespace_len(&self[i..]);
+					if !at_line_end(&self[i + ws..]) {
+						out.extend_from_slice(&self[i..i + ws]);
+					}
+					i += ws;
+				}
+				b => {
+					out.push(b);
+					i += 1;
+				}
+			}
+		}
+		RawString::from(out)
+	}
+}
+
+impl RawString {
+	/// Encodes the [`RawString`] in the quoted-printable encoding of RFC 2045.
+	/// Requires the `quoted-printable` feature.
+	///
+	/// See [`RawStr::encode_quoted_printable`].
+	#[inline]
+	#[must_use]
+	pub fn encode_quoted_printable(&self) -> String {
+		self.as_ref().encode_quoted_printable()
+	}
+
+	/// Decodes the [`RawString`] from the quoted-printable encoding of RFC 2045.
+	/// Requires the `quoted-printable` feature.
+	///
+	/// See [`RawStr::decode_quoted_printable`].
+	#[inline]
+	#[must_use]
+	pub fn decode_quoted_printable(&self) -> RawString {
+		self.as_ref().decode_quoted_printable()
+	}
+}
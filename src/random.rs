@@ -0,0 +1,99 @@
+// rawstring::random
+
+use std::ops::RangeInclusive;
+
+use rand::{Rng, distr::Distribution};
+
+use crate::RawString;
+
+/// The bytes generated by [`RandomRawString`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Alphabet {
+	/// Any byte, uniformly.
+	#[default]
+	Binary,
+	/// ASCII letters and digits.
+	Alphanumeric,
+	/// Printable ASCII with occasional invalid UTF-8 bytes mixed in,
+	/// for exercising code paths that handle both text and invalid data.
+	PrintableWithInvalid,
+}
+
+/// A [`Distribution`] of [`RawString`]s with a length in `len` and bytes from `alphabet`.
+///
+/// Requires the `rand` feature.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{Alphabet, RandomRawString, RawString};
+/// use rand::Rng;
+///
+/// let mut rng = rand::rng();
+/// let token: RawString = rng.sample(RandomRawString::new(32, Alphabet::Alphanumeric));
+/// assert_eq!(token.len(), 32);
+/// assert!(token.iter().all(u8::is_ascii_alphanumeric));
+///
+/// let mixed = RandomRawString { len: 0..=64, alphabet: Alphabet::PrintableWithInvalid };
+/// for raw in rng.sample_iter(mixed).take(100) {
+///     assert!(raw.len() <= 64);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RandomRawString {
+	/// The range of lengths, in bytes.
+	pub len: RangeInclusive<usize>,
+	/// The bytes to choose from.
+	pub alphabet: Alphabet,
+}
+
+impl RandomRawString {
+	/// Creates a distribution of [`RawString`]s of exactly `len` bytes.
+	#[inline]
+	#[must_use]
+	pub const fn new(len: usize, alphabet: Alphabet) -> Self {
+		Self { len: len..=len, alphabet }
+	}
+}
+
+const ALPHANUMERIC: &[u8; 62] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+impl Distribution<RawString> for RandomRawString {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> RawString {
+		let len = rng.random_range(self.len.clone());
+		match self.alphabet {
+			Alphabet::Binary => RawString::random_bytes(len, rng),
+			Alphabet::Alphanumeric => {
+				(0..len).map(|_| ALPHANUMERIC[rng.random_range(0..ALPHANUMERIC.len())]).collect::<Vec<_>>().into()
+			}
+			Alphabet::PrintableWithInvalid => {
+				(0..len).map(|_| {
+					// about one byte in sixteen is a lone continuation byte or an invalid lead byte
+					if rng.random_ratio(1, 16) {
+						rng.random_range(0x80..=0xFF)
+					} else {
+						rng.random_range(b' '..=b'~')
+					}
+				}).collect::<Vec<_>>().into()
+			}
+		}
+	}
+}
+
+impl RawString {
+	/// Creates a [`RawString`] of `len` uniformly random bytes.
+	///
+	/// Requires the `rand` feature.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// let nonce = RawString::random_bytes(12, &mut rand::rng());
+	/// assert_eq!(nonce.len(), 12);
+	/// ```
+	#[must_use]
+	pub fn random_bytes<R: Rng + ?Sized>(len: usize, rng: &mut R) -> RawString {
+		let mut bytes = vec![0; len];
+		rng.fill_bytes(&mut bytes);
+		RawString(bytes)
+	}
+}
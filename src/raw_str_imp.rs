@@ -1,12 +1,16 @@
 // raw_string::raw_str_imp
 
 use std::{
+	borrow::Cow,
 	cmp::Ordering,
 	fmt::{self, Write},
-	ops::{Deref, DerefMut},
+	ops::{Deref, DerefMut, Range},
+	slice,
 	str::Utf8Error,
 };
 
+use crate::RawString;
+
 /// A borrowed string slice that may or may not contain valid UTF-8.
 /// 
 /// [`RawStr`] serves as an alternative to Rust's [`str`] type
@@ -30,6 +34,21 @@ use std::{
 pub struct RawStr(pub [u8]);
 
 impl RawStr {
+	/// An empty [`RawStr`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// fn first_or_empty<S: Default + AsRef<RawStr>>(items: Vec<S>) -> S {
+	///     items.into_iter().next().unwrap_or_default()
+	/// }
+	///
+	/// assert_eq!(first_or_empty(Vec::<&RawStr>::new()), RawStr::EMPTY);
+	/// assert_eq!(first_or_empty(Vec::<RawString>::new()), RawString::new());
+	/// assert!(RawStr::EMPTY.is_empty());
+	/// ```
+	pub const EMPTY: &'static RawStr = RawStr::from_bytes(&[]);
+
 	/// Returns a reference to a [`RawStr`] from any type
 	/// that can be referenced as a byte slice.
 	/// 
@@ -86,6 +105,91 @@ impl RawStr {
 		unsafe { &mut *(bytes as *mut [u8] as *mut RawStr) }
 	}
 
+	/// Forms a [`RawStr`] from a pointer and a length, such as a buffer received over FFI.
+	///
+	/// See [`slice::from_raw_parts`].
+	///
+	/// # Safety
+	/// - `ptr` must be non-null and aligned, even if `len` is zero. A dangling pointer
+	///   such as [`NonNull::dangling`](std::ptr::NonNull::dangling) is fine for an empty slice,
+	///   but a null pointer from C is not; check for it first.
+	/// - Unless `len` is zero, `ptr` must point to `len` initialized bytes within a single
+	///   allocation.
+	/// - The bytes must not be mutated for the chosen lifetime `'a`, by Rust or foreign code.
+	/// - `len` must be no greater than [`isize::MAX`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let data = b"foreign \xFF data";
+	/// let (ptr, len) = (data.as_ptr(), data.len());
+	/// // SAFETY: ptr and len describe `data`, which outlives `raw` and is not mutated
+	/// let raw = unsafe { RawStr::from_raw_parts(ptr, len) };
+	/// assert_eq!(raw, data);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const unsafe fn from_raw_parts<'a>(ptr: *const u8, len: usize) -> &'a Self {
+		// SAFETY: upheld by the caller
+		Self::from_bytes(unsafe { slice::from_raw_parts(ptr, len) })
+	}
+
+	/// Forms a mutable [`RawStr`] from a pointer and a length, such as a buffer received over FFI.
+	///
+	/// See [`slice::from_raw_parts_mut`].
+	///
+	/// # Safety
+	/// The requirements of [`RawStr::from_raw_parts`] apply, and in addition the bytes must not
+	/// be accessed through any other pointer, including by foreign code, for the chosen lifetime `'a`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let mut data = *b"hello";
+	/// let (ptr, len) = (data.as_mut_ptr(), data.len());
+	/// // SAFETY: ptr and len describe `data`, which is not accessed while `raw` is alive
+	/// let raw = unsafe { RawStr::from_raw_parts_mut(ptr, len) };
+	/// raw.make_ascii_uppercase();
+	/// assert_eq!(&data, b"HELLO");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const unsafe fn from_raw_parts_mut<'a>(ptr: *mut u8, len: usize) -> &'a mut Self {
+		// SAFETY: upheld by the caller
+		Self::from_bytes_mut(unsafe { slice::from_raw_parts_mut(ptr, len) })
+	}
+
+	/// Returns the two raw pointers spanning the [`RawStr`].
+	///
+	/// The end pointer is one past the last byte. Passing the start pointer and the length
+	/// to [`RawStr::from_raw_parts`] gives back the same [`RawStr`]. See [`<[u8]>::as_ptr_range`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new("key=value");
+	/// let (key, value) = raw.split_once_byte(b'=').unwrap();
+	/// let range = raw.as_ptr_range();
+	/// assert!(range.contains(&value.as_ptr()));
+	/// assert_eq!(range.end, value.as_ptr_range().end);
+	/// // the offset of a subslice within its parent
+	/// assert_eq!(value.as_ptr() as usize - range.start as usize, key.len() + 1);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn as_ptr_range(&self) -> Range<*const u8> {
+		self.0.as_ptr_range()
+	}
+
+	/// Returns the two unsafe mutable pointers spanning the [`RawStr`].
+	///
+	/// See [`RawStr::as_ptr_range`] and [`<[u8]>::as_mut_ptr_range`].
+	#[inline]
+	#[must_use]
+	pub const fn as_mut_ptr_range(&mut self) -> Range<*mut u8> {
+		self.0.as_mut_ptr_range()
+	}
+
 	/// Converts the [`RawStr`] to a [`str`] if it contains valid UTF-8.
 	/// Returns a [`Utf8Error`] if the bytes are not valid UTF-8.
 	/// 
@@ -101,7 +205,7 @@ impl RawStr {
 	/// assert!(bad.to_utf8_checked().is_err());
 	/// ```
 	#[inline]
-	#[must_use]
+	#[must_use = "this only checks the bytes; use the returned `str` or handle the error"]
 	pub const fn to_utf8_checked(&self) -> Result<&str, Utf8Error> {
 		str::from_utf8(&self.0)
 	}
@@ -122,6 +226,50 @@ impl RawStr {
 	pub const fn is_utf8(&self) -> bool {
 		self.to_utf8_checked().is_ok()
 	}
+
+	/// Lossily converts the [`RawStr`] to a [`str`].
+	/// Invalid UTF-8 sequences are replaced with the replacement character (�).
+	///
+	/// See [`String::from_utf8_lossy`].
+	///
+	/// # Panics
+	/// With the `strict` feature in debug builds, panics if the [`RawStr`] is not valid UTF-8.
+	#[inline]
+	#[must_use]
+	#[track_caller]
+	pub fn to_utf8_lossy(&self) -> Cow<'_, str> {
+		crate::strict::check_lossless(&self.0, "to_utf8_lossy");
+		String::from_utf8_lossy(&self.0)
+	}
+
+	/// Lossily converts the [`RawStr`] to UTF-8, writing the result into `buf` after clearing it.
+	///
+	/// This reuses the allocation of `buf`. See [`RawStr::to_utf8_lossy`].
+	///
+	/// # Panics
+	/// With the `strict` feature in debug builds, panics if the [`RawStr`] is not valid UTF-8.
+	///
+	/// # Examples
+	/// ```
+	/// # #[cfg(not(feature = "strict"))] {
+	/// # use rawstring::{RawStr, RawString};
+	/// let mut buf = RawString::new();
+	/// RawStr::new(b"a\xFFb").to_utf8_lossy_into(&mut buf);
+	/// assert_eq!(buf, RawString::from("a�b"));
+	/// # }
+	/// ```
+	#[track_caller]
+	pub fn to_utf8_lossy_into(&self, buf: &mut RawString) {
+		crate::strict::check_lossless(&self.0, "to_utf8_lossy_into");
+		buf.clear();
+		buf.reserve(self.len());
+		for chunk in self.utf8_chunks() {
+			buf.extend_from_slice(chunk.valid().as_bytes());
+			if !chunk.invalid().is_empty() {
+				buf.extend_from_slice(crate::UNICODE_REPLACEMENT_CHARACTER.encode_utf8(&mut [0; 4]).as_bytes());
+			}
+		}
+	}
 }
 
 impl const Deref for RawStr {
@@ -154,33 +302,153 @@ impl const AsMut<[u8]> for RawStr {
 	}
 }
 
+impl const AsRef<RawStr> for RawStr {
+	#[inline]
+	fn as_ref(&self) -> &RawStr {
+		self
+	}
+}
+
+impl const AsMut<RawStr> for RawStr {
+	#[inline]
+	fn as_mut(&mut self) -> &mut RawStr {
+		self
+	}
+}
+
+impl Default for &RawStr {
+	#[inline]
+	fn default() -> Self {
+		RawStr::EMPTY
+	}
+}
+
+impl Default for &mut RawStr {
+	#[inline]
+	fn default() -> Self {
+		RawStr::from_bytes_mut(&mut [])
+	}
+}
+
+impl ToOwned for RawStr {
+	type Owned = RawString;
+
+	#[inline]
+	fn to_owned(&self) -> RawString {
+		RawString::from_bytes(self.0.to_vec())
+	}
+}
+
+/// Formats the [`RawStr`] as an escaped string literal.
+///
+/// The alternate form (`{:#?}`) renders a hex dump instead, with 16 bytes per row,
+/// an offset column and an ASCII gutter, which is easier to read for binary data.
+///
+/// A precision (`{:.N?}`) limits the output to the first `N` bytes,
+/// followed by the number of bytes left out. See also [`RawStr::debug_truncated`].
+///
+/// # Examples
+/// ```
+/// # use rawstring::RawStr;
+/// let raw = RawStr::new(b"hello world\n\0\xFF\xFEAB");
+/// assert_eq!(format!("{raw:?}"), r#""hello world\n\0\xff\xfeAB""#);
+/// assert_eq!(format!("{raw:.5?}"), r#""hello"… (+12 bytes)"#);
+/// assert_eq!(format!("{raw:#?}"), "\
+/// 00000000  68 65 6c 6c 6f 20 77 6f  72 6c 64 0a 00 ff fe 41  |hello world....A|
+/// 00000010  42                                                |B|");
+/// ```
 impl fmt::Debug for RawStr {
+	#[inline]
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "\"")?;
-		for chunk in self.utf8_chunks() {
-			for c in chunk.valid().chars() {
-				match c {
-					'\0' => write!(f, "\\0")?,
-					'\x01'..='\x7F' => write!(f, "{}", (c as u8).escape_ascii())?,
-					_ => write!(f, "{}", c.escape_debug())?,
-				}
+		fmt_debug(self, f.precision(), f)
+	}
+}
+
+/// Formats `raw` for [`Debug`](fmt::Debug), showing at most `max_len` bytes.
+pub(crate) fn fmt_debug(raw: &RawStr, max_len: Option<usize>, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+	let shown = &raw[..raw.len().min(max_len.unwrap_or(usize::MAX))];
+	let omitted = raw.len() - shown.len();
+
+	if f.alternate() && !shown.is_empty() {
+		fmt_hex_dump(shown, f)?;
+		if omitted > 0 {
+			write!(f, "\n… (+{omitted} bytes)")?;
+		}
+		return Ok(());
+	}
+
+	write!(f, "\"")?;
+	for chunk in shown.utf8_chunks() {
+		for c in chunk.valid().chars() {
+			match c {
+				'\0' => write!(f, "\\0")?,
+				'\x01'..='\x7F' => write!(f, "{}", (c as u8).escape_ascii())?,
+				_ => write!(f, "{}", c.escape_debug())?,
+			}
+		}
+		write!(f, "{}", chunk.invalid().escape_ascii())?;
+	}
+	write!(f, "\"")?;
+	if omitted > 0 {
+		write!(f, "… (+{omitted} bytes)")?;
+	}
+	Ok(())
+}
+
+/// Writes `bytes` in the canonical hex dump format, without a trailing newline.
+fn fmt_hex_dump(bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+	for (row, chunk) in bytes.chunks(16).enumerate() {
+		if row > 0 {
+			f.write_char('\n')?;
+		}
+		write!(f, "{:08x} ", row * 16)?;
+		for i in 0..16 {
+			if i == 8 {
+				f.write_char(' ')?;
 			}
-			write!(f, "{}", chunk.invalid().escape_ascii())?;
+			match chunk.get(i) {
+				Some(byte) => write!(f, " {byte:02x}")?,
+				None => f.write_str("   ")?,
+			}
+		}
+		f.write_str("  |")?;
+		for &byte in chunk {
+			let c = if byte.is_ascii_graphic() || byte == b' ' { byte as char } else { '.' };
+			f.write_char(c)?;
 		}
-		write!(f, "\"")?;
-		Ok(())
+		f.write_char('|')?;
 	}
+	Ok(())
 }
 
+/// Formats the bytes as UTF-8, replacing invalid sequences with the replacement character.
+///
+/// With the `strict` feature in debug builds, panics if the bytes are not valid UTF-8.
 impl fmt::Display for RawStr {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		fn fmt_no_pad(this: &RawStr, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		crate::strict::check_lossless(&self.0, "Display");
+		self.fmt_lossy(f, crate::raw_str_imp::REPLACEMENT_STR)
+	}
+}
+
+/// [`UNICODE_REPLACEMENT_CHARACTER`](crate::UNICODE_REPLACEMENT_CHARACTER) as a string.
+pub(crate) const REPLACEMENT_STR: &str = "\u{FFFD}";
+
+impl RawStr {
+	/// Formats the bytes as [`Display`](fmt::Display) does, without the `strict` mode check,
+	/// writing `replacement` for each invalid sequence.
+	pub(crate) fn fmt_lossy(&self, f: &mut fmt::Formatter<'_>, replacement: &str) -> fmt::Result {
+		fn fmt_no_pad(this: &RawStr, f: &mut fmt::Formatter<'_>, replacement: &str) -> fmt::Result {
 			// formats the bytes as utf8 without any padding
-			// invalid utf8 chunks are replaced with the replacement character
+			// invalid utf8 chunks are replaced with the replacement
+			if this.is_ascii() {
+				// SAFETY: ASCII is valid UTF-8
+				return f.write_str(unsafe { str::from_utf8_unchecked(&this.0) });
+			}
 			for chunk in this.utf8_chunks() {
 				f.write_str(chunk.valid())?;
 				if !chunk.invalid().is_empty() {
-					f.write_char(crate::UNICODE_REPLACEMENT_CHARACTER)?;
+					f.write_str(replacement)?;
 				}
 			}
 			Ok(())
@@ -188,13 +456,17 @@ impl fmt::Display for RawStr {
 
 		if let Some(align) = f.align() {
 			// calculate the padding on both sides
-			let len: usize = self
-				.utf8_chunks()
-				.map(|chunk| {
-					chunk.valid().chars().count()
-					+ if chunk.invalid().is_empty() { 0 } else { 1 }
-				})
-				.sum();
+			let len: usize = if self.is_ascii() {
+				self.len()
+			} else {
+				self
+					.utf8_chunks()
+					.map(|chunk| {
+						chunk.valid().chars().count()
+						+ if chunk.invalid().is_empty() { 0 } else { replacement.chars().count() }
+					})
+					.sum()
+			};
 			let total_padding = f.width()
 				.unwrap_or(0)
 				.saturating_sub(len);
@@ -212,7 +484,7 @@ impl fmt::Display for RawStr {
 			for _ in 0..lpad {
 				f.write_char(fill)?;
 			}
-			fmt_no_pad(self, f)?;
+			fmt_no_pad(self, f, replacement)?;
 			for _ in 0..rpad {
 				f.write_char(fill)?;
 			}
@@ -221,7 +493,7 @@ impl fmt::Display for RawStr {
 		} else {
 			// no padding needed
 			// directly format the bytes
-			fmt_no_pad(self, f)
+			fmt_no_pad(self, f, replacement)
 		}
 	}
 }
@@ -2,7 +2,9 @@
 
 use std::{
 	borrow::{Cow, Borrow, BorrowMut},
+	error::Error,
 	ops::{Deref, DerefMut},
+	str::Utf8Error,
 	string::FromUtf8Error,
 	fmt,
 };
@@ -23,10 +25,12 @@ pub struct RawString(pub Vec<u8>);
 
 impl RawString {
 	/// Creates a new, empty [`RawString`].
+	///
+	/// This is the same as [`RawString::default`], but usable in constant expressions.
 	#[inline]
 	#[must_use]
-	pub fn new() -> Self {
-		Self::from(Vec::new())
+	pub const fn new() -> Self {
+		Self(Vec::new())
 	}
 
 	/// Creates a new [`RawString`] from any type that can be converted into a `Vec<u8>`.
@@ -42,6 +46,7 @@ impl RawString {
 	/// Returns a reference to the inner byte slice as a [`RawStr`].
 	#[inline]
 	#[must_use]
+	#[allow(clippy::should_implement_trait)]
 	pub fn as_ref(&self) -> &RawStr {
 		RawStr::from_bytes(&self.0)
 	}
@@ -50,6 +55,7 @@ impl RawString {
 	#[doc(hidden)]
 	#[inline]
 	#[must_use]
+	#[allow(clippy::should_implement_trait)]
 	pub fn as_mut(&mut self) -> &mut RawStr {
 		RawStr::from_bytes_mut(&mut self.0)
 	}
@@ -67,11 +73,36 @@ impl RawString {
 	/// 
 	/// See [`String::from_utf8`].
 	#[inline]
-	#[must_use]
+	#[must_use = "this consumes the `RawString`; dropping the result discards it"]
 	pub fn to_utf8_checked(self) -> Result<String, FromUtf8Error> {
 		String::from_utf8(self.0)
 	}
 
+	/// Converts the [`RawString`] into a [`String`] if it contains valid UTF-8, without copying.
+	///
+	/// # Errors
+	/// Returns an [`IntoStringError`] if the bytes are not valid UTF-8.
+	/// The error gives the original [`RawString`] back, so the allocation is never lost.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawString;
+	/// assert_eq!(RawString::from("caf\u{e9}").into_string(), Ok(String::from("caf\u{e9}")));
+	///
+	/// let err = RawString::from(b"caf\xE9").into_string().unwrap_err();
+	/// assert_eq!(err.utf8_error().valid_up_to(), 3);
+	/// assert_eq!(err.to_string(), "incomplete utf-8 byte sequence from index 3");
+	/// assert_eq!(err.into_raw_string(), RawString::from(b"caf\xE9"));
+	/// ```
+	#[inline]
+	pub fn into_string(self) -> Result<String, IntoStringError> {
+		match str::from_utf8(&self.0) {
+			// SAFETY: the bytes were just checked to be valid UTF-8
+			Ok(_) => Ok(unsafe { String::from_utf8_unchecked(self.0) }),
+			Err(error) => Err(IntoStringError { raw: self, error }),
+		}
+	}
+
 	/// Converts the [`RawString`] into a [`String`] without checking for valid UTF-8.
 	/// 
 	/// # Safety
@@ -86,10 +117,14 @@ impl RawString {
 
 	/// Lossily converts the [`RawString`] into a [`String`].
 	/// Invalid UTF-8 sequences are replaced with the replacement character (�).
+	///
+	/// # Panics
+	/// With the `strict` feature in debug builds, panics if the [`RawString`] is not valid UTF-8.
 	#[inline]
 	#[must_use]
+	#[track_caller]
 	pub fn to_utf8_lossy(&self) -> Cow<'_, str> {
-		String::from_utf8_lossy(&self.0)
+		self.as_ref().to_utf8_lossy()
 	}
 
 	/// Returns `true` if the [`RawString`] contains valid UTF-8.
@@ -188,6 +223,20 @@ impl fmt::Display for RawString {
 	}
 }
 
+/// Converts anything that converts into a [`Vec<u8>`], without copying where [`Vec<u8>`] does not.
+///
+/// # Examples
+/// ```
+/// # use std::borrow::Cow;
+/// # use rawstring::{RawStr, RawString};
+/// let from_str = RawString::from("abc");
+/// assert_eq!(RawString::from(String::from("abc")), from_str);
+/// assert_eq!(RawString::from(*b"abc"), from_str);
+/// assert_eq!(RawString::from(b"abc".as_slice()), from_str);
+/// assert_eq!(RawString::from(Box::<[u8]>::from(*b"abc")), from_str);
+/// assert_eq!(RawString::from(Cow::Borrowed(b"abc".as_slice())), from_str);
+/// assert_eq!(RawString::from(RawStr::new("abc")), from_str);
+/// ```
 impl<T: Into<Vec<u8>>> From<T> for RawString {
 	#[inline]
 	fn from(value: T) -> Self {
@@ -202,4 +251,112 @@ impl TryFrom<RawString> for String {
 	fn try_from(this: RawString) -> Result<String, FromUtf8Error> {
 		String::from_utf8(this.0)
 	}
-}
\ No newline at end of file
+}
+
+/// An error returned by [`RawString::into_string`] when the bytes are not valid UTF-8.
+///
+/// The original [`RawString`] can be recovered with [`IntoStringError::into_raw_string`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntoStringError {
+	raw: RawString,
+	error: Utf8Error,
+}
+
+impl IntoStringError {
+	/// Returns the [`Utf8Error`] describing where the bytes stop being valid UTF-8.
+	#[inline]
+	#[must_use]
+	pub const fn utf8_error(&self) -> Utf8Error {
+		self.error
+	}
+
+	/// Returns the bytes that failed to convert.
+	#[inline]
+	#[must_use]
+	pub fn as_raw_str(&self) -> &RawStr {
+		self.raw.as_ref()
+	}
+
+	/// Returns the [`RawString`] that failed to convert.
+	#[inline]
+	#[must_use]
+	pub fn into_raw_string(self) -> RawString {
+		self.raw
+	}
+}
+
+impl fmt::Display for IntoStringError {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.error.fmt(f)
+	}
+}
+
+impl Error for IntoStringError {
+	#[inline]
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		Some(&self.error)
+	}
+}
+
+impl From<&RawStr> for Vec<u8> {
+	#[inline]
+	fn from(raw: &RawStr) -> Self {
+		raw.0.to_vec()
+	}
+}
+
+impl FromIterator<u8> for RawString {
+	#[inline]
+	fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+		Self(Vec::from_iter(iter))
+	}
+}
+
+/// Concatenates the pieces into a new [`RawString`].
+impl<'a> FromIterator<&'a RawStr> for RawString {
+	fn from_iter<I: IntoIterator<Item = &'a RawStr>>(iter: I) -> Self {
+		let mut raw = RawString::new();
+		for piece in iter {
+			raw.extend_from_slice(piece);
+		}
+		raw
+	}
+}
+
+/// Concatenates the pieces into a new [`RawString`].
+///
+/// Together with the standard `FromIterator` impl for [`Result`], this lets fallible
+/// sources such as [`BufRead::split`](std::io::BufRead::split) be collected into an
+/// `io::Result<RawString>`.
+///
+/// # Examples
+/// ```
+/// # use std::io::{self, BufRead};
+/// # use rawstring::RawString;
+/// let input = io::Cursor::new(b"a;b\xFF;c");
+/// let joined: io::Result<RawString> = input.split(b';').collect();
+/// assert_eq!(joined.unwrap(), RawString::from(b"ab\xFFc"));
+/// ```
+impl FromIterator<Vec<u8>> for RawString {
+	fn from_iter<I: IntoIterator<Item = Vec<u8>>>(iter: I) -> Self {
+		let mut iter = iter.into_iter();
+		let Some(first) = iter.next() else {
+			return RawString::new();
+		};
+		// reuse the first allocation
+		let mut raw = RawString(first);
+		for piece in iter {
+			raw.extend_from_slice(&piece);
+		}
+		raw
+	}
+}
+
+/// Concatenates the pieces into a new [`RawString`].
+impl FromIterator<RawString> for RawString {
+	#[inline]
+	fn from_iter<I: IntoIterator<Item = RawString>>(iter: I) -> Self {
+		iter.into_iter().map(|raw| raw.0).collect()
+	}
+}
@@ -0,0 +1,229 @@
+// rawstring::reader
+
+use std::{error::Error, fmt};
+
+use crate::RawStr;
+
+/// A cursor for reading binary data from a [`RawStr`].
+///
+/// The reader borrows the data and tracks a position into it; reads advance the position
+/// on success and leave it unchanged on failure.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{RawStr, RawStrReader};
+/// let data = RawStr::new(b"\x01\x00\x00\x00\x02\xAC\x02\x03abc");
+/// let mut reader = RawStrReader::new(data);
+/// assert_eq!(reader.read_u8(), Ok(0x01));
+/// assert_eq!(reader.read_u16_le(), Ok(0));
+/// assert_eq!(reader.read_u16_be(), Ok(0x0002));
+/// assert_eq!(reader.read_var_u64(), Ok(300));
+/// let len = reader.read_u8().unwrap();
+/// assert_eq!(reader.read_exact_raw(len.into()), Ok(RawStr::new("abc")));
+/// assert!(reader.is_empty());
+///
+/// let err = reader.read_u32_le().unwrap_err();
+/// assert_eq!(err.to_string(), "unexpected end of input at byte 11: needed 4 bytes, 0 remaining");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RawStrReader<'a> {
+	data: &'a RawStr,
+	pos: usize,
+}
+
+/// An error returned by [`RawStrReader`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReadError {
+	position: usize,
+	kind: ReadErrorKind,
+}
+
+/// The kind of a [`ReadError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReadErrorKind {
+	/// The input ended before the value was complete.
+	UnexpectedEof {
+		/// The number of bytes the read needed.
+		needed: usize,
+		/// The number of bytes that were left.
+		remaining: usize,
+	},
+	/// A variable-length integer did not fit in the target type.
+	VarintOverflow,
+}
+
+impl ReadError {
+	/// Returns the position of the reader when the read failed.
+	#[inline]
+	#[must_use]
+	pub const fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Returns the kind of error.
+	#[inline]
+	#[must_use]
+	pub const fn kind(&self) -> ReadErrorKind {
+		self.kind
+	}
+}
+
+impl fmt::Display for ReadError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.kind {
+			ReadErrorKind::UnexpectedEof { needed, remaining } => write!(
+				f,
+				"unexpected end of input at byte {}: needed {needed} bytes, {remaining} remaining",
+				self.position,
+			),
+			ReadErrorKind::VarintOverflow => write!(f, "variable-length integer at byte {} overflows", self.position),
+		}
+	}
+}
+
+impl Error for ReadError {}
+
+macro_rules! read_int {
+	($($t:ty => $le:ident, $be:ident;)*) => {$(
+		#[doc = concat!("Reads a little-endian [`", stringify!($t), "`].")]
+		#[inline]
+		pub fn $le(&mut self) -> Result<$t, ReadError> {
+			self.read_array().map(<$t>::from_le_bytes)
+		}
+
+		#[doc = concat!("Reads a big-endian [`", stringify!($t), "`].")]
+		#[inline]
+		pub fn $be(&mut self) -> Result<$t, ReadError> {
+			self.read_array().map(<$t>::from_be_bytes)
+		}
+	)*};
+}
+
+impl<'a> RawStrReader<'a> {
+	/// Creates a reader positioned at the start of `data`.
+	#[inline]
+	#[must_use]
+	pub const fn new(data: &'a RawStr) -> Self {
+		Self { data, pos: 0 }
+	}
+
+	/// Returns the current position in bytes from the start of the data.
+	#[inline]
+	#[must_use]
+	pub const fn position(&self) -> usize {
+		self.pos
+	}
+
+	/// Moves the reader to `pos`, clamped to the length of the data.
+	#[inline]
+	pub fn set_position(&mut self, pos: usize) {
+		self.pos = pos.min(self.data.len());
+	}
+
+	/// Returns the data the reader was created with.
+	#[inline]
+	#[must_use]
+	pub const fn get_ref(&self) -> &'a RawStr {
+		self.data
+	}
+
+	/// Returns the data that has not been read yet.
+	#[inline]
+	#[must_use]
+	pub fn remaining(&self) -> &'a RawStr {
+		RawStr::new(&self.data[self.pos..])
+	}
+
+	/// Returns `true` if all data has been read.
+	#[inline]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.pos == self.data.len()
+	}
+
+	/// Returns the next byte without advancing the reader.
+	#[inline]
+	#[must_use]
+	pub fn peek_u8(&self) -> Option<u8> {
+		self.data.get(self.pos).copied()
+	}
+
+	/// Reads the next `n` bytes as a [`RawStr`] borrowed from the data.
+	pub fn read_exact_raw(&mut self, n: usize) -> Result<&'a RawStr, ReadError> {
+		let remaining = self.data.len() - self.pos;
+		if n > remaining {
+			return Err(self.error(ReadErrorKind::UnexpectedEof { needed: n, remaining }));
+		}
+		let raw = RawStr::new(&self.data[self.pos..self.pos + n]);
+		self.pos += n;
+		Ok(raw)
+	}
+
+	/// Advances the reader by `n` bytes.
+	#[inline]
+	pub fn skip(&mut self, n: usize) -> Result<(), ReadError> {
+		self.read_exact_raw(n).map(drop)
+	}
+
+	/// Reads a fixed-size array of bytes.
+	#[inline]
+	pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], ReadError> {
+		self.read_exact_raw(N).map(|raw| raw.0.try_into().expect("read exactly N bytes"))
+	}
+
+	/// Reads a [`u8`].
+	#[inline]
+	pub fn read_u8(&mut self) -> Result<u8, ReadError> {
+		self.read_array().map(|[b]| b)
+	}
+
+	/// Reads an [`i8`].
+	#[inline]
+	pub fn read_i8(&mut self) -> Result<i8, ReadError> {
+		self.read_array().map(i8::from_le_bytes)
+	}
+
+	read_int! {
+		u16 => read_u16_le, read_u16_be;
+		u32 => read_u32_le, read_u32_be;
+		u64 => read_u64_le, read_u64_be;
+		u128 => read_u128_le, read_u128_be;
+		i16 => read_i16_le, read_i16_be;
+		i32 => read_i32_le, read_i32_be;
+		i64 => read_i64_le, read_i64_be;
+		i128 => read_i128_le, read_i128_be;
+		f32 => read_f32_le, read_f32_be;
+		f64 => read_f64_le, read_f64_be;
+	}
+
+	/// Reads an unsigned LEB128 variable-length integer, as used by Protocol Buffers and WebAssembly.
+	pub fn read_var_u64(&mut self) -> Result<u64, ReadError> {
+		let mut value = 0u64;
+		for (i, &byte) in self.data[self.pos..].iter().enumerate() {
+			let shift = 7 * i as u32;
+			let bits = u64::from(byte & 0x7F);
+			if shift >= 64 || (shift > 0 && bits >> (64 - shift) != 0) {
+				return Err(self.error(ReadErrorKind::VarintOverflow));
+			}
+			value |= bits << shift;
+			if byte & 0x80 == 0 {
+				self.pos += i + 1;
+				return Ok(value);
+			}
+		}
+		let remaining = self.data.len() - self.pos;
+		Err(self.error(ReadErrorKind::UnexpectedEof { needed: remaining + 1, remaining }))
+	}
+
+	/// Reads a signed LEB128 variable-length integer using zigzag encoding.
+	#[inline]
+	pub fn read_var_i64(&mut self) -> Result<i64, ReadError> {
+		self.read_var_u64().map(|n| (n >> 1) as i64 ^ -((n & 1) as i64))
+	}
+
+	#[inline]
+	fn error(&self, kind: ReadErrorKind) -> ReadError {
+		ReadError { position: self.pos, kind }
+	}
+}
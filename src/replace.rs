@@ -0,0 +1,114 @@
+// rawstring::replace
+
+use std::borrow::Cow;
+
+use crate::{RawStr, RawString, search::{CaselessFinder, Finder}};
+
+impl RawStr {
+	/// Replaces all non-overlapping occurrences of `from` with `to`, returning a new [`RawString`].
+	///
+	/// An empty `from` matches at every byte position, including the end.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// let raw = RawStr::new(b"a\xFFb\xFFc");
+	/// assert_eq!(raw.replace(b"\xFF", ", "), RawString::from("a, b, c"));
+	/// assert_eq!(raw.replace("x", "y"), RawString::from(b"a\xFFb\xFFc"));
+	/// ```
+	#[must_use]
+	pub fn replace<F, T>(&self, from: &F, to: &T) -> RawString
+	where
+		F: ?Sized + AsRef<[u8]>,
+		T: ?Sized + AsRef<[u8]>,
+	{
+		let mut buf = RawString::new();
+		self.replace_into(from, to, &mut buf);
+		buf
+	}
+
+	/// Replaces all non-overlapping occurrences of `from` with `to`,
+	/// returning the [`RawStr`] unchanged, without copying, if `from` does not occur.
+	///
+	/// See [`RawStr::replace`].
+	///
+	/// # Examples
+	/// ```
+	/// # use std::borrow::Cow;
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"a\xFFb");
+	/// assert_eq!(*raw.replace_cow(b"\xFF", "\u{FFFD}"), "a\u{FFFD}b");
+	/// assert!(matches!(raw.replace_cow("\r\n", "\n"), Cow::Borrowed(_)));
+	/// ```
+	#[must_use]
+	pub fn replace_cow<F, T>(&self, from: &F, to: &T) -> Cow<'_, RawStr>
+	where
+		F: ?Sized + AsRef<[u8]>,
+		T: ?Sized + AsRef<[u8]>,
+	{
+		match Finder::new(from).find(self) {
+			Some(_) => Cow::Owned(self.replace(from, to)),
+			None => Cow::Borrowed(self),
+		}
+	}
+
+	/// Replaces all non-overlapping occurrences of `from` with `to`,
+	/// writing the result into `buf` after clearing it.
+	///
+	/// This reuses the allocation of `buf`. See [`RawStr::replace`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// let mut buf = RawString::from("old contents");
+	/// RawStr::new("a-b-c").replace_into("-", "+", &mut buf);
+	/// assert_eq!(buf, RawString::from("a+b+c"));
+	/// ```
+	pub fn replace_into<F, T>(&self, from: &F, to: &T, buf: &mut RawString)
+	where
+		F: ?Sized + AsRef<[u8]>,
+		T: ?Sized + AsRef<[u8]>,
+	{
+		let (from, to) = (from.as_ref(), to.as_ref());
+		buf.clear();
+		buf.reserve(self.len());
+
+		let mut last = 0;
+		for start in Finder::new(from).find_iter(self) {
+			buf.extend_from_slice(&self[last..start]);
+			buf.extend_from_slice(to);
+			last = start + from.len();
+		}
+		buf.extend_from_slice(&self[last..]);
+	}
+
+	/// Replaces all non-overlapping occurrences of `from`, ignoring ASCII case, with `to`,
+	/// returning a new [`RawString`].
+	///
+	/// See [`CaselessFinder`] for how bytes are compared.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString};
+	/// let raw = RawStr::new(b"select a\xFF From t UNION select b from u");
+	/// let upper = raw.replace_ignore_ascii_case("from", "FROM");
+	/// assert_eq!(upper, RawString::from(b"select a\xFF FROM t UNION select b FROM u"));
+	/// ```
+	#[must_use]
+	pub fn replace_ignore_ascii_case<F, T>(&self, from: &F, to: &T) -> RawString
+	where
+		F: ?Sized + AsRef<[u8]>,
+		T: ?Sized + AsRef<[u8]>,
+	{
+		let (from, to) = (from.as_ref(), to.as_ref());
+		let mut buf = RawString::from(Vec::with_capacity(self.len()));
+		let mut last = 0;
+		for start in CaselessFinder::new(from).find_iter(self) {
+			buf.extend_from_slice(&self[last..start]);
+			buf.extend_from_slice(to);
+			last = start + from.len();
+		}
+		buf.extend_from_slice(&self[last..]);
+		buf
+	}
+}
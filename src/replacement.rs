@@ -0,0 +1,97 @@
+// rawstring::replacement
+
+use std::{borrow::Cow, fmt};
+
+use crate::{RawStr, RawString};
+
+impl RawStr {
+	/// Returns a wrapper that displays the [`RawStr`] with `replacement` in place of each
+	/// invalid sequence, instead of [`UNICODE_REPLACEMENT_CHARACTER`](crate::UNICODE_REPLACEMENT_CHARACTER).
+	///
+	/// This is for sinks that cannot show `�`, such as ASCII-only terminals and some log
+	/// collectors. Valid characters are written unchanged, and padding counts the characters
+	/// of `replacement`. An empty `replacement` drops invalid data. Unlike [`Display`](fmt::Display),
+	/// this never panics in `strict` mode, since the caller has chosen the replacement.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"caf\xC3\xA9 \xFF\xFE!");
+	/// assert_eq!(raw.display_replacing("?").to_string(), "caf\u{e9} ??!");
+	/// assert_eq!(format!("[{:>10}]", raw.display_replacing("<?>")), "[caf\u{e9} <?><?>!]");
+	/// assert_eq!(format!("[{:<8}]", raw.display_replacing("")), "[caf\u{e9} !  ]");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn display_replacing<'a>(&'a self, replacement: &'a str) -> DisplayReplacing<'a> {
+		DisplayReplacing { raw: self, replacement }
+	}
+
+	/// Lossily converts the [`RawStr`] to UTF-8 with `replacement` in place of each invalid sequence.
+	///
+	/// Returns the text borrowed if it is valid UTF-8. See [`RawStr::display_replacing`]
+	/// and [`RawStr::to_utf8_lossy`]. Unlike [`RawStr::to_utf8_lossy`], this never panics in
+	/// `strict` mode, since the caller has chosen the replacement.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"a\xFFb\xE2\x82").to_utf8_lossy_replacing("?"), "a?b?");
+	/// ```
+	#[must_use]
+	pub fn to_utf8_lossy_replacing(&self, replacement: &str) -> Cow<'_, str> {
+		let mut chunks = self.utf8_chunks();
+		let Some(first) = chunks.next() else {
+			return Cow::Borrowed("");
+		};
+		if first.invalid().is_empty() {
+			return Cow::Borrowed(first.valid());
+		}
+
+		let mut text = String::with_capacity(self.len());
+		for chunk in std::iter::once(first).chain(chunks) {
+			text.push_str(chunk.valid());
+			if !chunk.invalid().is_empty() {
+				text.push_str(replacement);
+			}
+		}
+		Cow::Owned(text)
+	}
+}
+
+impl RawString {
+	/// Returns a wrapper that displays the [`RawString`] with `replacement` in place of each
+	/// invalid sequence.
+	///
+	/// See [`RawStr::display_replacing`].
+	#[inline]
+	#[must_use]
+	pub fn display_replacing<'a>(&'a self, replacement: &'a str) -> DisplayReplacing<'a> {
+		self.as_ref().display_replacing(replacement)
+	}
+
+	/// Lossily converts the [`RawString`] to UTF-8 with `replacement` in place of each invalid sequence.
+	///
+	/// See [`RawStr::to_utf8_lossy_replacing`].
+	#[inline]
+	#[must_use]
+	pub fn to_utf8_lossy_replacing(&self, replacement: &str) -> Cow<'_, str> {
+		self.as_ref().to_utf8_lossy_replacing(replacement)
+	}
+}
+
+/// A wrapper that displays a [`RawStr`] with a custom replacement for invalid sequences.
+///
+/// Created by [`RawStr::display_replacing`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayReplacing<'a> {
+	raw: &'a RawStr,
+	replacement: &'a str,
+}
+
+impl fmt::Display for DisplayReplacing<'_> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.raw.fmt_lossy(f, self.replacement)
+	}
+}
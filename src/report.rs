@@ -0,0 +1,182 @@
+// rawstring::report
+
+use std::fmt;
+
+use crate::{RawStr, RawString, Utf8ErrorKind};
+
+/// The error kinds in the order they are counted and displayed.
+const KINDS: [Utf8ErrorKind; 5] = [
+	Utf8ErrorKind::InvalidStartByte,
+	Utf8ErrorKind::Overlong,
+	Utf8ErrorKind::Surrogate,
+	Utf8ErrorKind::OutOfRange,
+	Utf8ErrorKind::Truncated,
+];
+
+/// A summary of how much of a [`RawStr`] is valid UTF-8.
+///
+/// Created by [`RawStr::validity_report`]. The [`Display`](fmt::Display) impl writes a
+/// one-line summary suitable for logs, with counts pluralized.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{RawStr, Utf8ErrorKind};
+/// let report = RawStr::new(b"ok \xFF\xFE caf\xC3 \xED\xA0\x80").validity_report();
+/// assert_eq!(report.total_bytes(), 14);
+/// assert_eq!(report.valid_bytes(), 8);
+/// assert_eq!(report.invalid_runs(), 6);
+/// assert_eq!(report.first_error_offset(), Some(3));
+/// assert_eq!(report.count(Utf8ErrorKind::InvalidStartByte), 4);
+/// assert_eq!(
+///     report.to_string(),
+///     "14 bytes, 6 invalid sequences (6 bytes) starting at byte 3: 4 invalid start bytes, 1 encoded surrogate, 1 truncated sequence",
+/// );
+///
+/// let report = RawStr::new("\u{e9}").validity_report();
+/// assert!(report.is_valid());
+/// assert_eq!(report.to_string(), "2 bytes, all valid UTF-8");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ValidityReport {
+	total: usize,
+	invalid_bytes: usize,
+	runs: usize,
+	first_error: Option<usize>,
+	kinds: [usize; KINDS.len()],
+}
+
+impl ValidityReport {
+	/// Returns the total number of bytes.
+	#[inline]
+	#[must_use]
+	pub const fn total_bytes(&self) -> usize {
+		self.total
+	}
+
+	/// Returns the number of bytes that are part of valid characters.
+	#[inline]
+	#[must_use]
+	pub const fn valid_bytes(&self) -> usize {
+		self.total - self.invalid_bytes
+	}
+
+	/// Returns the number of bytes that are part of invalid sequences.
+	#[inline]
+	#[must_use]
+	pub const fn invalid_bytes(&self) -> usize {
+		self.invalid_bytes
+	}
+
+	/// Returns the number of invalid sequences, each of which is displayed as one `�`.
+	#[inline]
+	#[must_use]
+	pub const fn invalid_runs(&self) -> usize {
+		self.runs
+	}
+
+	/// Returns the byte offset of the first invalid sequence, or `None` if there is none.
+	#[inline]
+	#[must_use]
+	pub const fn first_error_offset(&self) -> Option<usize> {
+		self.first_error
+	}
+
+	/// Returns the number of invalid sequences of the given kind.
+	#[inline]
+	#[must_use]
+	pub fn count(&self, kind: Utf8ErrorKind) -> usize {
+		self.kinds[kind_index(kind)]
+	}
+
+	/// Returns `true` if all bytes are valid UTF-8.
+	#[inline]
+	#[must_use]
+	pub const fn is_valid(&self) -> bool {
+		self.runs == 0
+	}
+}
+
+/// Returns the position of `kind` in [`KINDS`].
+#[inline]
+fn kind_index(kind: Utf8ErrorKind) -> usize {
+	match kind {
+		Utf8ErrorKind::InvalidStartByte => 0,
+		Utf8ErrorKind::Overlong => 1,
+		Utf8ErrorKind::Surrogate => 2,
+		Utf8ErrorKind::OutOfRange => 3,
+		Utf8ErrorKind::Truncated => 4,
+	}
+}
+
+/// Returns the description of `kind` for a count of `n`.
+fn kind_description(kind: Utf8ErrorKind, n: usize) -> &'static str {
+	match (kind, n == 1) {
+		(Utf8ErrorKind::InvalidStartByte, true) => "invalid start byte",
+		(Utf8ErrorKind::InvalidStartByte, false) => "invalid start bytes",
+		(Utf8ErrorKind::Overlong, true) => "overlong encoding",
+		(Utf8ErrorKind::Overlong, false) => "overlong encodings",
+		(Utf8ErrorKind::Surrogate, true) => "encoded surrogate",
+		(Utf8ErrorKind::Surrogate, false) => "encoded surrogates",
+		(Utf8ErrorKind::OutOfRange, true) => "code point above U+10FFFF",
+		(Utf8ErrorKind::OutOfRange, false) => "code points above U+10FFFF",
+		(Utf8ErrorKind::Truncated, true) => "truncated sequence",
+		(Utf8ErrorKind::Truncated, false) => "truncated sequences",
+	}
+}
+
+/// Returns `"s"` unless `n` is one.
+#[inline]
+fn plural(n: usize) -> &'static str {
+	if n == 1 { "" } else { "s" }
+}
+
+impl fmt::Display for ValidityReport {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} byte{}", self.total, plural(self.total))?;
+		let Some(first_error) = self.first_error else {
+			return f.write_str(", all valid UTF-8");
+		};
+		write!(
+			f,
+			", {} invalid sequence{} ({} byte{}) starting at byte {first_error}:",
+			self.runs,
+			plural(self.runs),
+			self.invalid_bytes,
+			plural(self.invalid_bytes),
+		)?;
+		let counts = KINDS.iter().zip(self.kinds).filter(|&(_, n)| n > 0);
+		for (i, (&kind, n)) in counts.enumerate() {
+			let separator = if i == 0 { " " } else { ", " };
+			write!(f, "{separator}{n} {}", kind_description(kind, n))?;
+		}
+		Ok(())
+	}
+}
+
+impl RawStr {
+	/// Counts the valid and invalid data in the [`RawStr`] in a single pass.
+	///
+	/// See [`ValidityReport`].
+	#[must_use]
+	pub fn validity_report(&self) -> ValidityReport {
+		let mut report = ValidityReport { total: self.len(), ..ValidityReport::default() };
+		for (range, kind) in self.utf8_error_positions() {
+			report.first_error.get_or_insert(range.start);
+			report.invalid_bytes += range.len();
+			report.runs += 1;
+			report.kinds[kind_index(kind)] += 1;
+		}
+		report
+	}
+}
+
+impl RawString {
+	/// Counts the valid and invalid data in the [`RawString`] in a single pass.
+	///
+	/// See [`RawStr::validity_report`].
+	#[inline]
+	#[must_use]
+	pub fn validity_report(&self) -> ValidityReport {
+		self.as_ref().validity_report()
+	}
+}
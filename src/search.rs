@@ -0,0 +1,1148 @@
+// rawstring::search
+
+//! Low-level substring search over [`RawStr`].
+//!
+//! A [`Finder`] precomputes whatever tables its [`SearchStrategy`] needs once,
+//! and can then be reused to search any number of haystacks.
+//! [`SearchStrategy::Auto`] picks a reasonable algorithm based on the needle,
+//! but callers with unusual inputs (e.g. tiny alphabets with long needles)
+//! can force a specific algorithm. A [`CaselessFinder`] does the same while ignoring ASCII case.
+//!
+//! # Examples
+//! ```
+//! # use rawstring::RawStr;
+//! use rawstring::search::{Finder, SearchStrategy};
+//!
+//! let finder = Finder::with_strategy(b"GATTACA", SearchStrategy::Kmp);
+//! let dna = RawStr::new("GATGATTACAGATTACA");
+//! assert_eq!(finder.find(dna), Some(3));
+//! assert_eq!(finder.find_iter(dna).collect::<Vec<_>>(), [3, 10]);
+//! ```
+
+use std::{fmt, iter::FusedIterator};
+
+use crate::RawStr;
+
+/// The algorithm used by a [`Finder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SearchStrategy {
+	/// Picks an algorithm based on the needle:
+	/// [`Memchr`](Self::Memchr) for needles of up to 2 bytes,
+	/// [`TwoWay`](Self::TwoWay) otherwise.
+	#[default]
+	Auto,
+	/// Scans for the first byte of the needle and verifies the rest at each candidate.
+	/// Fast for short needles whose first byte is rare, but quadratic in the worst case.
+	Memchr,
+	/// The Crochemore–Perrin Two-Way algorithm.
+	/// Linear time with constant extra space; a good general-purpose default.
+	TwoWay,
+	/// Boyer–Moore–Horspool using a 256-entry bad-character shift table.
+	/// Fast for long needles over large alphabets, but degrades on small alphabets.
+	Horspool,
+	/// Knuth–Morris–Pratt using a failure table.
+	/// Linear time regardless of alphabet size, which suits tiny alphabets and long needles.
+	Kmp,
+}
+
+/// A precompiled substring searcher for a single needle.
+///
+/// See the [module documentation](self) for details.
+#[derive(Clone)]
+pub struct Finder<'n> {
+	needle: &'n [u8],
+	imp: Imp,
+}
+
+#[derive(Clone)]
+enum Imp {
+	Memchr,
+	TwoWay(TwoWay),
+	Horspool(Box<[usize; 256]>),
+	Kmp(Box<[usize]>),
+}
+
+impl<'n> Finder<'n> {
+	/// Creates a [`Finder`] for `needle` using [`SearchStrategy::Auto`].
+	#[inline]
+	#[must_use]
+	pub fn new<B>(needle: &'n B) -> Self
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		Self::with_strategy(needle, SearchStrategy::Auto)
+	}
+
+	/// Creates a [`Finder`] for `needle` using the given strategy.
+	#[must_use]
+	pub fn with_strategy<B>(needle: &'n B, strategy: SearchStrategy) -> Self
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let needle = needle.as_ref();
+		let strategy = match strategy {
+			SearchStrategy::Auto if needle.len() <= 2 => SearchStrategy::Memchr,
+			SearchStrategy::Auto => SearchStrategy::TwoWay,
+			strategy => strategy,
+		};
+		let imp = match strategy {
+			SearchStrategy::Auto | SearchStrategy::Memchr => Imp::Memchr,
+			SearchStrategy::TwoWay => Imp::TwoWay(TwoWay::new(needle)),
+			SearchStrategy::Horspool => Imp::Horspool(horspool_table(needle)),
+			SearchStrategy::Kmp => Imp::Kmp(kmp_table(needle)),
+		};
+		Self { needle, imp }
+	}
+
+	/// Returns the needle this [`Finder`] searches for.
+	#[inline]
+	#[must_use]
+	pub fn needle(&self) -> &'n RawStr {
+		RawStr::new(self.needle)
+	}
+
+	/// Returns the strategy in use. This is never [`SearchStrategy::Auto`].
+	#[inline]
+	#[must_use]
+	pub fn strategy(&self) -> SearchStrategy {
+		match self.imp {
+			Imp::Memchr => SearchStrategy::Memchr,
+			Imp::TwoWay(_) => SearchStrategy::TwoWay,
+			Imp::Horspool(_) => SearchStrategy::Horspool,
+			Imp::Kmp(_) => SearchStrategy::Kmp,
+		}
+	}
+
+	/// Returns the byte index of the first occurrence of the needle in `haystack`.
+	///
+	/// An empty needle matches at index 0.
+	#[inline]
+	#[must_use]
+	pub fn find(&self, haystack: &RawStr) -> Option<usize> {
+		self.find_from(haystack, 0)
+	}
+
+	/// Returns an iterator over the starting byte indices of
+	/// non-overlapping occurrences of the needle in `haystack`.
+	#[inline]
+	pub fn find_iter<'h>(&'h self, haystack: &'h RawStr) -> FindIter<'h, 'n> {
+		FindIter { finder: self, haystack, pos: 0 }
+	}
+
+	/// Returns the byte index of the last occurrence of the needle in `haystack`.
+	///
+	/// An empty needle matches at the end of `haystack`.
+	/// Reverse searches scan for the last byte of the needle regardless of the strategy.
+	#[inline]
+	#[must_use]
+	pub fn rfind(&self, haystack: &RawStr) -> Option<usize> {
+		self.rfind_to(haystack, haystack.len())
+	}
+
+	/// Returns an iterator over the starting byte indices of
+	/// non-overlapping occurrences of the needle in `haystack`, from last to first.
+	#[inline]
+	pub fn rfind_iter<'h>(&'h self, haystack: &'h RawStr) -> RFindIter<'h, 'n> {
+		RFindIter { finder: self, haystack, end: Some(haystack.len()) }
+	}
+
+	/// Returns the index of the first occurrence at or after `start`.
+	pub(crate) fn find_from(&self, haystack: &RawStr, start: usize) -> Option<usize> {
+		let haystack = haystack.get(start..)?;
+		if self.needle.is_empty() {
+			return Some(start);
+		}
+		if self.needle.len() > haystack.len() {
+			return None;
+		}
+		let found = match &self.imp {
+			Imp::Memchr => find_memchr(self.needle, haystack),
+			Imp::TwoWay(two_way) => two_way.find(self.needle, haystack),
+			Imp::Horspool(table) => find_horspool(self.needle, table, haystack),
+			Imp::Kmp(table) => find_kmp(self.needle, table, haystack),
+		};
+		found.map(|i| start + i)
+	}
+
+	/// Returns the index of the last occurrence that ends at or before `end`.
+	fn rfind_to(&self, haystack: &RawStr, end: usize) -> Option<usize> {
+		let haystack = haystack.get(..end)?;
+		rfind_memchr(self.needle, haystack)
+	}
+
+	/// Returns the largest number of non-overlapping matches in `len` bytes.
+	#[inline]
+	fn max_matches(&self, len: usize) -> usize {
+		match self.needle.len() {
+			0 => len + 1,
+			n => len / n,
+		}
+	}
+
+	/// Returns the end of the search range after a reverse match at `found`,
+	/// or `None` if an empty needle has reached the start.
+	#[inline]
+	fn rnext_end(&self, found: usize) -> Option<usize> {
+		if self.needle.is_empty() { found.checked_sub(1) } else { Some(found) }
+	}
+}
+
+impl fmt::Debug for Finder<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Finder")
+			.field("needle", &self.needle())
+			.field("strategy", &self.strategy())
+			.finish()
+	}
+}
+
+/// An iterator over the match positions of a [`Finder`].
+///
+/// Created by [`Finder::find_iter`].
+#[derive(Debug, Clone)]
+pub struct FindIter<'h, 'n> {
+	finder: &'h Finder<'n>,
+	haystack: &'h RawStr,
+	pos: usize,
+}
+
+impl Iterator for FindIter<'_, '_> {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<usize> {
+		let found = self.finder.find_from(self.haystack, self.pos)?;
+		// an empty needle advances one byte at a time
+		self.pos = found + self.finder.needle.len().max(1);
+		Some(found)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let max = self.haystack.len().checked_sub(self.pos).map_or(0, |len| self.finder.max_matches(len));
+		(0, Some(max))
+	}
+}
+
+impl FusedIterator for FindIter<'_, '_> {}
+
+/// An iterator over the match positions of a [`Finder`], from last to first.
+///
+/// Created by [`Finder::rfind_iter`].
+#[derive(Debug, Clone)]
+pub struct RFindIter<'h, 'n> {
+	finder: &'h Finder<'n>,
+	haystack: &'h RawStr,
+	end: Option<usize>,
+}
+
+impl Iterator for RFindIter<'_, '_> {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<usize> {
+		let found = self.finder.rfind_to(self.haystack, self.end?)?;
+		self.end = self.finder.rnext_end(found);
+		Some(found)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.end.map_or(0, |end| self.finder.max_matches(end))))
+	}
+}
+
+impl FusedIterator for RFindIter<'_, '_> {}
+
+/// A precompiled substring searcher that ignores ASCII case.
+///
+/// Bytes match if they are equal after [ASCII lowercasing](u8::to_ascii_lowercase);
+/// non-ASCII bytes must match exactly. The search uses Boyer–Moore–Horspool with a
+/// case-folded shift table, so the haystack is never copied or lowercased.
+///
+/// # Examples
+/// ```
+/// # use rawstring::RawStr;
+/// use rawstring::search::CaselessFinder;
+///
+/// let finder = CaselessFinder::new("content-length:");
+/// let headers = RawStr::new(b"Host: a\r\nContent-Length: 5\r\nX-Raw: \xFF\r\n");
+/// assert_eq!(finder.find(headers), Some(9));
+/// assert_eq!(finder.find_iter(RawStr::new("CONTENT-LENGTH: content-length:")).count(), 2);
+/// ```
+#[derive(Clone)]
+pub struct CaselessFinder<'n> {
+	needle: &'n [u8],
+	table: Box<[usize; 256]>,
+}
+
+impl<'n> CaselessFinder<'n> {
+	/// Creates a [`CaselessFinder`] for `needle`.
+	#[must_use]
+	pub fn new<B>(needle: &'n B) -> Self
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let needle = needle.as_ref();
+		let mut table = Box::new([needle.len(); 256]);
+		if let Some((_, init)) = needle.split_last() {
+			for (i, &b) in init.iter().enumerate() {
+				let shift = needle.len() - 1 - i;
+				table[usize::from(b.to_ascii_lowercase())] = shift;
+				table[usize::from(b.to_ascii_uppercase())] = shift;
+			}
+		}
+		Self { needle, table }
+	}
+
+	/// Returns the needle this [`CaselessFinder`] searches for.
+	#[inline]
+	#[must_use]
+	pub fn needle(&self) -> &'n RawStr {
+		RawStr::new(self.needle)
+	}
+
+	/// Returns the byte index of the first occurrence of the needle in `haystack`, ignoring ASCII case.
+	///
+	/// An empty needle matches at index 0.
+	#[inline]
+	#[must_use]
+	pub fn find(&self, haystack: &RawStr) -> Option<usize> {
+		self.find_from(haystack, 0)
+	}
+
+	/// Returns an iterator over the starting byte indices of
+	/// non-overlapping occurrences of the needle in `haystack`, ignoring ASCII case.
+	#[inline]
+	pub fn find_iter<'h>(&'h self, haystack: &'h RawStr) -> CaselessFindIter<'h, 'n> {
+		CaselessFindIter { finder: self, haystack, pos: 0 }
+	}
+
+	/// Returns the index of the first occurrence at or after `start`.
+	pub(crate) fn find_from(&self, haystack: &RawStr, start: usize) -> Option<usize> {
+		let haystack = haystack.get(start..)?;
+		let Some(last) = self.needle.len().checked_sub(1) else {
+			return Some(start);
+		};
+		let mut pos = 0;
+		while pos + self.needle.len() <= haystack.len() {
+			let window = &haystack[pos..pos + self.needle.len()];
+			if window[last].eq_ignore_ascii_case(&self.needle[last]) && window.eq_ignore_ascii_case(self.needle) {
+				return Some(start + pos);
+			}
+			pos += self.table[usize::from(window[last])];
+		}
+		None
+	}
+}
+
+impl fmt::Debug for CaselessFinder<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("CaselessFinder")
+			.field("needle", &self.needle())
+			.finish()
+	}
+}
+
+/// An iterator over the match positions of a [`CaselessFinder`].
+///
+/// Created by [`CaselessFinder::find_iter`].
+#[derive(Debug, Clone)]
+pub struct CaselessFindIter<'h, 'n> {
+	finder: &'h CaselessFinder<'n>,
+	haystack: &'h RawStr,
+	pos: usize,
+}
+
+impl Iterator for CaselessFindIter<'_, '_> {
+	type Item = usize;
+
+	fn next(&mut self) -> Option<usize> {
+		let found = self.finder.find_from(self.haystack, self.pos)?;
+		// an empty needle advances one byte at a time
+		self.pos = found + self.finder.needle.len().max(1);
+		Some(found)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.haystack.len().saturating_sub(self.pos);
+		let max = match self.finder.needle.len() {
+			0 if self.pos <= self.haystack.len() => len + 1,
+			0 => 0,
+			n => len / n,
+		};
+		(0, Some(max))
+	}
+}
+
+impl FusedIterator for CaselessFindIter<'_, '_> {}
+
+/// An iterator over the non-overlapping matches of a needle and their byte indices.
+///
+/// Created by [`RawStr::match_indices`].
+#[derive(Debug, Clone)]
+pub struct MatchIndices<'h, 'n> {
+	finder: Finder<'n>,
+	haystack: &'h RawStr,
+	pos: usize,
+}
+
+impl<'h> Iterator for MatchIndices<'h, '_> {
+	type Item = (usize, &'h RawStr);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let found = self.finder.find_from(self.haystack, self.pos)?;
+		let end = found + self.finder.needle.len();
+		self.pos = found + self.finder.needle.len().max(1);
+		Some((found, RawStr::new(&self.haystack[found..end])))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let max = self.haystack.len().checked_sub(self.pos).map_or(0, |len| self.finder.max_matches(len));
+		(0, Some(max))
+	}
+}
+
+impl FusedIterator for MatchIndices<'_, '_> {}
+
+/// An iterator over the non-overlapping matches of a needle and their byte indices,
+/// from last to first.
+///
+/// Created by [`RawStr::rmatch_indices`].
+#[derive(Debug, Clone)]
+pub struct RMatchIndices<'h, 'n> {
+	finder: Finder<'n>,
+	haystack: &'h RawStr,
+	end: Option<usize>,
+}
+
+impl<'h> Iterator for RMatchIndices<'h, '_> {
+	type Item = (usize, &'h RawStr);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let found = self.finder.rfind_to(self.haystack, self.end?)?;
+		self.end = self.finder.rnext_end(found);
+		Some((found, RawStr::new(&self.haystack[found..found + self.finder.needle.len()])))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.end.map_or(0, |end| self.finder.max_matches(end))))
+	}
+}
+
+impl FusedIterator for RMatchIndices<'_, '_> {}
+
+/// An iterator over the non-overlapping matches of a needle.
+///
+/// Created by [`RawStr::matches`].
+#[derive(Debug, Clone)]
+pub struct Matches<'h, 'n>(MatchIndices<'h, 'n>);
+
+impl<'h> Iterator for Matches<'h, '_> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<&'h RawStr> {
+		self.0.next().map(|(_, m)| m)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
+
+impl FusedIterator for Matches<'_, '_> {}
+
+/// An iterator over the non-overlapping matches of a needle, from last to first.
+///
+/// Created by [`RawStr::rmatches`].
+#[derive(Debug, Clone)]
+pub struct RMatches<'h, 'n>(RMatchIndices<'h, 'n>);
+
+impl<'h> Iterator for RMatches<'h, '_> {
+	type Item = &'h RawStr;
+
+	#[inline]
+	fn next(&mut self) -> Option<&'h RawStr> {
+		self.0.next().map(|(_, m)| m)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.0.size_hint()
+	}
+}
+
+impl FusedIterator for RMatches<'_, '_> {}
+
+/// An iterator over the parts of a [`RawStr`] separated by a needle.
+///
+/// Created by [`RawStr::split_str`].
+#[derive(Debug, Clone)]
+pub struct Split<'h, 'n> {
+	finder: Finder<'n>,
+	haystack: &'h RawStr,
+	start: usize,
+	search: usize,
+	finished: bool,
+}
+
+impl<'h> Split<'h, '_> {
+	/// Returns the rest of the [`RawStr`] without splitting it further.
+	fn remainder(&mut self) -> Option<&'h RawStr> {
+		if self.finished {
+			return None;
+		}
+		self.finished = true;
+		Some(RawStr::new(&self.haystack[self.start..]))
+	}
+}
+
+impl<'h> Iterator for Split<'h, '_> {
+	type Item = &'h RawStr;
+
+	fn next(&mut self) -> Option<&'h RawStr> {
+		if self.finished {
+			return None;
+		}
+		match self.finder.find_from(self.haystack, self.search) {
+			Some(found) => {
+				let part = RawStr::new(&self.haystack[self.start..found]);
+				self.start = found + self.finder.needle.len();
+				self.search = found + self.finder.needle.len().max(1);
+				Some(part)
+			}
+			None => self.remainder(),
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.finished {
+			return (0, Some(0));
+		}
+		let max = self.haystack.len().checked_sub(self.search).map_or(0, |len| self.finder.max_matches(len));
+		(1, Some(max + 1))
+	}
+}
+
+impl FusedIterator for Split<'_, '_> {}
+
+/// An iterator over the parts of a [`RawStr`] separated by a needle, from last to first.
+///
+/// Created by [`RawStr::rsplit_str`].
+#[derive(Debug, Clone)]
+pub struct RSplit<'h, 'n> {
+	finder: Finder<'n>,
+	haystack: &'h RawStr,
+	end: usize,
+	search: Option<usize>,
+	finished: bool,
+}
+
+impl<'h> RSplit<'h, '_> {
+	/// Returns the rest of the [`RawStr`] without splitting it further.
+	fn remainder(&mut self) -> Option<&'h RawStr> {
+		if self.finished {
+			return None;
+		}
+		self.finished = true;
+		Some(RawStr::new(&self.haystack[..self.end]))
+	}
+}
+
+impl<'h> Iterator for RSplit<'h, '_> {
+	type Item = &'h RawStr;
+
+	fn next(&mut self) -> Option<&'h RawStr> {
+		if self.finished {
+			return None;
+		}
+		match self.search.and_then(|search| self.finder.rfind_to(self.haystack, search)) {
+			Some(found) => {
+				let part = RawStr::new(&self.haystack[found + self.finder.needle.len()..self.end]);
+				self.end = found;
+				self.search = self.finder.rnext_end(found);
+				Some(part)
+			}
+			None => self.remainder(),
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.finished {
+			return (0, Some(0));
+		}
+		(1, Some(self.search.map_or(0, |end| self.finder.max_matches(end)) + 1))
+	}
+}
+
+impl FusedIterator for RSplit<'_, '_> {}
+
+/// An iterator over at most `n` parts of a [`RawStr`] separated by a needle.
+///
+/// Created by [`RawStr::splitn_str`].
+#[derive(Debug, Clone)]
+pub struct SplitN<'h, 'n> {
+	inner: Split<'h, 'n>,
+	count: usize,
+}
+
+impl<'h> Iterator for SplitN<'h, '_> {
+	type Item = &'h RawStr;
+
+	fn next(&mut self) -> Option<&'h RawStr> {
+		match self.count {
+			0 => None,
+			1 => {
+				self.count = 0;
+				self.inner.remainder()
+			}
+			_ => {
+				self.count -= 1;
+				self.inner.next()
+			}
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.count == 0 {
+			return (0, Some(0));
+		}
+		let (lower, upper) = self.inner.size_hint();
+		(lower.min(self.count), upper.map(|upper| upper.min(self.count)))
+	}
+}
+
+impl FusedIterator for SplitN<'_, '_> {}
+
+/// An iterator over at most `n` parts of a [`RawStr`] separated by a needle, from last to first.
+///
+/// Created by [`RawStr::rsplitn_str`].
+#[derive(Debug, Clone)]
+pub struct RSplitN<'h, 'n> {
+	inner: RSplit<'h, 'n>,
+	count: usize,
+}
+
+impl<'h> Iterator for RSplitN<'h, '_> {
+	type Item = &'h RawStr;
+
+	fn next(&mut self) -> Option<&'h RawStr> {
+		match self.count {
+			0 => None,
+			1 => {
+				self.count = 0;
+				self.inner.remainder()
+			}
+			_ => {
+				self.count -= 1;
+				self.inner.next()
+			}
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.count == 0 {
+			return (0, Some(0));
+		}
+		let (lower, upper) = self.inner.size_hint();
+		(lower.min(self.count), upper.map(|upper| upper.min(self.count)))
+	}
+}
+
+impl FusedIterator for RSplitN<'_, '_> {}
+
+/// Where the delimiter goes when splitting with [`RawStr::split_once_seq`]
+/// or [`RawStr::rsplit_once_seq`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DelimiterMode {
+	/// The delimiter is dropped, as with [`RawStr::split_once_str`].
+	#[default]
+	Discard,
+	/// The delimiter ends the left piece, e.g. to keep a line terminator with its line.
+	Left,
+	/// The delimiter starts the right piece, e.g. to keep a boundary marker with the part it introduces.
+	Right,
+}
+
+impl RawStr {
+	/// Returns the byte index of the first occurrence of `needle`,
+	/// or `None` if it does not occur.
+	///
+	/// To search for the same needle repeatedly, or to choose the search algorithm,
+	/// use a [`Finder`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"key\xFF=value");
+	/// assert_eq!(raw.find("=v"), Some(4));
+	/// assert_eq!(raw.find(b"\xFF"), Some(3));
+	/// assert_eq!(raw.find("nope"), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn find<B>(&self, needle: &B) -> Option<usize>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		Finder::new(needle).find(self)
+	}
+
+	/// Returns the byte index of the first occurrence of `needle`, ignoring ASCII case,
+	/// or `None` if it does not occur.
+	///
+	/// To search for the same needle repeatedly, use a [`CaselessFinder`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let sql = RawStr::new(b"select *\xFF From users");
+	/// assert_eq!(sql.find_ignore_ascii_case("FROM"), Some(10));
+	/// assert_eq!(sql.find_ignore_ascii_case("where"), None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn find_ignore_ascii_case<B>(&self, needle: &B) -> Option<usize>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		CaselessFinder::new(needle).find(self)
+	}
+
+	/// Returns the byte index of the last occurrence of `needle`,
+	/// or `None` if it does not occur.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new("archive.tar.gz");
+	/// assert_eq!(raw.rfind("."), Some(11));
+	/// assert_eq!(raw.rfind(""), Some(14));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn rfind<B>(&self, needle: &B) -> Option<usize>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		Finder::new(needle).rfind(self)
+	}
+
+	/// Returns an iterator over the non-overlapping matches of `needle` and their byte indices.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new("aaaa");
+	/// let found: Vec<_> = raw.match_indices("aa").map(|(i, _)| i).collect();
+	/// assert_eq!(found, [0, 2]);
+	/// assert_eq!(raw.match_indices("aa").size_hint(), (0, Some(2)));
+	/// assert_eq!(raw.matches("a").count(), 4);
+	/// ```
+	#[inline]
+	pub fn match_indices<'n, B>(&self, needle: &'n B) -> MatchIndices<'_, 'n>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		MatchIndices { finder: Finder::new(needle), haystack: self, pos: 0 }
+	}
+
+	/// Returns an iterator over the non-overlapping matches of `needle`, from last to first.
+	///
+	/// Matches are found from the end, so they can differ from [`RawStr::match_indices`]
+	/// when occurrences of `needle` overlap.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new("aaa");
+	/// assert_eq!(raw.match_indices("aa").next(), Some((0, RawStr::new("aa"))));
+	/// assert_eq!(raw.rmatch_indices("aa").next(), Some((1, RawStr::new("aa"))));
+	/// ```
+	#[inline]
+	pub fn rmatch_indices<'n, B>(&self, needle: &'n B) -> RMatchIndices<'_, 'n>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		RMatchIndices { finder: Finder::new(needle), haystack: self, end: Some(self.len()) }
+	}
+
+	/// Returns an iterator over the non-overlapping matches of `needle`.
+	///
+	/// See [`RawStr::match_indices`].
+	#[inline]
+	pub fn matches<'n, B>(&self, needle: &'n B) -> Matches<'_, 'n>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		Matches(self.match_indices(needle))
+	}
+
+	/// Returns an iterator over the non-overlapping matches of `needle`, from last to first.
+	///
+	/// See [`RawStr::rmatch_indices`].
+	#[inline]
+	pub fn rmatches<'n, B>(&self, needle: &'n B) -> RMatches<'_, 'n>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		RMatches(self.rmatch_indices(needle))
+	}
+
+	/// Returns an iterator over the parts of the [`RawStr`] separated by `needle`.
+	///
+	/// Unlike [`<[u8]>::split`], this splits on a substring rather than on bytes matching a predicate.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let parts: Vec<_> = RawStr::new("a::b::::c").split_str("::").collect();
+	/// assert_eq!(parts, ["a", "b", "", "c"]);
+	///
+	/// let parts: Vec<_> = RawStr::new("ab").split_str("").collect();
+	/// assert_eq!(parts, ["", "a", "b", ""]);
+	/// ```
+	#[inline]
+	pub fn split_str<'n, B>(&self, needle: &'n B) -> Split<'_, 'n>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		Split { finder: Finder::new(needle), haystack: self, start: 0, search: 0, finished: false }
+	}
+
+	/// Returns an iterator over the parts of the [`RawStr`] separated by `needle`, from last to first.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let parts: Vec<_> = RawStr::new("a::b::c").rsplit_str("::").collect();
+	/// assert_eq!(parts, ["c", "b", "a"]);
+	/// ```
+	#[inline]
+	pub fn rsplit_str<'n, B>(&self, needle: &'n B) -> RSplit<'_, 'n>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		RSplit { finder: Finder::new(needle), haystack: self, end: self.len(), search: Some(self.len()), finished: false }
+	}
+
+	/// Returns an iterator over at most `n` parts of the [`RawStr`] separated by `needle`.
+	/// The last part contains the rest of the [`RawStr`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let parts: Vec<_> = RawStr::new("GET /a b HTTP/1.1").splitn_str(2, " ").collect();
+	/// assert_eq!(parts, ["GET", "/a b HTTP/1.1"]);
+	/// ```
+	#[inline]
+	pub fn splitn_str<'n, B>(&self, n: usize, needle: &'n B) -> SplitN<'_, 'n>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		SplitN { inner: self.split_str(needle), count: n }
+	}
+
+	/// Returns an iterator over at most `n` parts of the [`RawStr`] separated by `needle`,
+	/// from last to first. The last part contains the rest of the [`RawStr`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let mut parts = RawStr::new("user-profile-1042").rsplitn_str(2, "-");
+	/// assert_eq!(parts.next(), Some(RawStr::new("1042")));
+	/// assert_eq!(parts.next(), Some(RawStr::new("user-profile")));
+	/// assert_eq!(parts.next(), None);
+	/// ```
+	#[inline]
+	pub fn rsplitn_str<'n, B>(&self, n: usize, needle: &'n B) -> RSplitN<'_, 'n>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		RSplitN { inner: self.rsplit_str(needle), count: n }
+	}
+
+	/// Splits the [`RawStr`] on the first occurrence of `needle`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new("key: value: more");
+	/// assert_eq!(raw.split_once_str(": "), Some((RawStr::new("key"), RawStr::new("value: more"))));
+	/// ```
+	#[must_use]
+	pub fn split_once_str<B>(&self, needle: &B) -> Option<(&RawStr, &RawStr)>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let needle = needle.as_ref();
+		let found = self.find(needle)?;
+		Some((RawStr::new(&self[..found]), RawStr::new(&self[found + needle.len()..])))
+	}
+
+	/// Splits the [`RawStr`] on the last occurrence of `needle`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let (stem, ext) = RawStr::new(b"photo.\xFF.jpeg").rsplit_once_str(".").unwrap();
+	/// assert_eq!((stem, ext), (RawStr::new(b"photo.\xFF"), RawStr::new("jpeg")));
+	/// ```
+	#[must_use]
+	pub fn rsplit_once_str<B>(&self, needle: &B) -> Option<(&RawStr, &RawStr)>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let needle = needle.as_ref();
+		let found = self.rfind(needle)?;
+		Some((RawStr::new(&self[..found]), RawStr::new(&self[found + needle.len()..])))
+	}
+
+	/// Splits the [`RawStr`] on the first occurrence of `needle`, keeping the delimiter
+	/// in the piece chosen by `mode`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// use rawstring::search::DelimiterMode;
+	///
+	/// let message = RawStr::new(b"Host: a\r\n\r\n\xFF\x00body");
+	/// let (header, body) = message.split_once_seq(b"\r\n\r\n", DelimiterMode::Discard).unwrap();
+	/// assert_eq!((header, body), (RawStr::new("Host: a"), RawStr::new(b"\xFF\x00body")));
+	///
+	/// let (header, _) = message.split_once_seq(b"\r\n\r\n", DelimiterMode::Left).unwrap();
+	/// assert_eq!(header, RawStr::new("Host: a\r\n\r\n"));
+	///
+	/// let (_, body) = message.split_once_seq(b"\r\n\r\n", DelimiterMode::Right).unwrap();
+	/// assert_eq!(body, RawStr::new(b"\r\n\r\n\xFF\x00body"));
+	/// ```
+	#[must_use]
+	pub fn split_once_seq<B>(&self, needle: &B, mode: DelimiterMode) -> Option<(&RawStr, &RawStr)>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let found = self.find(needle)?;
+		Some(self.split_at_delimiter(found, needle.as_ref().len(), mode))
+	}
+
+	/// Splits the [`RawStr`] on the last occurrence of `needle`, keeping the delimiter
+	/// in the piece chosen by `mode`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// use rawstring::search::DelimiterMode;
+	///
+	/// let path = RawStr::new(b"/srv/\xFFdata/file");
+	/// let (dir, _) = path.rsplit_once_seq("/", DelimiterMode::Left).unwrap();
+	/// assert_eq!(dir, RawStr::new(b"/srv/\xFFdata/"));
+	/// let (_, name) = path.rsplit_once_seq("/", DelimiterMode::Right).unwrap();
+	/// assert_eq!(name, RawStr::new("/file"));
+	/// ```
+	#[must_use]
+	pub fn rsplit_once_seq<B>(&self, needle: &B, mode: DelimiterMode) -> Option<(&RawStr, &RawStr)>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let found = self.rfind(needle)?;
+		Some(self.split_at_delimiter(found, needle.as_ref().len(), mode))
+	}
+
+	#[inline]
+	fn split_at_delimiter(&self, start: usize, len: usize, mode: DelimiterMode) -> (&RawStr, &RawStr) {
+		let (left_end, right_start) = match mode {
+			DelimiterMode::Discard => (start, start + len),
+			DelimiterMode::Left => (start + len, start + len),
+			DelimiterMode::Right => (start, start),
+		};
+		(RawStr::new(&self[..left_end]), RawStr::new(&self[right_start..]))
+	}
+}
+
+fn find_memchr(needle: &[u8], haystack: &[u8]) -> Option<usize> {
+	let (&first, rest) = needle.split_first()?;
+	let last_start = haystack.len() - needle.len();
+	let mut pos = 0;
+	while pos <= last_start {
+		pos += haystack[pos..=last_start].iter().position(|&b| b == first)?;
+		if &haystack[pos + 1..pos + needle.len()] == rest {
+			return Some(pos);
+		}
+		pos += 1;
+	}
+	None
+}
+
+fn rfind_memchr(needle: &[u8], haystack: &[u8]) -> Option<usize> {
+	let Some((&last, init)) = needle.split_last() else {
+		return Some(haystack.len());
+	};
+	let mut end = haystack.len();
+	while end >= needle.len() {
+		// index of a candidate for the last byte of the needle
+		let pos = init.len() + haystack[init.len()..end].iter().rposition(|&b| b == last)?;
+		let start = pos - init.len();
+		if &haystack[start..pos] == init {
+			return Some(start);
+		}
+		end = pos;
+	}
+	None
+}
+
+fn horspool_table(needle: &[u8]) -> Box<[usize; 256]> {
+	let mut table = Box::new([needle.len(); 256]);
+	if let Some((_, init)) = needle.split_last() {
+		for (i, &b) in init.iter().enumerate() {
+			table[usize::from(b)] = needle.len() - 1 - i;
+		}
+	}
+	table
+}
+
+fn find_horspool(needle: &[u8], table: &[usize; 256], haystack: &[u8]) -> Option<usize> {
+	let last = needle.len() - 1;
+	let mut pos = 0;
+	while pos + needle.len() <= haystack.len() {
+		let window = &haystack[pos..pos + needle.len()];
+		if window[last] == needle[last] && window == needle {
+			return Some(pos);
+		}
+		pos += table[usize::from(window[last])];
+	}
+	None
+}
+
+/// Builds the KMP failure table: `table[i]` is the length of the longest proper
+/// border of `needle[..=i]`.
+fn kmp_table(needle: &[u8]) -> Box<[usize]> {
+	let mut table = vec![0; needle.len()];
+	let mut k = 0;
+	for i in 1..needle.len() {
+		while k > 0 && needle[i] != needle[k] {
+			k = table[k - 1];
+		}
+		if needle[i] == needle[k] {
+			k += 1;
+		}
+		table[i] = k;
+	}
+	table.into_boxed_slice()
+}
+
+fn find_kmp(needle: &[u8], table: &[usize], haystack: &[u8]) -> Option<usize> {
+	let mut k = 0;
+	for (i, &b) in haystack.iter().enumerate() {
+		while k > 0 && b != needle[k] {
+			k = table[k - 1];
+		}
+		if b == needle[k] {
+			k += 1;
+		}
+		if k == needle.len() {
+			return Some(i + 1 - k);
+		}
+	}
+	None
+}
+
+/// Precomputed critical factorization for the Two-Way algorithm.
+#[derive(Clone)]
+struct TwoWay {
+	crit_pos: usize,
+	period: usize,
+	long_period: bool,
+}
+
+impl TwoWay {
+	fn new(needle: &[u8]) -> Self {
+		let (pos_lt, period_lt) = maximal_suffix(needle, false);
+		let (pos_gt, period_gt) = maximal_suffix(needle, true);
+		let (crit_pos, period) = if pos_lt > pos_gt {
+			(pos_lt, period_lt)
+		} else {
+			(pos_gt, period_gt)
+		};
+
+		if needle.get(period..period + crit_pos) == Some(&needle[..crit_pos]) {
+			// the needle is periodic; remember how much of the previous
+			// window is known to match to avoid rescanning it
+			Self { crit_pos, period, long_period: false }
+		} else {
+			let period = crit_pos.max(needle.len() - crit_pos) + 1;
+			Self { crit_pos, period, long_period: true }
+		}
+	}
+
+	fn find(&self, needle: &[u8], haystack: &[u8]) -> Option<usize> {
+		let n = needle.len();
+		let mut pos = 0;
+		let mut memory = 0;
+		'search: while pos + n <= haystack.len() {
+			// match the right half, left to right
+			let start = if self.long_period { self.crit_pos } else { self.crit_pos.max(memory) };
+			for i in start..n {
+				if needle[i] != haystack[pos + i] {
+					pos += i - self.crit_pos + 1;
+					memory = 0;
+					continue 'search;
+				}
+			}
+
+			// match the left half, right to left
+			let start = if self.long_period { 0 } else { memory };
+			for i in (start..self.crit_pos).rev() {
+				if needle[i] != haystack[pos + i] {
+					pos += self.period;
+					if !self.long_period {
+						memory = n - self.period;
+					}
+					continue 'search;
+				}
+			}
+
+			return Some(pos);
+		}
+		None
+	}
+}
+
+/// Computes the maximal suffix of `bytes` under the given byte ordering,
+/// returning its starting position and period.
+fn maximal_suffix(bytes: &[u8], order_greater: bool) -> (usize, usize) {
+	let mut left = 0;
+	let mut right = 1;
+	let mut offset = 0;
+	let mut period = 1;
+	while let Some(&a) = bytes.get(right + offset) {
+		let b = bytes[left + offset];
+		if (a < b && !order_greater) || (a > b && order_greater) {
+			// the suffix is smaller; the period is the whole prefix so far
+			right += offset + 1;
+			offset = 0;
+			period = right - left;
+		} else if a == b {
+			// advance through a repetition of the current period
+			if offset + 1 == period {
+				right += offset + 1;
+				offset = 0;
+			} else {
+				offset += 1;
+			}
+		} else {
+			// the suffix is larger; start over from the current position
+			left = right;
+			right += 1;
+			offset = 0;
+			period = 1;
+		}
+	}
+	(left, period)
+}
@@ -0,0 +1,88 @@
+// rawstring::secret
+
+use std::fmt;
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{RawStr, RawString};
+
+/// A [`RawString`] holding a secret, such as a binary key or a credential.
+///
+/// The contents are redacted from [`Debug`](fmt::Debug) and [`Display`](fmt::Display) output,
+/// compared in constant time, and overwritten with zeroes when the value is dropped.
+/// Requires the `zeroize` feature.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{RawStr, SecretRawString};
+/// let key = SecretRawString::new(b"\x00\x9Fkey".into());
+/// assert_eq!(format!("{key:?}"), "SecretRawString([REDACTED])");
+/// assert_eq!(key.to_string(), "[REDACTED]");
+/// assert_eq!(key.expose_secret(), RawStr::new(b"\x00\x9Fkey"));
+/// ```
+#[derive(Clone, Default)]
+pub struct SecretRawString(RawString);
+
+impl SecretRawString {
+	/// Wraps a [`RawString`] as a secret.
+	#[inline]
+	#[must_use]
+	pub fn new(secret: RawString) -> Self {
+		Self(secret)
+	}
+
+	/// Returns the secret.
+	///
+	/// Calls to this method mark the places where the secret is used.
+	#[inline]
+	#[must_use]
+	pub fn expose_secret(&self) -> &RawStr {
+		self.0.as_ref()
+	}
+
+	/// Returns the secret mutably.
+	#[inline]
+	#[must_use]
+	pub fn expose_secret_mut(&mut self) -> &mut RawStr {
+		self.0.as_mut()
+	}
+}
+
+impl Drop for SecretRawString {
+	#[inline]
+	fn drop(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+impl ZeroizeOnDrop for SecretRawString {}
+
+impl PartialEq for SecretRawString {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.0.ct_eq(other.expose_secret())
+	}
+}
+
+impl Eq for SecretRawString {}
+
+impl fmt::Debug for SecretRawString {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("SecretRawString([REDACTED])")
+	}
+}
+
+impl fmt::Display for SecretRawString {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("[REDACTED]")
+	}
+}
+
+impl From<RawString> for SecretRawString {
+	#[inline]
+	fn from(secret: RawString) -> Self {
+		Self::new(secret)
+	}
+}
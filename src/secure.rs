@@ -0,0 +1,114 @@
+// rawstring::secure
+
+use std::hint::black_box;
+
+use crate::{RawStr, RawString};
+
+impl RawStr {
+	/// Compares two [`RawStr`]s in time that depends only on their lengths, not their contents.
+	///
+	/// Use this instead of `==` when comparing secrets such as tokens or MACs,
+	/// where an early exit would leak how many leading bytes matched.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let token = RawStr::new(b"\x8Fs3cr3t");
+	/// assert!(token.ct_eq(RawStr::new(b"\x8Fs3cr3t")));
+	/// assert!(!token.ct_eq(RawStr::new(b"\x8Fs3cr3T")));
+	/// assert!(!token.ct_eq(RawStr::new(b"\x8F")));
+	/// ```
+	#[must_use]
+	pub fn ct_eq(&self, other: &RawStr) -> bool {
+		if self.len() != other.len() {
+			return false;
+		}
+		let diff = self.iter()
+			.zip(other.iter())
+			.fold(0u8, |acc, (a, b)| black_box(acc | (a ^ b)));
+		diff == 0
+	}
+
+	/// XORs every byte with the corresponding byte of `key`, repeating `key` as needed.
+	///
+	/// Applying the same key twice restores the original data.
+	///
+	/// # Panics
+	/// Panics if `key` is empty and the [`RawStr`] is not.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let mut data = *b"hello";
+	/// RawStr::new_mut(&mut data).xor_in_place(b"\x01\x02");
+	/// assert_eq!(&data, b"igmnn");
+	/// RawStr::new_mut(&mut data).xor_in_place(b"\x01\x02");
+	/// assert_eq!(&data, b"hello");
+	/// ```
+	pub fn xor_in_place<B>(&mut self, key: &B)
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let key = key.as_ref();
+		if self.is_empty() {
+			return;
+		}
+		assert!(!key.is_empty(), "empty XOR key");
+		for (byte, k) in self.iter_mut().zip(key.iter().cycle()) {
+			*byte ^= k;
+		}
+	}
+}
+
+impl RawString {
+	/// Compares two byte strings in time that depends only on their lengths, not their contents.
+	///
+	/// See [`RawStr::ct_eq`].
+	#[inline]
+	#[must_use]
+	pub fn ct_eq(&self, other: &RawStr) -> bool {
+		self.as_ref().ct_eq(other)
+	}
+
+	/// XORs every byte with the corresponding byte of `key`, repeating `key` as needed.
+	///
+	/// See [`RawStr::xor_in_place`].
+	///
+	/// # Panics
+	/// Panics if `key` is empty and the [`RawString`] is not.
+	#[inline]
+	pub fn xor_in_place<B>(&mut self, key: &B)
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		self.as_mut().xor_in_place(key);
+	}
+}
+
+/// Overwrites the bytes with zeroes in a way the compiler will not optimize away.
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for RawStr {
+	#[inline]
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+/// Overwrites the whole allocation with zeroes and clears the [`RawString`].
+///
+/// # Examples
+/// ```
+/// # use rawstring::RawString;
+/// use zeroize::Zeroize;
+///
+/// let mut token = RawString::from("hunter2");
+/// token.zeroize();
+/// assert!(token.is_empty());
+/// ```
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for RawString {
+	#[inline]
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
@@ -0,0 +1,185 @@
+// rawstring::segment
+
+use std::{fmt, iter::FusedIterator, ops::{Deref, Range}, str::{Utf8Chunks, Utf8Error}};
+
+use crate::RawStr;
+
+/// A region of a [`RawStr`] known to be valid UTF-8, together with its position.
+///
+/// A segment is validated once, when it is created, and then gives out its text as a [`str`]
+/// without checking again. This suits parsers that find text regions in one pass and
+/// interpret them in another. [`Utf8Segment`] derefs to [`str`].
+///
+/// Created by [`RawStr::utf8_segment`], [`RawStr::assume_utf8`] and [`RawStr::validated_segments`].
+///
+/// # Examples
+/// ```
+/// # use rawstring::RawStr;
+/// let raw = RawStr::new(b"name=caf\xC3\xA9;\xFF");
+/// let value = raw.utf8_segment(5..10).unwrap();
+/// assert_eq!(value.as_str(), "caf\u{e9}");
+/// assert_eq!(value.range(), 5..10);
+/// assert_eq!(value.chars().count(), 4);
+///
+/// assert!(raw.utf8_segment(9..12).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Utf8Segment<'a> {
+	text: &'a str,
+	offset: usize,
+}
+
+impl<'a> Utf8Segment<'a> {
+	/// Returns the text of the segment.
+	#[inline]
+	#[must_use]
+	pub const fn as_str(&self) -> &'a str {
+		self.text
+	}
+
+	/// Returns the text of the segment as a [`RawStr`].
+	#[inline]
+	#[must_use]
+	pub const fn as_raw_str(&self) -> &'a RawStr {
+		RawStr::from_bytes(self.text.as_bytes())
+	}
+
+	/// Returns the byte offset of the segment in the [`RawStr`] it was taken from.
+	#[inline]
+	#[must_use]
+	pub const fn offset(&self) -> usize {
+		self.offset
+	}
+
+	/// Returns the byte range of the segment in the [`RawStr`] it was taken from.
+	#[inline]
+	#[must_use]
+	pub const fn range(&self) -> Range<usize> {
+		self.offset..self.offset + self.text.len()
+	}
+}
+
+impl Deref for Utf8Segment<'_> {
+	type Target = str;
+
+	#[inline]
+	fn deref(&self) -> &str {
+		self.text
+	}
+}
+
+impl AsRef<str> for Utf8Segment<'_> {
+	#[inline]
+	fn as_ref(&self) -> &str {
+		self.text
+	}
+}
+
+impl AsRef<[u8]> for Utf8Segment<'_> {
+	#[inline]
+	fn as_ref(&self) -> &[u8] {
+		self.text.as_bytes()
+	}
+}
+
+impl fmt::Display for Utf8Segment<'_> {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(self.text, f)
+	}
+}
+
+impl RawStr {
+	/// Validates the bytes in `range` as UTF-8 and returns them as a [`Utf8Segment`].
+	///
+	/// # Errors
+	/// Returns a [`Utf8Error`] if the bytes in `range` are not valid UTF-8.
+	/// Its positions are relative to the start of `range`.
+	///
+	/// # Panics
+	/// Panics if `range` is out of bounds.
+	#[inline]
+	pub fn utf8_segment(&self, range: Range<usize>) -> Result<Utf8Segment<'_>, Utf8Error> {
+		let text = str::from_utf8(&self.0[range.clone()])?;
+		Ok(Utf8Segment { text, offset: range.start })
+	}
+
+	/// Returns the bytes in `range` as a [`Utf8Segment`] without checking that they are valid UTF-8.
+	///
+	/// # Safety
+	/// The bytes in `range` must be valid UTF-8, e.g. because they were validated in an earlier pass.
+	/// See [`str::from_utf8_unchecked`].
+	///
+	/// # Panics
+	/// Panics if `range` is out of bounds.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"\xFF key \xFE");
+	/// // first pass: find the text between the invalid bytes
+	/// let range = 1..raw.len() - 1;
+	/// assert!(raw.utf8_segment(range.clone()).is_ok());
+	/// // second pass: no need to validate again
+	/// // SAFETY: the range was validated above
+	/// let key = unsafe { raw.assume_utf8(range) };
+	/// assert_eq!(key.trim(), "key");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub unsafe fn assume_utf8(&self, range: Range<usize>) -> Utf8Segment<'_> {
+		// SAFETY: safety contract is upheld by the caller
+		let text = unsafe { str::from_utf8_unchecked(&self.0[range.clone()]) };
+		Utf8Segment { text, offset: range.start }
+	}
+
+	/// Returns an iterator over the maximal valid UTF-8 regions of the [`RawStr`], skipping invalid bytes.
+	///
+	/// Each region is validated once, so the segments can be used as [`str`]s without checking again.
+	/// Empty regions between adjacent invalid sequences are skipped.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"GET \xFF\xFE/caf\xC3\xA9 \xC0");
+	/// let segments: Vec<_> = raw.validated_segments().map(|s| (s.offset(), s.as_str())).collect();
+	/// assert_eq!(segments, [(0, "GET "), (6, "/caf\u{e9} ")]);
+	/// ```
+	#[inline]
+	pub fn validated_segments(&self) -> ValidatedSegments<'_> {
+		ValidatedSegments { chunks: self.0.utf8_chunks(), offset: 0, len: self.len() }
+	}
+}
+
+/// An iterator over the valid UTF-8 regions of a [`RawStr`].
+///
+/// Created by [`RawStr::validated_segments`].
+#[derive(Debug, Clone)]
+pub struct ValidatedSegments<'a> {
+	chunks: Utf8Chunks<'a>,
+	offset: usize,
+	len: usize,
+}
+
+impl<'a> Iterator for ValidatedSegments<'a> {
+	type Item = Utf8Segment<'a>;
+
+	fn next(&mut self) -> Option<Utf8Segment<'a>> {
+		loop {
+			let chunk = self.chunks.next()?;
+			let offset = self.offset;
+			self.offset += chunk.valid().len() + chunk.invalid().len();
+			if !chunk.valid().is_empty() {
+				return Some(Utf8Segment { text: chunk.valid(), offset });
+			}
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		// every segment but the last is followed by at least one invalid byte
+		(0, Some((self.len - self.offset).div_ceil(2)))
+	}
+}
+
+impl FusedIterator for ValidatedSegments<'_> {}
@@ -0,0 +1,154 @@
+// rawstring::shell
+
+use std::{borrow::Cow, error::Error, fmt};
+
+use crate::{RawStr, RawString};
+
+/// An error returned by [`RawStr::shell_split`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShellSplitError {
+	/// A quote starting at the given byte offset was never closed.
+	UnterminatedQuote {
+		/// The byte offset of the opening quote.
+		offset: usize,
+	},
+	/// The input ended with an unescaped backslash.
+	TrailingBackslash,
+}
+
+impl fmt::Display for ShellSplitError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::UnterminatedQuote { offset } => write!(f, "unterminated quote at byte {offset}"),
+			Self::TrailingBackslash => write!(f, "trailing backslash"),
+		}
+	}
+}
+
+impl Error for ShellSplitError {}
+
+/// Returns `true` if `byte` never needs quoting in a POSIX shell word.
+const fn is_shell_safe(byte: u8) -> bool {
+	matches!(byte,
+		b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9'
+		| b'_' | b'-' | b'.' | b'/' | b':' | b',' | b'+' | b'=' | b'@' | b'%'
+	)
+}
+
+impl RawStr {
+	/// Quotes the [`RawStr`] so that a POSIX shell reads it back as a single word
+	/// with exactly the same bytes.
+	///
+	/// Words consisting only of safe characters are returned unchanged;
+	/// anything else is wrapped in single quotes, with embedded single quotes written as `'\''`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(*RawStr::new("file.txt").shell_quote(), "file.txt");
+	/// assert_eq!(*RawStr::new("it's here").shell_quote(), r"'it'\''s here'");
+	/// assert_eq!(*RawStr::new(b"\xFF").shell_quote(), b"'\xFF'");
+	/// assert_eq!(*RawStr::new("").shell_quote(), "''");
+	/// ```
+	#[must_use]
+	pub fn shell_quote(&self) -> Cow<'_, RawStr> {
+		if !self.is_empty() && self.iter().all(|&b| is_shell_safe(b)) {
+			return Cow::Borrowed(self);
+		}
+
+		let mut quoted = RawString::from(Vec::with_capacity(self.len() + 2));
+		quoted.push(b'\'');
+		for &b in self.iter() {
+			if b == b'\'' {
+				quoted.extend_from_slice(br"'\''");
+			} else {
+				quoted.push(b);
+			}
+		}
+		quoted.push(b'\'');
+		Cow::Owned(quoted)
+	}
+
+	/// Splits the [`RawStr`] into words the way a POSIX shell would, without performing expansions.
+	///
+	/// Words are separated by unquoted spaces, tabs and newlines.
+	/// Single quotes preserve everything up to the closing quote;
+	/// inside double quotes a backslash only escapes `$`, `` ` ``, `"`, `\` and newline;
+	/// elsewhere a backslash escapes any byte, and a backslash-newline pair is removed.
+	/// An unquoted `#` at the start of a word begins a comment running to the end of the line.
+	///
+	/// # Errors
+	/// Returns a [`ShellSplitError`] if a quote is not closed or the input ends with a backslash.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, RawString, ShellSplitError};
+	/// let words = RawStr::new(b"cp 'a b' \"c\\\"d\" e\\ f \xFF # copy").shell_split().unwrap();
+	/// assert_eq!(words, [
+	///     RawString::from("cp"),
+	///     RawString::from("a b"),
+	///     RawString::from("c\"d"),
+	///     RawString::from("e f"),
+	///     RawString::from(b"\xFF"),
+	/// ]);
+	///
+	/// assert_eq!(
+	///     RawStr::new("echo 'oops").shell_split(),
+	///     Err(ShellSplitError::UnterminatedQuote { offset: 5 }),
+	/// );
+	/// ```
+	pub fn shell_split(&self) -> Result<Vec<RawString>, ShellSplitError> {
+		let bytes = &self.0;
+		let mut words = Vec::new();
+		let mut word: Option<RawString> = None;
+		let mut i = 0;
+
+		while let Some(&b) = bytes.get(i) {
+			match b {
+				b' ' | b'\t' | b'\n' => words.extend(word.take()),
+				b'#' if word.is_none() => {
+					let end = bytes[i..].iter().position(|&b| b == b'\n');
+					i = end.map_or(bytes.len(), |end| i + end);
+					continue;
+				}
+				b'\\' => match bytes.get(i + 1) {
+					None => return Err(ShellSplitError::TrailingBackslash),
+					Some(b'\n') => i += 1,
+					Some(&escaped) => {
+						word.get_or_insert_default().push(escaped);
+						i += 1;
+					}
+				},
+				b'\'' => {
+					let len = bytes[i + 1..].iter()
+						.position(|&b| b == b'\'')
+						.ok_or(ShellSplitError::UnterminatedQuote { offset: i })?;
+					word.get_or_insert_default().extend_from_slice(&bytes[i + 1..i + 1 + len]);
+					i += len + 1;
+				}
+				b'"' => {
+					let start = i;
+					let word = word.get_or_insert_default();
+					loop {
+						i += 1;
+						match bytes.get(i) {
+							None => return Err(ShellSplitError::UnterminatedQuote { offset: start }),
+							Some(b'"') => break,
+							Some(b'\\') if matches!(bytes.get(i + 1), Some(b'$' | b'`' | b'"' | b'\\')) => {
+								word.push(bytes[i + 1]);
+								i += 1;
+							}
+							Some(b'\\') if bytes.get(i + 1) == Some(&b'\n') => i += 1,
+							Some(&b) => word.push(b),
+						}
+					}
+				}
+				_ => word.get_or_insert_default().push(b),
+			}
+			i += 1;
+		}
+
+		words.extend(word);
+		Ok(words)
+	}
+}
@@ -0,0 +1,113 @@
+// rawstring::slice
+
+use std::{
+	error::Error,
+	fmt,
+	ops::{Bound, RangeBounds},
+};
+
+use crate::{ErrorOffset, RawStr, RawString};
+
+/// An error returned by [`RawStr::slice_checked`] when a range is out of bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SliceError {
+	start: usize,
+	end: usize,
+	len: usize,
+}
+
+impl SliceError {
+	#[inline]
+	pub(crate) const fn new(start: usize, end: usize, len: usize) -> Self {
+		Self { start, end, len }
+	}
+
+	/// Returns the start of the requested range.
+	#[inline]
+	#[must_use]
+	pub const fn start(&self) -> usize {
+		self.start
+	}
+
+	/// Returns the exclusive end of the requested range.
+	///
+	/// An inclusive end of `usize::MAX` is reported as `usize::MAX`.
+	#[inline]
+	#[must_use]
+	pub const fn end(&self) -> usize {
+		self.end
+	}
+
+	/// Returns the length of the [`RawStr`] that was sliced.
+	#[inline]
+	#[must_use]
+	pub const fn actual_len(&self) -> usize {
+		self.len
+	}
+}
+
+impl fmt::Display for SliceError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.start > self.end {
+			write!(f, "range start {} is greater than end {}", self.start, self.end)
+		} else {
+			write!(f, "range {}..{} out of bounds for length {}", self.start, self.end, self.len)
+		}
+	}
+}
+
+impl Error for SliceError {}
+
+impl ErrorOffset for SliceError {
+	#[inline]
+	fn error_offset(&self) -> Option<usize> {
+		Some(self.start.min(self.len))
+	}
+}
+
+impl RawStr {
+	/// Returns the bytes in `range`, or an error describing the range and length
+	/// if it is out of bounds.
+	///
+	/// This is the fallible counterpart of indexing, for parsers that propagate errors
+	/// instead of panicking.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let raw = RawStr::new(b"head\xFFtail");
+	/// assert_eq!(raw.slice_checked(..4), Ok(RawStr::new("head")));
+	/// assert_eq!(raw.slice_checked(5..), Ok(RawStr::new("tail")));
+	///
+	/// let err = raw.slice_checked(5..=9).unwrap_err();
+	/// assert_eq!((err.start(), err.end(), err.actual_len()), (5, 10, 9));
+	/// assert_eq!(err.to_string(), "range 5..10 out of bounds for length 9");
+	/// assert_eq!(raw.slice_checked(4..2).unwrap_err().to_string(), "range start 4 is greater than end 2");
+	/// ```
+	pub fn slice_checked<R: RangeBounds<usize>>(&self, range: R) -> Result<&RawStr, SliceError> {
+		let start = match range.start_bound() {
+			Bound::Included(&start) => start,
+			Bound::Excluded(&start) => start.saturating_add(1),
+			Bound::Unbounded => 0,
+		};
+		let end = match range.end_bound() {
+			Bound::Included(&end) => end.checked_add(1),
+			Bound::Excluded(&end) => Some(end),
+			Bound::Unbounded => Some(self.len()),
+		};
+		end.and_then(|end| self.get(start..end))
+			.map(RawStr::new)
+			.ok_or(SliceError::new(start, end.unwrap_or(usize::MAX), self.len()))
+	}
+}
+
+impl RawString {
+	/// Returns the bytes in `range`, or an error describing the range and length
+	/// if it is out of bounds.
+	///
+	/// See [`RawStr::slice_checked`].
+	#[inline]
+	pub fn slice_checked<R: RangeBounds<usize>>(&self, range: R) -> Result<&RawStr, SliceError> {
+		self.as_ref().slice_checked(range)
+	}
+}
@@ -0,0 +1,293 @@
+// rawstring::sort
+
+use std::{
+	cmp::Ordering,
+	hash::{Hash, Hasher},
+};
+
+use crate::{RawStr, RawString};
+
+/// An order for sorting raw strings, used by [`RawSliceExt`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RawOrder {
+	/// Byte-wise lexicographic order, the same as [`Ord`] for [`RawStr`].
+	#[default]
+	Bytes,
+	/// Natural order, comparing runs of ASCII digits by their numeric value.
+	/// See [`RawStr::cmp_natural`].
+	Natural,
+	/// Lexicographic order ignoring ASCII case.
+	/// See [`RawStr::cmp_ignore_ascii_case`].
+	IgnoreAsciiCase,
+}
+
+impl RawOrder {
+	/// Compares `a` and `b` in this order.
+	#[inline]
+	#[must_use]
+	pub fn compare(self, a: &RawStr, b: &RawStr) -> Ordering {
+		match self {
+			Self::Bytes => a.cmp(b),
+			Self::Natural => a.cmp_natural(b),
+			Self::IgnoreAsciiCase => a.cmp_ignore_ascii_case(b),
+		}
+	}
+}
+
+impl RawStr {
+	/// Compares the [`RawStr`] with `other` in natural order, so that `file2` sorts before `file10`.
+	///
+	/// Runs of ASCII digits are compared by their numeric value, of any length, and all other
+	/// bytes are compared by value. Strings that are equal this way, such as `a01` and `a1`,
+	/// are ordered by their bytes, so the order is consistent with [`Eq`].
+	///
+	/// # Examples
+	/// ```
+	/// # use std::cmp::Ordering;
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("file2.txt").cmp_natural(RawStr::new("file10.txt")), Ordering::Less);
+	/// assert_eq!(RawStr::new(b"v1.10\xFF").cmp_natural(RawStr::new(b"v1.9\xFF")), Ordering::Greater);
+	/// assert_eq!(RawStr::new("a01").cmp_natural(RawStr::new("a1")), Ordering::Less);
+	/// ```
+	#[must_use]
+	pub fn cmp_natural(&self, other: &RawStr) -> Ordering {
+		let (a, b) = (&self.0, &other.0);
+		let (mut i, mut j) = (0, 0);
+		while i < a.len() && j < b.len() {
+			if a[i].is_ascii_digit() && b[j].is_ascii_digit() {
+				let end_a = digits_end(a, i);
+				let end_b = digits_end(b, j);
+				let num_a = trim_zeros(&a[i..end_a]);
+				let num_b = trim_zeros(&b[j..end_b]);
+				let ordering = num_a.len().cmp(&num_b.len()).then_with(|| num_a.cmp(num_b));
+				if ordering.is_ne() {
+					return ordering;
+				}
+				(i, j) = (end_a, end_b);
+			} else {
+				let ordering = a[i].cmp(&b[j]);
+				if ordering.is_ne() {
+					return ordering;
+				}
+				(i, j) = (i + 1, j + 1);
+			}
+		}
+		(a.len() - i).cmp(&(b.len() - j)).then_with(|| a.cmp(b))
+	}
+
+	/// Compares the [`RawStr`] with `other` lexicographically, ignoring ASCII case.
+	///
+	/// Strings that differ only in case are ordered by their bytes,
+	/// so the order is consistent with [`Eq`].
+	///
+	/// # Examples
+	/// ```
+	/// # use std::cmp::Ordering;
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("apple").cmp_ignore_ascii_case(RawStr::new("Banana")), Ordering::Less);
+	/// assert_eq!(RawStr::new("B").cmp_ignore_ascii_case(RawStr::new("b")), Ordering::Less);
+	/// ```
+	#[must_use]
+	pub fn cmp_ignore_ascii_case(&self, other: &RawStr) -> Ordering {
+		cmp_lowercase(self, other).then_with(|| self.cmp(other))
+	}
+}
+
+/// Compares `a` and `b` with ASCII letters converted to lowercase.
+#[inline]
+fn cmp_lowercase(a: &RawStr, b: &RawStr) -> Ordering {
+	a.iter().map(u8::to_ascii_lowercase).cmp(b.iter().map(u8::to_ascii_lowercase))
+}
+
+/// Returns the end of the run of ASCII digits starting at `start`.
+#[inline]
+fn digits_end(bytes: &[u8], start: usize) -> usize {
+	bytes[start..].iter().position(|b| !b.is_ascii_digit()).map_or(bytes.len(), |n| start + n)
+}
+
+/// Removes leading zeros from a run of digits.
+#[inline]
+fn trim_zeros(digits: &[u8]) -> &[u8] {
+	let zeros = digits.iter().take_while(|&&b| b == b'0').count();
+	&digits[zeros..]
+}
+
+/// Sorting helpers for slices of raw strings.
+///
+/// This is implemented for slices of anything that can be viewed as a [`RawStr`],
+/// such as `[RawString]` and `[&RawStr]`.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{RawOrder, RawSliceExt, RawString};
+/// let mut names: Vec<RawString> = ["img10.png", "IMG2.png", "img1.png"].map(RawString::from).into();
+///
+/// names.sort_unstable_by_raw(RawOrder::Natural);
+/// assert_eq!(names, ["IMG2.png", "img1.png", "img10.png"].map(RawString::from));
+///
+/// names.sort_unstable_by_raw(RawOrder::IgnoreAsciiCase);
+/// assert_eq!(names, ["img1.png", "img10.png", "IMG2.png"].map(RawString::from));
+/// assert!(names.is_sorted_by_raw(RawOrder::IgnoreAsciiCase));
+/// assert!(!names.is_sorted_by_raw(RawOrder::Bytes));
+/// ```
+pub trait RawSliceExt {
+	/// Sorts the slice in `order`, without preserving the order of equal elements.
+	fn sort_unstable_by_raw(&mut self, order: RawOrder);
+
+	/// Sorts the slice in `order`, preserving the order of equal elements.
+	fn sort_by_raw(&mut self, order: RawOrder);
+
+	/// Returns `true` if the slice is sorted in `order`.
+	fn is_sorted_by_raw(&self, order: RawOrder) -> bool;
+}
+
+impl<T: AsRef<RawStr>> RawSliceExt for [T] {
+	#[inline]
+	fn sort_unstable_by_raw(&mut self, order: RawOrder) {
+		self.sort_unstable_by(|a, b| order.compare(a.as_ref(), b.as_ref()));
+	}
+
+	#[inline]
+	fn sort_by_raw(&mut self, order: RawOrder) {
+		self.sort_by(|a, b| order.compare(a.as_ref(), b.as_ref()));
+	}
+
+	#[inline]
+	fn is_sorted_by_raw(&self, order: RawOrder) -> bool {
+		self.is_sorted_by(|a, b| order.compare(a.as_ref(), b.as_ref()).is_le())
+	}
+}
+
+/// Deduplication helpers for vectors of [`RawString`]s.
+pub trait RawVecExt {
+	/// Removes every element whose content equals an earlier element,
+	/// keeping the first occurrence and the order of the remaining elements.
+	///
+	/// Unlike [`Vec::dedup`], duplicates do not need to be adjacent. No element is cloned or
+	/// hashed; the only allocation is a temporary index of one `usize` per element.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawString, RawVecExt};
+	/// let mut lines: Vec<RawString> = [&b"b"[..], b"\xFF", b"a", b"b", b"\xFF"].map(RawString::from).into();
+	/// lines.dedup_by_content();
+	/// assert_eq!(lines, [&b"b"[..], b"\xFF", b"a"].map(RawString::from));
+	/// ```
+	fn dedup_by_content(&mut self);
+}
+
+impl RawVecExt for Vec<RawString> {
+	fn dedup_by_content(&mut self) {
+		// sort indices by content, then by position, so the first of each run is the one kept
+		let mut kept: Vec<usize> = (0..self.len()).collect();
+		kept.sort_unstable_by(|&a, &b| self[a].cmp(&self[b]).then(a.cmp(&b)));
+		kept.dedup_by(|&mut a, &mut b| self[a] == self[b]);
+		kept.sort_unstable();
+
+		let mut kept = kept.into_iter().peekable();
+		let mut index = 0;
+		self.retain(|_| {
+			let keep = kept.next_if_eq(&index).is_some();
+			index += 1;
+			keep
+		});
+	}
+}
+
+/// A wrapper that compares, orders and hashes a raw string ignoring ASCII case.
+///
+/// Unlike [`RawStr::cmp_ignore_ascii_case`], strings that differ only in case are equal,
+/// so the wrapper can be used as a case-insensitive key in maps and sets.
+///
+/// # Examples
+/// ```
+/// # use std::collections::BTreeMap;
+/// # use rawstring::{CaseInsensitive, RawStr};
+/// let mut headers = BTreeMap::new();
+/// headers.insert(CaseInsensitive(RawStr::new("Content-Type")), "text/plain");
+/// assert_eq!(headers.get(&CaseInsensitive(RawStr::new("content-type"))), Some(&"text/plain"));
+///
+/// let mut names = ["b", "C", "a"].map(RawStr::new);
+/// names.sort_by_key(|&name| CaseInsensitive(name));
+/// assert_eq!(names, ["a", "b", "C"].map(RawStr::new));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CaseInsensitive<T>(pub T);
+
+impl<T: AsRef<RawStr>> PartialEq for CaseInsensitive<T> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.0.as_ref().eq_ignore_ascii_case(other.0.as_ref())
+	}
+}
+
+impl<T: AsRef<RawStr>> Eq for CaseInsensitive<T> {}
+
+impl<T: AsRef<RawStr>> PartialOrd for CaseInsensitive<T> {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<T: AsRef<RawStr>> Ord for CaseInsensitive<T> {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {
+		cmp_lowercase(self.0.as_ref(), other.0.as_ref())
+	}
+}
+
+impl<T: AsRef<RawStr>> Hash for CaseInsensitive<T> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		let raw = self.0.as_ref();
+		state.write_usize(raw.len());
+		for &byte in raw.iter() {
+			state.write_u8(byte.to_ascii_lowercase());
+		}
+	}
+}
+
+/// A wrapper that orders a raw string in natural order, as by [`RawStr::cmp_natural`].
+///
+/// Equality and hashing are by bytes, which is consistent with the ordering.
+///
+/// # Examples
+/// ```
+/// # use std::collections::BTreeSet;
+/// # use rawstring::{Natural, RawString};
+/// let files: BTreeSet<_> = ["v10", "v9", "v1"].map(|name| Natural(RawString::from(name))).into();
+/// let sorted: Vec<_> = files.into_iter().map(|Natural(name)| name).collect();
+/// assert_eq!(sorted, ["v1", "v9", "v10"].map(RawString::from));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Natural<T>(pub T);
+
+impl<T: AsRef<RawStr>> PartialEq for Natural<T> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {
+		self.0.as_ref() == other.0.as_ref()
+	}
+}
+
+impl<T: AsRef<RawStr>> Eq for Natural<T> {}
+
+impl<T: AsRef<RawStr>> PartialOrd for Natural<T> {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<T: AsRef<RawStr>> Ord for Natural<T> {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.0.as_ref().cmp_natural(other.0.as_ref())
+	}
+}
+
+impl<T: AsRef<RawStr>> Hash for Natural<T> {
+	#[inline]
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.0.as_ref().hash(state);
+	}
+}
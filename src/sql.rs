@@ -0,0 +1,114 @@
+// rawstring::sql
+
+use std::fmt::{self, Write};
+
+use crate::{RawStr, RawString};
+
+/// The SQL dialect an [`EscapeSql`] writes a literal for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum SqlDialect {
+	/// Standard SQL: quotes are doubled and nothing else is escaped.
+	#[default]
+	Standard,
+	/// MySQL and MariaDB with the default SQL mode: quotes are doubled, and backslashes,
+	/// NUL, line breaks and Ctrl-Z are escaped with backslashes.
+	///
+	/// This is not correct with the `NO_BACKSLASH_ESCAPES` SQL mode, where
+	/// [`SqlDialect::Standard`] must be used instead.
+	MySql,
+	/// SQLite: quotes are doubled and nothing else is escaped.
+	Sqlite,
+}
+
+impl RawStr {
+	/// Returns a wrapper that displays the [`RawStr`] as a quoted SQL string literal for
+	/// the given dialect.
+	///
+	/// If the [`RawStr`] is not valid UTF-8, or contains a NUL byte in a dialect that cannot
+	/// represent one in a string literal, it is written as a hexadecimal literal `x'...'`
+	/// instead, which all three dialects read as the exact bytes. Note that this has a binary
+	/// type, such as a BLOB in SQLite, rather than a text type.
+	///
+	/// Prefer bound parameters where possible; this is for tools that must generate SQL text.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, SqlDialect};
+	/// let raw = RawStr::new("it's a\\b\n");
+	/// assert_eq!(raw.escape_sql(SqlDialect::Standard).to_string(), "'it''s a\\b\n'");
+	/// assert_eq!(raw.escape_sql(SqlDialect::MySql).to_string(), r"'it''s a\\b\n'");
+	///
+	/// let raw = RawStr::new(b"caf\xE9");
+	/// assert_eq!(raw.escape_sql(SqlDialect::Sqlite).to_string(), "x'636166e9'");
+	/// assert_eq!(RawStr::new("a\0b").escape_sql(SqlDialect::MySql).to_string(), r"'a\0b'");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn escape_sql(&self, dialect: SqlDialect) -> EscapeSql<'_> {
+		EscapeSql { raw: self, dialect }
+	}
+}
+
+impl RawString {
+	/// Returns a wrapper that displays the [`RawString`] as a quoted SQL string literal for
+	/// the given dialect.
+	///
+	/// See [`RawStr::escape_sql`].
+	#[inline]
+	#[must_use]
+	pub fn escape_sql(&self, dialect: SqlDialect) -> EscapeSql<'_> {
+		self.as_ref().escape_sql(dialect)
+	}
+}
+
+/// A wrapper that displays a [`RawStr`] as a quoted SQL literal.
+///
+/// Created by [`RawStr::escape_sql`].
+#[derive(Debug, Clone, Copy)]
+pub struct EscapeSql<'a> {
+	raw: &'a RawStr,
+	dialect: SqlDialect,
+}
+
+impl EscapeSql<'_> {
+	/// Returns the escape of `c`, or `None` if it is written unchanged.
+	fn escape(&self, c: char) -> Option<&'static str> {
+		match (self.dialect, c) {
+			(_, '\'') => Some("''"),
+			(SqlDialect::MySql, '\\') => Some("\\\\"),
+			(SqlDialect::MySql, '\0') => Some("\\0"),
+			(SqlDialect::MySql, '\n') => Some("\\n"),
+			(SqlDialect::MySql, '\r') => Some("\\r"),
+			(SqlDialect::MySql, '\x1A') => Some("\\Z"),
+			_ => None,
+		}
+	}
+}
+
+impl fmt::Display for EscapeSql<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let text = match self.raw.to_utf8_checked() {
+			Ok(text) if self.dialect == SqlDialect::MySql || !text.contains('\0') => text,
+			_ => {
+				f.write_str("x'")?;
+				for &b in &self.raw.0 {
+					write!(f, "{b:02x}")?;
+				}
+				return f.write_char('\'');
+			}
+		};
+
+		f.write_char('\'')?;
+		let mut start = 0;
+		for (i, c) in text.char_indices() {
+			if let Some(escape) = self.escape(c) {
+				f.write_str(&text[start..i])?;
+				f.write_str(escape)?;
+				start = i + c.len_utf8();
+			}
+		}
+		f.write_str(&text[start..])?;
+		f.write_char('\'')
+	}
+}
@@ -0,0 +1,54 @@
+// rawstring::strict
+
+// With the `strict` feature in debug builds, every conversion that writes replacement
+// characters panics instead, except those where the caller chooses the replacement, such as
+// `to_utf8_lossy_with` and `display_replacing`, and `LogValue`, whose purpose is to show
+// invalid data.
+
+/// Panics if `bytes` is not valid UTF-8, so that lossy conversions of invalid data
+/// are caught during development.
+///
+/// This only checks anything with the `strict` feature in debug builds.
+#[cfg(all(feature = "strict", debug_assertions))]
+#[track_caller]
+pub(crate) fn check_lossless(bytes: &[u8], operation: &str) {
+	if let Err(err) = str::from_utf8(bytes) {
+		replaced_invalid(err.valid_up_to(), operation);
+	}
+}
+
+/// Does nothing without the `strict` feature or in release builds.
+#[cfg(not(all(feature = "strict", debug_assertions)))]
+#[inline(always)]
+pub(crate) fn check_lossless(_bytes: &[u8], _operation: &str) {}
+
+/// Panics if `units` is not well-formed UTF-16, as [`check_lossless`] does for UTF-8.
+#[cfg(all(feature = "strict", debug_assertions))]
+#[track_caller]
+pub(crate) fn check_lossless_wide(units: &[u16], operation: &str) {
+	let mut position = 0;
+	for c in char::decode_utf16(units.iter().copied()) {
+		match c {
+			Ok(c) => position += c.len_utf16(),
+			Err(_) => panic!("strict mode: {operation} replaced an unpaired surrogate at unit {position}"),
+		}
+	}
+}
+
+/// Does nothing without the `strict` feature or in release builds.
+#[cfg(not(all(feature = "strict", debug_assertions)))]
+#[inline(always)]
+pub(crate) fn check_lossless_wide(_units: &[u16], _operation: &str) {}
+
+/// Panics because `operation` is replacing the invalid UTF-8 at byte `position`,
+/// for conversions that only find invalid data as they go.
+#[cfg(all(feature = "strict", debug_assertions))]
+#[track_caller]
+pub(crate) fn replaced_invalid(position: usize, operation: &str) {
+	panic!("strict mode: {operation} replaced invalid UTF-8 at byte {position}");
+}
+
+/// Does nothing without the `strict` feature or in release builds.
+#[cfg(not(all(feature = "strict", debug_assertions)))]
+#[inline(always)]
+pub(crate) fn replaced_invalid(_position: usize, _operation: &str) {}
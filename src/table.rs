@@ -0,0 +1,166 @@
+// rawstring::table
+
+use std::fmt::{self, Write};
+
+use crate::{RawStr, width};
+
+/// Options controlling how [`display_table_with`] lays out a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TableOptions {
+	/// The maximum display width of a cell, in columns.
+	/// Longer cells are truncated and end with `…`.
+	pub max_column_width: Option<usize>,
+	/// Whether the first row is a header, separated from the other rows by a rule.
+	pub header: bool,
+}
+
+impl TableOptions {
+	/// Creates options with unlimited column widths and no header.
+	#[inline]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { max_column_width: None, header: false }
+	}
+}
+
+/// Returns a wrapper that displays `rows` as an ASCII-art table.
+///
+/// See [`display_table_with`].
+///
+/// # Examples
+/// ```
+/// # use rawstring::{RawStr, display_table};
+/// let rows: &[&[&RawStr]] = &[
+///     &[RawStr::new("key"), RawStr::new("value")],
+///     &[RawStr::new("名前"), RawStr::new(b"caf\xE9\n")],
+/// ];
+/// assert_eq!(display_table(rows).to_string(), "\
+/// +------+-----------+
+/// | key  | value     |
+/// | 名前 | caf\\xe9\\n |
+/// +------+-----------+");
+/// ```
+#[inline]
+#[must_use]
+pub fn display_table<'a>(rows: &'a [&'a [&'a RawStr]]) -> DisplayTable<'a> {
+	display_table_with(rows, TableOptions::new())
+}
+
+/// Returns a wrapper that displays `rows` as an ASCII-art table according to `options`.
+///
+/// Columns are padded to the [display width](RawStr::display_width) of their widest cell,
+/// so wide characters line up. Invalid UTF-8 and control characters are escaped as in
+/// [`Debug`](fmt::Debug) output, so a cell can never break the layout of the table.
+/// Rows with fewer cells than others are padded with empty cells.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{RawStr, TableOptions, display_table_with};
+/// let rows: &[&[&RawStr]] = &[
+///     &[RawStr::new("path"), RawStr::new("size")],
+///     &[RawStr::new(b"/tmp/\xFF\xFEreport.txt"), RawStr::new("12")],
+///     &[RawStr::new("/etc/hosts")],
+/// ];
+/// let options = TableOptions { max_column_width: Some(12), header: true };
+/// assert_eq!(display_table_with(rows, options).to_string(), "\
+/// +------------+------+
+/// | path       | size |
+/// +------------+------+
+/// | /tmp/\\xff… | 12   |
+/// | /etc/hosts |      |
+/// +------------+------+");
+/// ```
+#[inline]
+#[must_use]
+pub fn display_table_with<'a>(rows: &'a [&'a [&'a RawStr]], options: TableOptions) -> DisplayTable<'a> {
+	DisplayTable { rows, options }
+}
+
+/// A wrapper that displays rows of [`RawStr`] cells as an ASCII-art table.
+///
+/// Created by [`display_table`] and [`display_table_with`].
+/// The table is written without a trailing newline, and an empty table is written as nothing.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayTable<'a> {
+	rows: &'a [&'a [&'a RawStr]],
+	options: TableOptions,
+}
+
+impl fmt::Display for DisplayTable<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let cells: Vec<Vec<(String, usize)>> = self.rows
+			.iter()
+			.map(|row| row.iter().map(|raw| render_cell(raw, self.options.max_column_width)).collect())
+			.collect();
+		let columns = cells.iter().map(Vec::len).max().unwrap_or(0);
+		if columns == 0 {
+			return Ok(());
+		}
+		let mut widths = vec![0; columns];
+		for row in &cells {
+			for (width, (_, cell_width)) in widths.iter_mut().zip(row) {
+				*width = (*width).max(*cell_width);
+			}
+		}
+
+		let rule = |f: &mut fmt::Formatter<'_>| -> fmt::Result {
+			for &width in &widths {
+				write!(f, "+{:-<1$}", "", width + 2)?;
+			}
+			f.write_char('+')
+		};
+
+		rule(f)?;
+		for (i, row) in cells.iter().enumerate() {
+			f.write_char('\n')?;
+			for (column, &width) in widths.iter().enumerate() {
+				let (text, cell_width) = row.get(column).map_or(("", 0), |(text, width)| (text.as_str(), *width));
+				write!(f, "| {text}{:1$} ", "", width - cell_width)?;
+			}
+			f.write_char('|')?;
+			if i == 0 && self.options.header && cells.len() > 1 {
+				f.write_char('\n')?;
+				rule(f)?;
+			}
+		}
+		f.write_char('\n')?;
+		rule(f)
+	}
+}
+
+/// Renders `raw` as a single-line cell, returning the text and its display width.
+fn render_cell(raw: &RawStr, max_width: Option<usize>) -> (String, usize) {
+	let mut text = String::new();
+	let mut width = 0;
+	// the length and width of the text after each unit, for truncating between units
+	let mut ends = Vec::new();
+	for (_, unit) in raw.char_ranges() {
+		let start = text.len();
+		match unit {
+			Ok(c) if c.is_control() => {
+				let _ = write!(text, "{}", c.escape_debug());
+				width += text.len() - start;
+			}
+			Ok(c) => {
+				text.push(c);
+				width += width::unit_width(Ok(c));
+			}
+			Err(invalid) => {
+				let _ = write!(text, "{}", invalid.escape_ascii());
+				width += text.len() - start;
+			}
+		}
+		ends.push((text.len(), width));
+	}
+
+	match max_width {
+		Some(0) => (String::new(), 0),
+		Some(max) if width > max => {
+			let (len, width) = ends.into_iter().rev().find(|&(_, width)| width < max).unwrap_or((0, 0));
+			text.truncate(len);
+			text.push('…');
+			(text, width + 1)
+		}
+		_ => (text, width),
+	}
+}
@@ -0,0 +1,224 @@
+// rawstring::template
+
+use std::{error::Error, fmt};
+
+use crate::{ErrorOffset, EscapeStyle, RawStr, RawString};
+
+/// A binary-safe template with `{name}` holes, parsed once and rendered any number of times.
+///
+/// Text outside holes is copied byte for byte, with `{{` and `}}` standing for literal braces.
+/// A hole may name an escape after a `|`, applied to the value when rendering:
+///
+/// - `{name}` inserts the value unchanged.
+/// - `{name|hex}` inserts the value as lowercase hex.
+/// - `{name|shell}` inserts the value quoted for a POSIX shell, see [`RawStr::shell_quote`].
+/// - `{name|debug}` inserts the value with special and invalid bytes escaped,
+///   as in its [`Debug`](fmt::Debug) output without the quotes.
+///
+/// # Examples
+/// ```
+/// # use std::collections::HashMap;
+/// # use rawstring::{RawStr, RawString, RawTemplate};
+/// let template = RawTemplate::parse("run {cmd|shell} --key {key|hex} # {{{note}}}").unwrap();
+/// assert_eq!(template.names().collect::<Vec<_>>(), ["cmd", "key", "note"]);
+///
+/// let values = HashMap::from([
+///     ("cmd", RawStr::new("it's")),
+///     ("key", RawStr::new(b"\x00\xFF")),
+///     ("note", RawStr::new(b"raw \xFE")),
+/// ]);
+/// let rendered = template.render(|name| values.get(name)).unwrap();
+/// assert_eq!(rendered, RawString::from(b"run 'it'\\''s' --key 00ff # {raw \xFE}"));
+///
+/// let err = template.render(|name| values.get(name).filter(|_| name != "key")).unwrap_err();
+/// assert_eq!(err.to_string(), "no value for hole `key` at byte 22");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RawTemplate {
+	pieces: Vec<Piece>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Piece {
+	Text(RawString),
+	Hole { name: String, escape: Escape, position: usize },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Escape {
+	None,
+	Hex,
+	Shell,
+	Debug,
+}
+
+/// An error returned by [`RawTemplate::parse`] or [`RawTemplate::render`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TemplateError {
+	position: usize,
+	kind: TemplateErrorKind,
+}
+
+/// The kind of a [`TemplateError`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TemplateErrorKind {
+	/// A `{` was never closed.
+	UnterminatedHole,
+	/// A `}` appeared outside a hole without being doubled.
+	UnmatchedBrace,
+	/// A hole name is empty or not valid UTF-8.
+	InvalidName,
+	/// A hole names an escape that does not exist.
+	UnknownEscape(String),
+	/// No value was given for a hole when rendering.
+	MissingValue(String),
+}
+
+impl TemplateError {
+	/// Returns the byte offset in the template at which the error occurred.
+	#[inline]
+	#[must_use]
+	pub const fn position(&self) -> usize {
+		self.position
+	}
+
+	/// Returns the kind of error.
+	#[inline]
+	#[must_use]
+	pub const fn kind(&self) -> &TemplateErrorKind {
+		&self.kind
+	}
+}
+
+impl fmt::Display for TemplateError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match &self.kind {
+			TemplateErrorKind::UnterminatedHole => f.write_str("unterminated hole")?,
+			TemplateErrorKind::UnmatchedBrace => f.write_str("unmatched `}`")?,
+			TemplateErrorKind::InvalidName => f.write_str("invalid hole name")?,
+			TemplateErrorKind::UnknownEscape(escape) => write!(f, "unknown escape `{escape}`")?,
+			TemplateErrorKind::MissingValue(name) => write!(f, "no value for hole `{name}`")?,
+		}
+		write!(f, " at byte {}", self.position)
+	}
+}
+
+impl Error for TemplateError {}
+
+impl ErrorOffset for TemplateError {
+	#[inline]
+	fn error_offset(&self) -> Option<usize> {
+		Some(self.position)
+	}
+}
+
+impl RawTemplate {
+	/// Parses a template.
+	///
+	/// # Errors
+	/// Returns a [`TemplateError`] if a hole is unterminated, empty, not valid UTF-8 or names an
+	/// unknown escape, or if a `}` outside a hole is not doubled.
+	pub fn parse<B>(template: &B) -> Result<Self, TemplateError>
+	where
+		B: ?Sized + AsRef<[u8]>
+	{
+		let bytes = template.as_ref();
+		let error = |position, kind| TemplateError { position, kind };
+		let mut pieces = Vec::new();
+		let mut text = RawString::new();
+		let mut pos = 0;
+		while let Some(offset) = bytes[pos..].iter().position(|&b| b == b'{' || b == b'}') {
+			let brace = pos + offset;
+			text.extend_from_slice(&bytes[pos..brace]);
+			if bytes.get(brace + 1) == Some(&bytes[brace]) {
+				text.push(bytes[brace]);
+				pos = brace + 2;
+				continue;
+			}
+			if bytes[brace] == b'}' {
+				return Err(error(brace, TemplateErrorKind::UnmatchedBrace));
+			}
+
+			let len = bytes[brace + 1..]
+				.iter()
+				.position(|&b| b == b'}')
+				.ok_or(error(brace, TemplateErrorKind::UnterminatedHole))?;
+			let hole = str::from_utf8(&bytes[brace + 1..brace + 1 + len])
+				.map_err(|_| error(brace, TemplateErrorKind::InvalidName))?;
+			let (name, escape) = match hole.split_once('|') {
+				Some((name, escape)) => (name, Escape::parse(escape).ok_or_else(|| {
+					error(brace, TemplateErrorKind::UnknownEscape(escape.into()))
+				})?),
+				None => (hole, Escape::None),
+			};
+			if name.is_empty() || name.contains('{') {
+				return Err(error(brace, TemplateErrorKind::InvalidName));
+			}
+
+			if !text.is_empty() {
+				pieces.push(Piece::Text(std::mem::take(&mut text)));
+			}
+			pieces.push(Piece::Hole { name: name.into(), escape, position: brace });
+			pos = brace + len + 2;
+		}
+		text.extend_from_slice(&bytes[pos..]);
+		if !text.is_empty() {
+			pieces.push(Piece::Text(text));
+		}
+		Ok(Self { pieces })
+	}
+
+	/// Returns the names of the holes in order of appearance, including repeats.
+	pub fn names(&self) -> impl Iterator<Item = &str> {
+		self.pieces.iter().filter_map(|piece| match piece {
+			Piece::Hole { name, .. } => Some(name.as_str()),
+			Piece::Text(_) => None,
+		})
+	}
+
+	/// Renders the template, looking up the value of each hole by name with `values`.
+	///
+	/// # Errors
+	/// Returns a [`TemplateError`] if `values` returns `None` for a hole.
+	pub fn render<F, V>(&self, mut values: F) -> Result<RawString, TemplateError>
+	where
+		F: FnMut(&str) -> Option<V>,
+		V: AsRef<[u8]>,
+	{
+		let mut out = RawString::new();
+		for piece in &self.pieces {
+			match piece {
+				Piece::Text(text) => out.extend_from_slice(text),
+				Piece::Hole { name, escape, position } => {
+					let value = values(name).ok_or_else(|| TemplateError {
+						position: *position,
+						kind: TemplateErrorKind::MissingValue(name.clone()),
+					})?;
+					escape.apply(RawStr::new(value.as_ref()), &mut out);
+				}
+			}
+		}
+		Ok(out)
+	}
+}
+
+impl Escape {
+	fn parse(name: &str) -> Option<Self> {
+		match name {
+			"hex" => Some(Self::Hex),
+			"shell" => Some(Self::Shell),
+			"debug" => Some(Self::Debug),
+			_ => None,
+		}
+	}
+
+	fn apply(self, value: &RawStr, out: &mut RawString) {
+		match self {
+			Self::None => out.extend_from_slice(value),
+			Self::Hex => value.write_escaped_into(out, EscapeStyle::Hex),
+			Self::Shell => out.extend_from_slice(&value.shell_quote()),
+			Self::Debug => value.write_escaped_into(out, EscapeStyle::Debug),
+		}
+	}
+}
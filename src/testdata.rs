@@ -0,0 +1,111 @@
+// rawstring::testdata
+
+//! A corpus of invalid UTF-8 for testing code that handles [`RawStr`]s.
+//!
+//! [`SAMPLES`] lists categorized invalid sequences, and [`check_handles_invalid`] runs a
+//! callback against each of them in a variety of surroundings. Requires the `testdata` feature.
+//!
+//! # Examples
+//! ```
+//! # use rawstring::{RawStr, testdata};
+//! // lossy conversion must produce valid UTF-8 with at least one replacement character
+//! testdata::check_handles_invalid(|raw: &RawStr| {
+//!     let text = raw.to_utf8_lossy_with(|_, _| ());
+//!     assert!(text.contains('\u{FFFD}'));
+//! });
+//! ```
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{RawStr, RawString, Utf8ErrorKind};
+
+/// An invalid UTF-8 sample in the corpus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sample {
+	/// A short description of the sample.
+	pub name: &'static str,
+	/// The bytes of the sample.
+	pub bytes: &'static [u8],
+	/// The kind of the first error in the sample, as reported by [`RawStr::utf8_error_positions`].
+	pub kind: Utf8ErrorKind,
+}
+
+impl Sample {
+	/// Returns the bytes of the sample as a [`RawStr`].
+	#[inline]
+	#[must_use]
+	pub const fn as_raw_str(&self) -> &'static RawStr {
+		RawStr::new(self.bytes)
+	}
+}
+
+macro_rules! samples {
+	($($kind:ident: $name:literal => $bytes:literal,)*) => {
+		/// The invalid UTF-8 samples in the corpus, grouped by [`Utf8ErrorKind`].
+		pub const SAMPLES: &[Sample] = &[
+			$(Sample { name: $name, bytes: $bytes, kind: Utf8ErrorKind::$kind },)*
+		];
+	};
+}
+
+samples! {
+	InvalidStartByte: "lone continuation byte 80" => b"\x80",
+	InvalidStartByte: "lone continuation byte BF" => b"\xBF",
+	InvalidStartByte: "run of continuation bytes" => b"\x80\x81\x82\x83",
+	InvalidStartByte: "byte F5, above every lead byte" => b"\xF5\x80\x80\x80",
+	InvalidStartByte: "byte FE" => b"\xFE",
+	InvalidStartByte: "byte FF" => b"\xFF",
+	InvalidStartByte: "UTF-16 byte order mark bytes" => b"\xFF\xFE",
+	Overlong: "two-byte overlong NUL" => b"\xC0\x80",
+	Overlong: "two-byte overlong slash" => b"\xC0\xAF",
+	Overlong: "two-byte overlong U+007F" => b"\xC1\xBF",
+	Overlong: "three-byte overlong slash" => b"\xE0\x80\xAF",
+	Overlong: "three-byte overlong U+07FF" => b"\xE0\x9F\xBF",
+	Overlong: "four-byte overlong slash" => b"\xF0\x80\x80\xAF",
+	Overlong: "four-byte overlong U+FFFF" => b"\xF0\x8F\xBF\xBF",
+	Surrogate: "high surrogate U+D800" => b"\xED\xA0\x80",
+	Surrogate: "low surrogate U+DFFF" => b"\xED\xBF\xBF",
+	Surrogate: "surrogate pair encoded separately (CESU-8)" => b"\xED\xA0\xBD\xED\xB8\x80",
+	OutOfRange: "U+110000" => b"\xF4\x90\x80\x80",
+	OutOfRange: "largest four-byte value" => b"\xF4\xBF\xBF\xBF",
+	Truncated: "lead byte of two-byte sequence" => b"\xC2",
+	Truncated: "three-byte sequence missing one byte" => b"\xE2\x82",
+	Truncated: "four-byte sequence missing one byte" => b"\xF0\x9F\x98",
+	Truncated: "lead byte followed by ASCII" => b"\xE2A",
+	Truncated: "four-byte lead byte alone" => b"\xF4",
+}
+
+/// The surroundings each sample is tested in: a description, a prefix and a suffix.
+///
+/// None of them may complete a truncated sample.
+const SURROUNDINGS: &[(&str, &[u8], &[u8])] = &[
+	("alone", b"", b""),
+	("between ASCII", b"a", b"b"),
+	("after multi-byte text", "caf\u{e9} ".as_bytes(), b""),
+	("before multi-byte text", b"", " \u{20ac}".as_bytes()),
+	("before a lone lead byte", b"", b"\xE2"),
+	("after a NUL", b"\0", b""),
+];
+
+/// Calls `f` with every sample in [`SAMPLES`], alone and in a variety of surroundings:
+/// between ASCII, next to multi-byte characters, before a lone lead byte,
+/// after a NUL, and repeated.
+///
+/// # Panics
+/// If `f` panics, panics with a message naming the sample and surroundings that caused it,
+/// after the original panic message has been printed.
+pub fn check_handles_invalid(f: impl Fn(&RawStr)) {
+	for sample in SAMPLES {
+		let repeated = (String::from("repeated"), [sample.bytes, sample.bytes].concat());
+		let surrounded = SURROUNDINGS
+			.iter()
+			.map(|&(surrounding, prefix, suffix)| (surrounding.into(), [prefix, sample.bytes, suffix].concat()));
+
+		for (surrounding, input) in surrounded.chain([repeated]) {
+			let input = RawString::from(input);
+			if panic::catch_unwind(AssertUnwindSafe(|| f(input.as_ref()))).is_err() {
+				panic!("callback panicked on sample {:?}, {surrounding}: {input:?}", sample.name);
+			}
+		}
+	}
+}
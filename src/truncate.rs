@@ -0,0 +1,145 @@
+// rawstring::truncate
+
+use std::{borrow::Cow, ops::Range};
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::{RawStr, RawString, width};
+
+/// A run of characters displayed as one unit, with its display width.
+struct Cluster {
+	range: Range<usize>,
+	width: usize,
+}
+
+/// Splits `text` into clusters that must not be separated when truncating.
+///
+/// This approximates extended grapheme clusters without the Unicode segmentation tables:
+/// zero-width characters such as combining marks and variation selectors join the preceding
+/// character, a zero-width joiner also joins the following one, and regional indicators pair
+/// up into flags. Each invalid sequence is a cluster of its own.
+fn clusters(text: &RawStr) -> Vec<Cluster> {
+	let mut clusters: Vec<Cluster> = Vec::new();
+	let mut after_joiner = false;
+	let mut open_flag = false;
+	for (range, unit) in text.char_ranges() {
+		let unit_width = width::unit_width(unit.map_err(|invalid| &invalid.0));
+		let (joins, is_flag) = match unit {
+			Ok(c) => {
+				let is_flag = matches!(c, '\u{1F1E6}'..='\u{1F1FF}');
+				(after_joiner || c.width() == Some(0) || (is_flag && open_flag), is_flag)
+			}
+			Err(_) => (false, false),
+		};
+		match clusters.last_mut() {
+			Some(last) if joins => {
+				last.range.end = range.end;
+				last.width += unit_width;
+			}
+			_ => clusters.push(Cluster { range, width: unit_width }),
+		}
+		after_joiner = unit == Ok('\u{200D}');
+		open_flag = is_flag && !(joins && open_flag);
+	}
+	clusters
+}
+
+/// Returns the number of leading clusters that fit in `budget` columns, and their width.
+fn fitting<'a>(clusters: impl Iterator<Item = &'a Cluster>, budget: usize) -> (usize, usize) {
+	let mut count = 0;
+	let mut used = 0;
+	for cluster in clusters {
+		if used + cluster.width > budget {
+			break;
+		}
+		used += cluster.width;
+		count += 1;
+	}
+	(count, used)
+}
+
+/// Returns the longest prefix of `ellipsis` that fits in `max_width` columns.
+fn fit_ellipsis(ellipsis: &RawStr, max_width: usize) -> (&RawStr, usize) {
+	let clusters = clusters(ellipsis);
+	let (count, used) = fitting(clusters.iter(), max_width);
+	let end = clusters[..count].last().map_or(0, |cluster| cluster.range.end);
+	(RawStr::new(&ellipsis[..end]), used)
+}
+
+impl RawStr {
+	/// Shortens the [`RawStr`] to at most `max_width` display columns by cutting off its end
+	/// and appending `ellipsis`.
+	///
+	/// Returns the [`RawStr`] unchanged, without copying, if it already fits. Widths are measured
+	/// as in [`RawStr::display_width`], and the cut never falls inside a character or between a
+	/// character and the combining marks, joiners or variation selectors that follow it.
+	/// If `ellipsis` itself does not fit, as much of it as fits is returned.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let name = RawStr::new(b"re\xCC\x81sume\xCC\x81 final \xFF.pdf");
+	/// assert_eq!(*name.truncate_end(6, "..."), b"re\xCC\x81s...");
+	/// assert_eq!(*name.truncate_end(40, "..."), *name);
+	/// ```
+	#[must_use]
+	pub fn truncate_end<E>(&self, max_width: usize, ellipsis: &E) -> Cow<'_, RawStr>
+	where
+		E: ?Sized + AsRef<[u8]>
+	{
+		if self.display_width() <= max_width {
+			return Cow::Borrowed(self);
+		}
+		let (ellipsis, ellipsis_width) = fit_ellipsis(RawStr::new(ellipsis), max_width);
+		let clusters = clusters(self);
+		let (count, _) = fitting(clusters.iter(), max_width - ellipsis_width);
+		let end = clusters[..count].last().map_or(0, |cluster| cluster.range.end);
+
+		let mut truncated = RawString::from(Vec::with_capacity(end + ellipsis.len()));
+		truncated.extend_from_slice(&self[..end]);
+		truncated.extend_from_slice(ellipsis);
+		Cow::Owned(truncated)
+	}
+
+	/// Shortens the [`RawStr`] to at most `max_width` display columns by replacing its middle
+	/// with `ellipsis`, keeping the start and the end, as is usual for long paths.
+	///
+	/// The start gets the larger half of the remaining width when it does not divide evenly,
+	/// and the end gets any width the start could not use. Widths and cuts follow
+	/// [`RawStr::truncate_end`].
+	///
+	/// # Examples
+	/// ```
+	/// # use std::borrow::Cow;
+	/// # use rawstring::RawStr;
+	/// let path = RawStr::new(b"/home/user/projects/caf\xE9/src/main.rs");
+	/// assert_eq!(*path.truncate_middle(20, "\u{2026}"), b"/home/user\xE2\x80\xA6c/main.rs");
+	///
+	/// // wide characters are kept whole
+	/// let title = RawStr::new("\u{65e5}\u{672c}\u{8a9e}\u{306e}\u{30bf}\u{30a4}\u{30c8}\u{30eb}");
+	/// assert_eq!(*title.truncate_middle(9, "..."), "\u{65e5}...\u{30c8}\u{30eb}");
+	/// assert!(matches!(title.truncate_middle(16, "..."), Cow::Borrowed(_)));
+	/// ```
+	#[must_use]
+	pub fn truncate_middle<E>(&self, max_width: usize, ellipsis: &E) -> Cow<'_, RawStr>
+	where
+		E: ?Sized + AsRef<[u8]>
+	{
+		if self.display_width() <= max_width {
+			return Cow::Borrowed(self);
+		}
+		let (ellipsis, ellipsis_width) = fit_ellipsis(RawStr::new(ellipsis), max_width);
+		let clusters = clusters(self);
+		let budget = max_width - ellipsis_width;
+		let (head_count, head_width) = fitting(clusters.iter(), budget.div_ceil(2));
+		let (tail_count, _) = fitting(clusters[head_count..].iter().rev(), budget - head_width);
+		let head_end = clusters[..head_count].last().map_or(0, |cluster| cluster.range.end);
+		let tail_start = clusters[clusters.len() - tail_count..].first().map_or(self.len(), |cluster| cluster.range.start);
+
+		let mut truncated = RawString::from(Vec::with_capacity(head_end + ellipsis.len() + self.len() - tail_start));
+		truncated.extend_from_slice(&self[..head_end]);
+		truncated.extend_from_slice(ellipsis);
+		truncated.extend_from_slice(&self[tail_start..]);
+		Cow::Owned(truncated)
+	}
+}
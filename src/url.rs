@@ -0,0 +1,93 @@
+// rawstring::url
+
+use crate::{RawStr, RawString};
+
+/// The components of a URL or URI reference, as split by [`RawStr::split_url_components`].
+///
+/// Each component borrows the bytes of the URL exactly as they are, without the delimiters
+/// around it. A component that is absent is `None`, which is distinct from one that is
+/// present but empty, as in `http://host?` with its empty query, so the URL can be
+/// reassembled byte for byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UrlComponents<'a> {
+	/// The scheme, before the first `:`, such as `https`.
+	pub scheme: Option<&'a RawStr>,
+	/// The authority, after `//`, such as `user@host:8080`.
+	pub authority: Option<&'a RawStr>,
+	/// The path, which is always present but may be empty.
+	pub path: &'a RawStr,
+	/// The query, after `?`.
+	pub query: Option<&'a RawStr>,
+	/// The fragment, after `#`.
+	pub fragment: Option<&'a RawStr>,
+}
+
+impl RawStr {
+	/// Splits the URL in the [`RawStr`] into its components at their delimiters, following
+	/// the regular expression in appendix B of RFC 3986.
+	///
+	/// Nothing is percent-decoded, normalized or validated beyond finding the delimiters,
+	/// so this never fails and accepts bytes, such as spaces and invalid UTF-8, that a
+	/// full URL parser would reject or rewrite. A scheme is only recognized if it is not
+	/// empty and contains none of `/`, `?` and `#`.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let url = RawStr::new(b"https://user@h\xC3\xB6st:8080/a%2Fb/\xFF?q=1&r#top");
+	/// let parts = url.split_url_components();
+	/// assert_eq!(parts.scheme, Some(RawStr::new("https")));
+	/// assert_eq!(parts.authority, Some(RawStr::new(b"user@h\xC3\xB6st:8080")));
+	/// assert_eq!(parts.path, b"/a%2Fb/\xFF");
+	/// assert_eq!(parts.query, Some(RawStr::new("q=1&r")));
+	/// assert_eq!(parts.fragment, Some(RawStr::new("top")));
+	///
+	/// // a relative reference
+	/// let parts = RawStr::new("../img.png?").split_url_components();
+	/// assert_eq!((parts.scheme, parts.authority), (None, None));
+	/// assert_eq!(parts.path, "../img.png");
+	/// assert_eq!((parts.query, parts.fragment), (Some(RawStr::new("")), None));
+	/// ```
+	#[must_use]
+	pub fn split_url_components(&self) -> UrlComponents<'_> {
+		let mut rest = self;
+
+		let scheme_end = rest.iter().position(|&b| matches!(b, b':' | b'/' | b'?' | b'#'));
+		let scheme = match scheme_end {
+			Some(end) if end > 0 && rest[end] == b':' => {
+				let scheme = RawStr::new(&rest[..end]);
+				rest = RawStr::new(&rest[end + 1..]);
+				Some(scheme)
+			}
+			_ => None,
+		};
+
+		let authority = rest.strip_prefix(b"//").map(|after| {
+			let end = after.iter().position(|&b| matches!(b, b'/' | b'?' | b'#')).unwrap_or(after.len());
+			rest = RawStr::new(&after[end..]);
+			RawStr::new(&after[..end])
+		});
+
+		let (rest, fragment) = match rest.iter().position(|&b| b == b'#') {
+			Some(i) => (RawStr::new(&rest[..i]), Some(RawStr::new(&rest[i + 1..]))),
+			None => (rest, None),
+		};
+		let (path, query) = match rest.iter().position(|&b| b == b'?') {
+			Some(i) => (RawStr::new(&rest[..i]), Some(RawStr::new(&rest[i + 1..]))),
+			None => (rest, None),
+		};
+
+		UrlComponents { scheme, authority, path, query, fragment }
+	}
+}
+
+impl RawString {
+	/// Splits the URL in the [`RawString`] into its components at their delimiters.
+	///
+	/// See [`RawStr::split_url_components`].
+	#[inline]
+	#[must_use]
+	pub fn split_url_components(&self) -> UrlComponents<'_> {
+		self.as_ref().split_url_components()
+	}
+}
@@ -0,0 +1,57 @@
+// rawstring::utf8
+
+//! Crate-internal helpers for decoding one unit at a time.
+//!
+//! A unit is either a valid character or a maximal invalid sequence,
+//! the same segmentation used by [`<[u8]>::utf8_chunks`] and lossy decoding.
+
+/// Decodes the first unit of `bytes`.
+///
+/// Returns the decoded character, or the invalid sequence, or `None` if `bytes` is empty.
+pub(crate) fn decode_first(bytes: &[u8]) -> Option<Result<char, &[u8]>> {
+	let window = &bytes[..bytes.len().min(4)];
+	let chunk = window.utf8_chunks().next()?;
+	match chunk.valid().chars().next() {
+		Some(c) => Some(Ok(c)),
+		None => Some(Err(chunk.invalid())),
+	}
+}
+
+/// Decodes the last unit of `bytes`.
+///
+/// Returns the decoded character, or the invalid sequence, or `None` if `bytes` is empty.
+pub(crate) fn decode_last(bytes: &[u8]) -> Option<Result<char, &[u8]>> {
+	let last = bytes.len().checked_sub(1)?;
+	// units never span a non-continuation byte, so decoding forward from the last one
+	// reproduces the segmentation of the whole slice; a continuation byte with no
+	// lead byte within reach is an invalid unit on its own
+	let start = (bytes.len().saturating_sub(4)..bytes.len())
+		.rev()
+		.find(|&i| !is_continuation(bytes[i]))
+		.unwrap_or(last);
+
+	let mut rest = &bytes[start..];
+	loop {
+		let unit = decode_first(rest)?;
+		let len = unit_len(unit);
+		if len == rest.len() {
+			return Some(unit);
+		}
+		rest = &rest[len..];
+	}
+}
+
+/// Returns the number of bytes in a decoded unit.
+#[inline]
+pub(crate) fn unit_len(unit: Result<char, &[u8]>) -> usize {
+	match unit {
+		Ok(c) => c.len_utf8(),
+		Err(invalid) => invalid.len(),
+	}
+}
+
+/// Returns `true` if `byte` is a UTF-8 continuation byte.
+#[inline]
+pub(crate) const fn is_continuation(byte: u8) -> bool {
+	byte & 0xC0 == 0x80
+}
@@ -0,0 +1,236 @@
+// rawstring::utf8_raw_string_imp
+
+use std::{
+	borrow::Borrow,
+	fmt,
+	ops::Deref,
+	str::Utf8Error,
+	string::FromUtf8Error,
+};
+
+use crate::{RawStr, RawString};
+
+/// A growable string that is guaranteed to contain valid UTF-8.
+///
+/// [`Utf8RawString`] is a [`RawString`] whose contents have been validated.
+/// Every way of modifying it preserves that invariant, so [`as_str`](Self::as_str)
+/// never has to validate again. It converts into a [`RawString`] or [`String`] for free.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{RawStr, RawString, Utf8RawString};
+/// let mut text = Utf8RawString::new();
+/// text.try_push_utf8(RawStr::new("héllo")).unwrap();
+/// assert!(text.try_push_utf8(RawStr::new(b"\xFF")).is_err());
+/// assert_eq!(text.as_str(), "héllo");
+///
+/// let raw: RawString = text.into();
+/// assert_eq!(raw, RawString::from("héllo"));
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Utf8RawString(String);
+
+impl Utf8RawString {
+	/// Creates a new, empty [`Utf8RawString`].
+	#[inline]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(String::new())
+	}
+
+	/// Validates a [`RawString`] and wraps it in a [`Utf8RawString`] without copying.
+	///
+	/// See [`String::from_utf8`].
+	#[inline]
+	pub fn from_raw_string(raw: RawString) -> Result<Self, FromUtf8Error> {
+		raw.to_utf8_checked().map(Self)
+	}
+
+	/// Returns the contents as a [`str`].
+	#[inline]
+	#[must_use]
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+
+	/// Returns the contents as a [`RawStr`].
+	#[inline]
+	#[must_use]
+	pub fn as_raw_str(&self) -> &RawStr {
+		RawStr::new(&self.0)
+	}
+
+	/// Converts the [`Utf8RawString`] into a [`RawString`] without copying.
+	#[inline]
+	#[must_use]
+	pub fn into_raw_string(self) -> RawString {
+		RawString::from(self.0)
+	}
+
+	/// Converts the [`Utf8RawString`] into a [`String`] without copying.
+	#[inline]
+	#[must_use]
+	pub fn into_string(self) -> String {
+		self.0
+	}
+
+	/// Appends a string slice.
+	#[inline]
+	pub fn push_str(&mut self, s: &str) {
+		self.0.push_str(s);
+	}
+
+	/// Appends a character.
+	#[inline]
+	pub fn push(&mut self, c: char) {
+		self.0.push(c);
+	}
+
+	/// Appends `raw` if it is valid UTF-8.
+	///
+	/// # Errors
+	/// Returns a [`Utf8Error`], leaving the string unchanged, if `raw` is not valid UTF-8.
+	#[inline]
+	pub fn try_push_utf8(&mut self, raw: &RawStr) -> Result<(), Utf8Error> {
+		self.0.push_str(raw.to_utf8_checked()?);
+		Ok(())
+	}
+
+	/// Prepends `raw` if it is valid UTF-8.
+	///
+	/// # Errors
+	/// Returns a [`Utf8Error`], leaving the string unchanged, if `raw` is not valid UTF-8.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, Utf8RawString};
+	/// let mut text = Utf8RawString::from("world");
+	/// text.try_prepend_utf8(RawStr::new("hello ")).unwrap();
+	/// assert_eq!(text.as_str(), "hello world");
+	/// ```
+	#[inline]
+	pub fn try_prepend_utf8(&mut self, raw: &RawStr) -> Result<(), Utf8Error> {
+		self.0.insert_str(0, raw.to_utf8_checked()?);
+		Ok(())
+	}
+
+	/// Truncates the string to `new_len` bytes.
+	///
+	/// # Panics
+	/// Panics if `new_len` does not lie on a character boundary.
+	#[inline]
+	pub fn truncate(&mut self, new_len: usize) {
+		self.0.truncate(new_len);
+	}
+
+	/// Removes all contents, keeping the allocated capacity.
+	#[inline]
+	pub fn clear(&mut self) {
+		self.0.clear();
+	}
+}
+
+impl Deref for Utf8RawString {
+	type Target = str;
+
+	#[inline]
+	fn deref(&self) -> &str {
+		&self.0
+	}
+}
+
+impl AsRef<str> for Utf8RawString {
+	#[inline]
+	fn as_ref(&self) -> &str {
+		&self.0
+	}
+}
+
+impl AsRef<[u8]> for Utf8RawString {
+	#[inline]
+	fn as_ref(&self) -> &[u8] {
+		self.0.as_bytes()
+	}
+}
+
+impl AsRef<RawStr> for Utf8RawString {
+	#[inline]
+	fn as_ref(&self) -> &RawStr {
+		self.as_raw_str()
+	}
+}
+
+impl Borrow<str> for Utf8RawString {
+	#[inline]
+	fn borrow(&self) -> &str {
+		&self.0
+	}
+}
+
+impl fmt::Debug for Utf8RawString {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.as_raw_str().fmt(f)
+	}
+}
+
+impl fmt::Display for Utf8RawString {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(&self.0, f)
+	}
+}
+
+impl fmt::Write for Utf8RawString {
+	#[inline]
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.0.push_str(s);
+		Ok(())
+	}
+}
+
+impl From<String> for Utf8RawString {
+	#[inline]
+	fn from(s: String) -> Self {
+		Self(s)
+	}
+}
+
+impl From<&str> for Utf8RawString {
+	#[inline]
+	fn from(s: &str) -> Self {
+		Self(s.to_owned())
+	}
+}
+
+impl From<Utf8RawString> for Vec<u8> {
+	#[inline]
+	fn from(s: Utf8RawString) -> Self {
+		s.0.into_bytes()
+	}
+}
+
+impl From<Utf8RawString> for String {
+	#[inline]
+	fn from(s: Utf8RawString) -> Self {
+		s.0
+	}
+}
+
+impl TryFrom<RawString> for Utf8RawString {
+	type Error = FromUtf8Error;
+
+	#[inline]
+	fn try_from(raw: RawString) -> Result<Self, Self::Error> {
+		Self::from_raw_string(raw)
+	}
+}
+
+impl<'a> TryFrom<&'a RawStr> for Utf8RawString {
+	type Error = Utf8Error;
+
+	#[inline]
+	fn try_from(raw: &'a RawStr) -> Result<Self, Self::Error> {
+		raw.to_utf8_checked().map(Self::from)
+	}
+}
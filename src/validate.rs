@@ -0,0 +1,229 @@
+// rawstring::validate
+
+use std::{borrow::Cow, fmt, iter::FusedIterator, ops::Range, str::Utf8Chunks};
+
+use crate::{RawStr, RawString};
+
+/// Why a region of a [`RawStr`] is not valid UTF-8.
+///
+/// Yielded by [`RawStr::utf8_error_positions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Utf8ErrorKind {
+	/// A byte that can never start a sequence: a continuation byte or `0xF5..=0xFF`.
+	InvalidStartByte,
+	/// A sequence that encodes a character in more bytes than necessary.
+	Overlong,
+	/// A sequence that encodes a UTF-16 surrogate (`U+D800..=U+DFFF`).
+	Surrogate,
+	/// A sequence that encodes a value above `U+10FFFF`.
+	OutOfRange,
+	/// A sequence cut short by the end of the input or by a byte that does not continue it.
+	Truncated,
+}
+
+impl Utf8ErrorKind {
+	/// Classifies the invalid sequence `invalid`, followed by `next` if there is another byte.
+	pub(crate) fn classify(invalid: &[u8], next: Option<u8>) -> Self {
+		match (invalid[0], invalid.len(), next) {
+			(0x80..=0xBF | 0xF5..=0xFF, ..) => Self::InvalidStartByte,
+			(0xC0 | 0xC1, ..) => Self::Overlong,
+			(0xE0, 1, Some(0x80..=0x9F)) | (0xF0, 1, Some(0x80..=0x8F)) => Self::Overlong,
+			(0xED, 1, Some(0xA0..=0xBF)) => Self::Surrogate,
+			(0xF4, 1, Some(0x90..=0xBF)) => Self::OutOfRange,
+			_ => Self::Truncated,
+		}
+	}
+}
+
+impl fmt::Display for Utf8ErrorKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::InvalidStartByte => "invalid start byte",
+			Self::Overlong => "overlong encoding",
+			Self::Surrogate => "encoded surrogate",
+			Self::OutOfRange => "code point above U+10FFFF",
+			Self::Truncated => "truncated sequence",
+		})
+	}
+}
+
+impl RawStr {
+	/// Returns an iterator over the regions of the [`RawStr`] that are not valid UTF-8, with the reason for each.
+	///
+	/// Regions follow the same segmentation as lossy decoding, so each one
+	/// corresponds to one [`UNICODE_REPLACEMENT_CHARACTER`](crate::UNICODE_REPLACEMENT_CHARACTER)
+	/// in the output of [`RawStr::to_utf8_lossy`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, Utf8ErrorKind};
+	/// let raw = RawStr::new(b"ok \xC0\xAF \xED\xA0\x80 \xE2\x82");
+	/// let errors: Vec<_> = raw.utf8_error_positions().collect();
+	/// assert_eq!(errors[0], (3..4, Utf8ErrorKind::Overlong));
+	/// assert_eq!(errors[1], (4..5, Utf8ErrorKind::InvalidStartByte));
+	/// assert_eq!(errors[2], (6..7, Utf8ErrorKind::Surrogate));
+	/// assert_eq!(errors.last(), Some(&(10..12, Utf8ErrorKind::Truncated)));
+	/// assert_eq!(errors[0].1.to_string(), "overlong encoding");
+	///
+	/// assert_eq!(RawStr::new("valid").utf8_error_positions().next(), None);
+	/// ```
+	#[inline]
+	pub fn utf8_error_positions(&self) -> Utf8ErrorPositions<'_> {
+		Utf8ErrorPositions { bytes: &self.0, chunks: self.0.utf8_chunks(), offset: 0 }
+	}
+
+	/// Lossily converts the [`RawStr`] to a [`str`] like [`RawStr::to_utf8_lossy`], calling `on_replace`
+	/// with the byte range and kind of each invalid region as it is replaced.
+	///
+	/// `on_replace` is called once per replacement character, in order, and not at all if the
+	/// [`RawStr`] is valid UTF-8. Unlike [`RawStr::to_utf8_lossy`], this never panics in `strict` mode,
+	/// since the caller is handling the replacements.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, Utf8ErrorKind};
+	/// let mut incidents = Vec::new();
+	/// let text = RawStr::new(b"id=\xFF\xFE name=caf\xE9").to_utf8_lossy_with(|range, kind| incidents.push((range, kind)));
+	/// assert_eq!(text, "id=\u{FFFD}\u{FFFD} name=caf\u{FFFD}");
+	/// assert_eq!(incidents, [
+	///     (3..4, Utf8ErrorKind::InvalidStartByte),
+	///     (4..5, Utf8ErrorKind::InvalidStartByte),
+	///     (14..15, Utf8ErrorKind::Truncated),
+	/// ]);
+	/// ```
+	pub fn to_utf8_lossy_with<F>(&self, mut on_replace: F) -> Cow<'_, str>
+	where
+		F: FnMut(Range<usize>, Utf8ErrorKind)
+	{
+		let mut errors = self.utf8_error_positions().peekable();
+		if errors.peek().is_none() {
+			// SAFETY: there are no invalid regions
+			return Cow::Borrowed(unsafe { str::from_utf8_unchecked(&self.0) });
+		}
+
+		let mut text = String::with_capacity(self.len() + 2);
+		let mut last = 0;
+		for (range, kind) in errors {
+			// SAFETY: the bytes between invalid regions are valid UTF-8
+			text.push_str(unsafe { str::from_utf8_unchecked(&self.0[last..range.start]) });
+			text.push(crate::UNICODE_REPLACEMENT_CHARACTER);
+			last = range.end;
+			on_replace(range, kind);
+		}
+		// SAFETY: the bytes after the last invalid region are valid UTF-8
+		text.push_str(unsafe { str::from_utf8_unchecked(&self.0[last..]) });
+		Cow::Owned(text)
+	}
+
+	/// Returns the length of the longest prefix of the [`RawStr`] that is valid UTF-8.
+	///
+	/// This is the whole length if the [`RawStr`] is valid UTF-8. See [`Utf8Error::valid_up_to`](std::str::Utf8Error::valid_up_to).
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new(b"caf\xC3\xA9\xFF!").valid_up_to(), 5);
+	/// assert_eq!(RawStr::new("caf\u{e9}").valid_up_to(), 5);
+	/// assert_eq!(RawStr::new(b"\x80").valid_up_to(), 0);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn valid_up_to(&self) -> usize {
+		match str::from_utf8(&self.0) {
+			Ok(_) => self.0.len(),
+			Err(err) => err.valid_up_to(),
+		}
+	}
+
+	/// Splits the [`RawStr`] into its longest valid UTF-8 prefix and the remaining bytes.
+	///
+	/// The tail is empty if the [`RawStr`] is valid UTF-8. When reading a stream, the tail
+	/// may be the start of a character cut off by the end of a chunk, so it should be carried
+	/// over to the next chunk rather than treated as an error.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// // the euro sign is cut off after its first two bytes
+	/// let chunk = RawStr::new(b"price: \xE2\x82");
+	/// let (text, tail) = chunk.utf8_prefix();
+	/// assert_eq!(text, "price: ");
+	/// assert_eq!(tail, RawStr::new(b"\xE2\x82"));
+	///
+	/// assert_eq!(RawStr::new("ok").utf8_prefix(), ("ok", RawStr::new("")));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub const fn utf8_prefix(&self) -> (&str, &RawStr) {
+		let (head, tail) = self.0.split_at(self.valid_up_to());
+		// SAFETY: `head` is the valid UTF-8 prefix
+		(unsafe { str::from_utf8_unchecked(head) }, RawStr::new(tail))
+	}
+}
+
+impl RawString {
+	/// Lossily converts the [`RawString`] to a [`str`], calling `on_replace` with the byte range
+	/// and kind of each invalid region as it is replaced.
+	///
+	/// See [`RawStr::to_utf8_lossy_with`].
+	#[inline]
+	pub fn to_utf8_lossy_with<F>(&self, on_replace: F) -> Cow<'_, str>
+	where
+		F: FnMut(Range<usize>, Utf8ErrorKind)
+	{
+		self.as_ref().to_utf8_lossy_with(on_replace)
+	}
+
+	/// Returns the length of the longest prefix of the [`RawString`] that is valid UTF-8.
+	///
+	/// See [`RawStr::valid_up_to`].
+	#[inline]
+	#[must_use]
+	pub fn valid_up_to(&self) -> usize {
+		self.as_ref().valid_up_to()
+	}
+
+	/// Splits the [`RawString`] into its longest valid UTF-8 prefix and the remaining bytes.
+	///
+	/// See [`RawStr::utf8_prefix`].
+	#[inline]
+	#[must_use]
+	pub fn utf8_prefix(&self) -> (&str, &RawStr) {
+		self.as_ref().utf8_prefix()
+	}
+}
+
+/// An iterator over the invalid UTF-8 regions of a [`RawStr`].
+///
+/// Created by [`RawStr::utf8_error_positions`].
+#[derive(Debug, Clone)]
+pub struct Utf8ErrorPositions<'a> {
+	bytes: &'a [u8],
+	chunks: Utf8Chunks<'a>,
+	offset: usize,
+}
+
+impl Iterator for Utf8ErrorPositions<'_> {
+	type Item = (Range<usize>, Utf8ErrorKind);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let chunk = self.chunks.next()?;
+			let start = self.offset + chunk.valid().len();
+			let invalid = chunk.invalid();
+			self.offset = start + invalid.len();
+			if !invalid.is_empty() {
+				let kind = Utf8ErrorKind::classify(invalid, self.bytes.get(self.offset).copied());
+				return Some((start..self.offset, kind));
+			}
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.bytes.len() - self.offset))
+	}
+}
+
+impl FusedIterator for Utf8ErrorPositions<'_> {}
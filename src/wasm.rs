@@ -0,0 +1,84 @@
+// rawstring::wasm
+
+use js_sys::{JsString, Uint8Array};
+use wasm_bindgen::JsValue;
+
+use crate::{RawStr, RawString};
+
+/// Copies the [`RawStr`] into a new `Uint8Array`. Requires the `wasm-bindgen` feature.
+impl From<&RawStr> for Uint8Array {
+	#[inline]
+	fn from(raw: &RawStr) -> Self {
+		Uint8Array::from(&raw.0)
+	}
+}
+
+/// Copies the [`RawString`] into a new `Uint8Array`. Requires the `wasm-bindgen` feature.
+impl From<RawString> for Uint8Array {
+	#[inline]
+	fn from(raw: RawString) -> Self {
+		Uint8Array::from(raw.0.as_slice())
+	}
+}
+
+/// Converts the [`RawString`] into a JavaScript `Uint8Array`, so it can be returned
+/// from `#[wasm_bindgen]` functions. Requires the `wasm-bindgen` feature.
+impl From<RawString> for JsValue {
+	#[inline]
+	fn from(raw: RawString) -> Self {
+		Uint8Array::from(raw).into()
+	}
+}
+
+impl RawString {
+	/// Copies the contents of a JavaScript `Uint8Array` into a new [`RawString`].
+	///
+	/// Requires the `wasm-bindgen` feature.
+	///
+	/// # Examples
+	/// ```no_run
+	/// # use rawstring::RawString;
+	/// use js_sys::Uint8Array;
+	///
+	/// let array = Uint8Array::from(&b"caf\xE9"[..]);
+	/// let raw = RawString::from_uint8_array(&array);
+	/// assert_eq!(raw, RawString::from(b"caf\xE9".as_slice()));
+	/// assert_eq!(RawString::from_uint8_array(&Uint8Array::from(raw.clone())), raw);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn from_uint8_array(array: &Uint8Array) -> RawString {
+		RawString(array.to_vec())
+	}
+
+	/// Encodes a JavaScript string as UTF-8.
+	///
+	/// JavaScript strings may contain unpaired surrogates, which are replaced with `�`.
+	/// Requires the `wasm-bindgen` feature.
+	#[must_use]
+	pub fn from_js_string_lossy(s: &JsString) -> RawString {
+		let s: String = char::decode_utf16(s.iter())
+			.map(|c| c.unwrap_or(crate::UNICODE_REPLACEMENT_CHARACTER))
+			.collect();
+		RawString::from(s)
+	}
+}
+
+impl RawStr {
+	/// Lossily converts the [`RawStr`] to a JavaScript string.
+	///
+	/// Invalid UTF-8 sequences are replaced with `�`, as by [`RawStr::to_utf8_lossy`].
+	/// Requires the `wasm-bindgen` feature.
+	///
+	/// # Examples
+	/// ```no_run
+	/// # use rawstring::RawStr;
+	/// let js = RawStr::new(b"caf\xE9").to_js_string_lossy();
+	/// assert_eq!(String::from(js), "caf�");
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn to_js_string_lossy(&self) -> JsString {
+		JsString::from(&*self.to_utf8_lossy())
+	}
+}
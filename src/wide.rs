@@ -0,0 +1,429 @@
+// rawstring::wide
+
+use std::{
+	borrow::{Borrow, BorrowMut},
+	char::{self, DecodeUtf16, DecodeUtf16Error},
+	cmp::Ordering,
+	fmt::{self, Write},
+	iter::Copied,
+	ops::{Deref, DerefMut},
+	slice,
+};
+
+use crate::{RawStr, RawString, UNICODE_REPLACEMENT_CHARACTER, utf8};
+
+/// A borrowed string of UTF-16 code units that may or may not be well-formed.
+///
+/// This is the UTF-16 counterpart of [`RawStr`], for data such as Windows file names and
+/// JavaScript strings, which may contain unpaired surrogates. Like [`RawStr`], it derefs
+/// to its units, displays lossily and debugs with escapes.
+///
+/// Converting to a [`RawString`] uses [WTF-8](https://simonsapin.github.io/wtf-8/),
+/// which encodes unpaired surrogates like other code points, so nothing is lost.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{RawString, RawWideStr};
+/// let units = [0x68, 0x69, 0xD800, 0x2764];
+/// let wide = RawWideStr::new(&units);
+/// assert!(!wide.is_utf16());
+/// # #[cfg(not(feature = "strict"))]
+/// assert_eq!(wide.to_string(), "hi\u{FFFD}\u{2764}");
+/// assert_eq!(format!("{wide:?}"), "\"hi\\u{d800}\u{2764}\"");
+///
+/// let wtf8 = RawString::from(wide);
+/// assert_eq!(wtf8, RawString::from(b"hi\xED\xA0\x80\xE2\x9D\xA4"));
+/// ```
+#[repr(transparent)]
+#[derive(Eq, Hash)] // PartialEq, PartialOrd, Ord implemented manually
+pub struct RawWideStr(pub [u16]);
+
+/// An owned, growable string of UTF-16 code units that may or may not be well-formed.
+///
+/// This is the UTF-16 counterpart of [`RawString`], and derefs to [`Vec<u16>`].
+/// See [`RawWideStr`].
+///
+/// # Examples
+/// ```
+/// # use rawstring::{RawString, RawWideString};
+/// let mut wide = RawWideString::from("caf\u{e9}");
+/// wide.push(0xDC00);
+/// assert_eq!(wide.len(), 5);
+///
+/// // unpaired surrogates survive a round trip through WTF-8
+/// let wtf8 = wide.to_wtf8();
+/// assert_eq!(wtf8, RawString::from(b"caf\xC3\xA9\xED\xB0\x80"));
+/// assert_eq!(RawWideString::from_wtf8_lossy(wtf8.as_ref()), wide);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct RawWideString(pub Vec<u16>);
+
+impl RawWideStr {
+	/// Returns a reference to a [`RawWideStr`] from any type that can be referenced as a slice of units.
+	#[inline]
+	#[must_use]
+	pub fn new<U>(units: &U) -> &Self
+	where
+		U: ?Sized + AsRef<[u16]>
+	{
+		Self::from_units(units.as_ref())
+	}
+
+	/// Reinterprets the given units as a [`RawWideStr`].
+	#[inline]
+	#[must_use]
+	pub const fn from_units(units: &[u16]) -> &Self {
+		// SAFETY: RawWideStr is a transparent wrapper over [u16]
+		unsafe { &*(units as *const [u16] as *const RawWideStr) }
+	}
+
+	/// Reinterprets the given mutable units as a mutable [`RawWideStr`].
+	#[inline]
+	#[must_use]
+	pub const fn from_units_mut(units: &mut [u16]) -> &mut Self {
+		// SAFETY: RawWideStr is a transparent wrapper over [u16]
+		unsafe { &mut *(units as *mut [u16] as *mut RawWideStr) }
+	}
+
+	/// Returns `true` if the [`RawWideStr`] is well-formed UTF-16, with no unpaired surrogates.
+	#[inline]
+	#[must_use]
+	pub fn is_utf16(&self) -> bool {
+		self.chars().all(|c| c.is_ok())
+	}
+
+	/// Returns an iterator over the characters of the [`RawWideStr`],
+	/// with an error for each unpaired surrogate.
+	#[inline]
+	pub fn chars(&self) -> DecodeUtf16<Copied<slice::Iter<'_, u16>>> {
+		char::decode_utf16(self.0.iter().copied())
+	}
+
+	/// Converts the [`RawWideStr`] to a [`String`] if it is well-formed UTF-16.
+	///
+	/// # Errors
+	/// Returns a [`DecodeUtf16Error`] for the first unpaired surrogate.
+	#[inline]
+	pub fn to_utf8_checked(&self) -> Result<String, DecodeUtf16Error> {
+		self.chars().collect()
+	}
+
+	/// Lossily converts the [`RawWideStr`] to a [`String`].
+	/// Unpaired surrogates are replaced with the replacement character (�).
+	///
+	/// # Panics
+	/// With the `strict` feature in debug builds, panics if the [`RawWideStr`] is not well-formed UTF-16.
+	#[inline]
+	#[must_use]
+	#[track_caller]
+	pub fn to_utf8_lossy(&self) -> String {
+		crate::strict::check_lossless_wide(&self.0, "to_utf8_lossy");
+		self.chars().map(|c| c.unwrap_or(UNICODE_REPLACEMENT_CHARACTER)).collect()
+	}
+
+	/// Converts the [`RawWideStr`] to WTF-8, which is UTF-8 extended to encode unpaired
+	/// surrogates as three-byte sequences.
+	///
+	/// The result is valid UTF-8 if and only if the [`RawWideStr`] is well-formed UTF-16.
+	#[must_use]
+	pub fn to_wtf8(&self) -> RawString {
+		let mut wtf8 = RawString::from(Vec::with_capacity(self.len()));
+		for c in self.chars() {
+			match c {
+				Ok(c) => wtf8.push_char(c),
+				Err(err) => {
+					let unit = err.unpaired_surrogate();
+					wtf8.extend_from_slice(&[
+						0xE0 | (unit >> 12) as u8,
+						0x80 | ((unit >> 6) & 0x3F) as u8,
+						0x80 | (unit & 0x3F) as u8,
+					]);
+				}
+			}
+		}
+		wtf8
+	}
+}
+
+impl RawWideString {
+	/// Creates a new, empty [`RawWideString`].
+	#[inline]
+	#[must_use]
+	pub const fn new() -> Self {
+		Self(Vec::new())
+	}
+
+	/// Returns a reference to the units as a [`RawWideStr`].
+	#[inline]
+	#[must_use]
+	#[allow(clippy::should_implement_trait)]
+	pub fn as_ref(&self) -> &RawWideStr {
+		RawWideStr::from_units(&self.0)
+	}
+
+	/// Converts WTF-8 to UTF-16, the inverse of [`RawWideStr::to_wtf8`].
+	///
+	/// Encoded surrogates become unpaired surrogates. Other invalid sequences, which have no
+	/// UTF-16 equivalent, are replaced with `U+FFFD` using the same segmentation as
+	/// [`RawStr::to_utf8_lossy`].
+	#[must_use]
+	pub fn from_wtf8_lossy(wtf8: &RawStr) -> Self {
+		let mut units = Vec::with_capacity(wtf8.len());
+		let mut rest = &wtf8.0;
+		while let Some(unit) = utf8::decode_first(rest) {
+			let len = match (unit, rest) {
+				(Ok(c), _) => {
+					units.extend_from_slice(c.encode_utf16(&mut [0; 2]));
+					c.len_utf8()
+				}
+				(Err(_), &[0xED, b1 @ 0xA0..=0xBF, b2 @ 0x80..=0xBF, ..]) => {
+					units.push(0xD000 | (u16::from(b1 & 0x3F) << 6) | u16::from(b2 & 0x3F));
+					3
+				}
+				(Err(invalid), _) => {
+					units.push(UNICODE_REPLACEMENT_CHARACTER as u16);
+					invalid.len()
+				}
+			};
+			rest = &rest[len..];
+		}
+		Self(units)
+	}
+
+	/// Converts the [`RawWideString`] to WTF-8. See [`RawWideStr::to_wtf8`].
+	#[inline]
+	#[must_use]
+	pub fn to_wtf8(&self) -> RawString {
+		self.as_ref().to_wtf8()
+	}
+
+	/// Lossily converts the [`RawWideString`] to a [`String`]. See [`RawWideStr::to_utf8_lossy`].
+	///
+	/// # Panics
+	/// With the `strict` feature in debug builds, panics if the [`RawWideString`] is not well-formed UTF-16.
+	#[inline]
+	#[must_use]
+	#[track_caller]
+	pub fn to_utf8_lossy(&self) -> String {
+		self.as_ref().to_utf8_lossy()
+	}
+
+	/// Returns `true` if the [`RawWideString`] is well-formed UTF-16. See [`RawWideStr::is_utf16`].
+	#[inline]
+	#[must_use]
+	pub fn is_utf16(&self) -> bool {
+		self.as_ref().is_utf16()
+	}
+}
+
+impl Deref for RawWideStr {
+	type Target = [u16];
+
+	#[inline]
+	fn deref(&self) -> &[u16] {
+		&self.0
+	}
+}
+
+impl DerefMut for RawWideStr {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut [u16] {
+		&mut self.0
+	}
+}
+
+impl AsRef<[u16]> for RawWideStr {
+	#[inline]
+	fn as_ref(&self) -> &[u16] {
+		&self.0
+	}
+}
+
+impl AsRef<RawWideStr> for RawWideStr {
+	#[inline]
+	fn as_ref(&self) -> &RawWideStr {
+		self
+	}
+}
+
+impl ToOwned for RawWideStr {
+	type Owned = RawWideString;
+
+	#[inline]
+	fn to_owned(&self) -> RawWideString {
+		RawWideString(self.0.to_vec())
+	}
+}
+
+impl Deref for RawWideString {
+	type Target = Vec<u16>;
+
+	#[inline]
+	fn deref(&self) -> &Vec<u16> {
+		&self.0
+	}
+}
+
+impl DerefMut for RawWideString {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Vec<u16> {
+		&mut self.0
+	}
+}
+
+impl AsRef<[u16]> for RawWideString {
+	#[inline]
+	fn as_ref(&self) -> &[u16] {
+		&self.0
+	}
+}
+
+impl AsRef<RawWideStr> for RawWideString {
+	#[inline]
+	fn as_ref(&self) -> &RawWideStr {
+		RawWideStr::from_units(&self.0)
+	}
+}
+
+impl Borrow<RawWideStr> for RawWideString {
+	#[inline]
+	fn borrow(&self) -> &RawWideStr {
+		RawWideStr::from_units(&self.0)
+	}
+}
+
+impl BorrowMut<RawWideStr> for RawWideString {
+	#[inline]
+	fn borrow_mut(&mut self) -> &mut RawWideStr {
+		RawWideStr::from_units_mut(&mut self.0)
+	}
+}
+
+impl From<Vec<u16>> for RawWideString {
+	#[inline]
+	fn from(units: Vec<u16>) -> Self {
+		Self(units)
+	}
+}
+
+impl From<&[u16]> for RawWideString {
+	#[inline]
+	fn from(units: &[u16]) -> Self {
+		Self(units.to_vec())
+	}
+}
+
+impl From<&RawWideStr> for RawWideString {
+	#[inline]
+	fn from(wide: &RawWideStr) -> Self {
+		wide.to_owned()
+	}
+}
+
+impl From<&str> for RawWideString {
+	#[inline]
+	fn from(s: &str) -> Self {
+		Self(s.encode_utf16().collect())
+	}
+}
+
+impl From<RawWideString> for Vec<u16> {
+	#[inline]
+	fn from(wide: RawWideString) -> Self {
+		wide.0
+	}
+}
+
+/// Converts to WTF-8, so that [`RawString::from`] accepts a [`RawWideStr`]. See [`RawWideStr::to_wtf8`].
+impl From<&RawWideStr> for Vec<u8> {
+	#[inline]
+	fn from(wide: &RawWideStr) -> Self {
+		wide.to_wtf8().0
+	}
+}
+
+/// Converts to WTF-8, so that [`RawString::from`] accepts a [`RawWideString`]. See [`RawWideStr::to_wtf8`].
+impl From<RawWideString> for Vec<u8> {
+	#[inline]
+	fn from(wide: RawWideString) -> Self {
+		wide.to_wtf8().0
+	}
+}
+
+impl<T: ?Sized + AsRef<[u16]>> PartialEq<T> for RawWideStr {
+	#[inline]
+	fn eq(&self, other: &T) -> bool {
+		self.0 == *other.as_ref()
+	}
+}
+
+impl<T: ?Sized + AsRef<[u16]>> PartialOrd<T> for RawWideStr {
+	#[inline]
+	fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+		Some(self.0.cmp(other.as_ref()))
+	}
+}
+
+impl Ord for RawWideStr {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.0.cmp(&other.0)
+	}
+}
+
+impl PartialEq<RawWideStr> for RawWideString {
+	#[inline]
+	fn eq(&self, other: &RawWideStr) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl PartialEq<&RawWideStr> for RawWideString {
+	#[inline]
+	fn eq(&self, other: &&RawWideStr) -> bool {
+		self.0 == other.0
+	}
+}
+
+/// Formats the units as an escaped string literal, with unpaired surrogates written as `\u{...}`.
+impl fmt::Debug for RawWideStr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_char('"')?;
+		for c in self.chars() {
+			match c {
+				Ok('\0') => f.write_str("\\0")?,
+				Ok(c @ '\x01'..='\x7F') => write!(f, "{}", (c as u8).escape_ascii())?,
+				Ok(c) => write!(f, "{}", c.escape_debug())?,
+				Err(err) => write!(f, "\\u{{{:x}}}", err.unpaired_surrogate())?,
+			}
+		}
+		f.write_char('"')
+	}
+}
+
+/// Formats the units as text, replacing unpaired surrogates with the replacement character.
+///
+/// With the `strict` feature in debug builds, panics if the units are not well-formed UTF-16.
+impl fmt::Display for RawWideStr {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		crate::strict::check_lossless_wide(&self.0, "Display");
+		let text: String = self.chars().map(|c| c.unwrap_or(UNICODE_REPLACEMENT_CHARACTER)).collect();
+		f.pad(&text)
+	}
+}
+
+impl fmt::Debug for RawWideString {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(self.as_ref(), f)
+	}
+}
+
+impl fmt::Display for RawWideString {
+	#[inline]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(self.as_ref(), f)
+	}
+}
@@ -0,0 +1,41 @@
+// rawstring::width
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::RawStr;
+
+/// Returns the number of terminal columns a decoded unit occupies when displayed.
+///
+/// Invalid sequences are displayed as a single replacement character.
+#[inline]
+pub(crate) fn unit_width(unit: Result<char, &[u8]>) -> usize {
+	match unit {
+		Ok(c) => c.width().unwrap_or(0),
+		Err(_) => 1,
+	}
+}
+
+impl RawStr {
+	/// Returns the number of terminal columns the [`RawStr`] occupies when displayed.
+	///
+	/// Wide characters (such as CJK ideographs) count as two columns,
+	/// combining and control characters as zero,
+	/// and each invalid sequence as one column for its replacement character.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// assert_eq!(RawStr::new("abc").display_width(), 3);
+	/// assert_eq!(RawStr::new("日本").display_width(), 4);
+	/// assert_eq!(RawStr::new(b"a\xFF\xFEb").display_width(), 4);
+	/// ```
+	#[must_use]
+	pub fn display_width(&self) -> usize {
+		self.utf8_chunks()
+			.map(|chunk| {
+				let valid: usize = chunk.valid().chars().map(|c| unit_width(Ok(c))).sum();
+				valid + chunk.invalid().len().min(1)
+			})
+			.sum()
+	}
+}
@@ -0,0 +1,149 @@
+// rawstring::wrap
+
+use std::iter::FusedIterator;
+
+use crate::{RawStr, utf8, width};
+
+/// Options controlling how [`RawStr::wrap_with`] breaks lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WrapOptions {
+	/// The maximum display width of a line, in columns.
+	pub width: usize,
+	/// Whether words wider than a line may be broken between characters.
+	/// Otherwise such words are placed on a line of their own, exceeding the width.
+	pub break_anywhere: bool,
+	/// The number of columns reserved for indentation on every line after the first.
+	///
+	/// The indentation is not included in the returned lines;
+	/// callers print it themselves, which reduces the width available to those lines.
+	pub hanging_indent: usize,
+}
+
+impl WrapOptions {
+	/// Creates options wrapping at `width` columns without breaking words or indenting.
+	#[inline]
+	#[must_use]
+	pub const fn new(width: usize) -> Self {
+		Self { width, break_anywhere: false, hanging_indent: 0 }
+	}
+}
+
+impl RawStr {
+	/// Wraps the [`RawStr`] into lines no wider than `width` display columns,
+	/// breaking at spaces and tabs.
+	///
+	/// See [`RawStr::wrap_with`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::RawStr;
+	/// let text = RawStr::new(b"the quick brown \xFF\xFF fox");
+	/// let lines: Vec<_> = text.wrap(10).collect();
+	/// assert_eq!(lines, [
+	///     RawStr::new("the quick"),
+	///     RawStr::new(b"brown \xFF\xFF"),
+	///     RawStr::new("fox"),
+	/// ]);
+	/// ```
+	#[inline]
+	pub fn wrap(&self, width: usize) -> Wrap<'_> {
+		self.wrap_with(WrapOptions::new(width))
+	}
+
+	/// Wraps the [`RawStr`] into lines according to `options`.
+	///
+	/// Lines are broken at runs of spaces and tabs, which are not included in the returned lines,
+	/// and existing `\n` line breaks are preserved. Display width is measured as by
+	/// [`RawStr::display_width`], and lines are never broken inside a valid multi-byte character.
+	///
+	/// # Examples
+	/// ```
+	/// # use rawstring::{RawStr, WrapOptions};
+	/// let text = RawStr::new("abcdefgh ij");
+	/// let options = WrapOptions { break_anywhere: true, hanging_indent: 2, ..WrapOptions::new(5) };
+	/// let lines: Vec<_> = text.wrap_with(options).collect();
+	/// assert_eq!(lines, [RawStr::new("abcde"), RawStr::new("fgh"), RawStr::new("ij")]);
+	/// ```
+	#[inline]
+	pub fn wrap_with(&self, options: WrapOptions) -> Wrap<'_> {
+		Wrap { rest: &self.0, options, first: true }
+	}
+}
+
+/// An iterator over the wrapped lines of a [`RawStr`].
+///
+/// Created by [`RawStr::wrap`] and [`RawStr::wrap_with`].
+#[derive(Debug, Clone)]
+pub struct Wrap<'a> {
+	rest: &'a [u8],
+	options: WrapOptions,
+	first: bool,
+}
+
+impl<'a> Iterator for Wrap<'a> {
+	type Item = &'a RawStr;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.rest.is_empty() {
+			return None;
+		}
+
+		let indent = if self.first { 0 } else { self.options.hanging_indent };
+		let width = self.options.width.saturating_sub(indent).max(1);
+		self.first = false;
+
+		let rest = self.rest;
+		let mut pos = 0;
+		let mut line_width = 0;
+		// the start and end of the last run of whitespace seen on this line
+		let mut last_space: Option<(usize, usize)> = None;
+
+		let (line_end, next_start) = loop {
+			let Some(unit) = utf8::decode_first(&rest[pos..]) else {
+				break (pos, pos);
+			};
+			let len = utf8::unit_len(unit);
+			match unit {
+				Ok('\n') if pos > 0 && rest[pos - 1] == b'\r' => break (pos - 1, pos + 1),
+				Ok('\n') => break (pos, pos + 1),
+				Ok(' ' | '\t') => {
+					let space_end = pos + rest[pos..].iter().take_while(|&&b| b == b' ' || b == b'\t').count();
+					if rest.get(space_end) == Some(&b'\n') || space_end == rest.len() {
+						// trailing whitespace never causes a break
+						break (pos, space_end + usize::from(space_end < rest.len()));
+					}
+					if pos > 0 {
+						last_space = Some((pos, space_end));
+					}
+					line_width += space_end - pos;
+					pos = space_end;
+					continue;
+				}
+				_ => {}
+			}
+
+			let unit_width = width::unit_width(unit);
+			if line_width + unit_width > width && pos > 0 {
+				match last_space {
+					Some((start, end)) => break (start, end),
+					None if self.options.break_anywhere => break (pos, pos),
+					None => {}
+				}
+			}
+			line_width += unit_width;
+			pos += len;
+		};
+
+		self.rest = &rest[next_start..];
+		Some(RawStr::new(&rest[..line_end]))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		// every line consumes at least one byte
+		let len = self.rest.len();
+		(usize::from(len > 0), Some(len))
+	}
+}
+
+impl FusedIterator for Wrap<'_> {}
@@ -0,0 +1,234 @@
+// rawstring::writer
+
+use std::num::TryFromIntError;
+
+use crate::{RawStr, RawStrReader, RawString, ReadError};
+
+/// The encoding of the length in front of a length-prefixed field.
+///
+/// Used by [`RawStringWriter::put_len_prefixed`] and [`RawStrReader::read_len_prefixed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LengthPrefix {
+	/// A single byte.
+	U8,
+	/// A little-endian `u16`.
+	U16Le,
+	/// A big-endian `u16`.
+	U16Be,
+	/// A little-endian `u32`.
+	U32Le,
+	/// A big-endian `u32`.
+	U32Be,
+	/// A little-endian `u64`.
+	U64Le,
+	/// A big-endian `u64`.
+	U64Be,
+	/// An unsigned LEB128 variable-length integer.
+	Var,
+}
+
+/// A builder for binary data, producing a [`RawString`].
+///
+/// This is the counterpart of [`RawStrReader`]: every `put_*` method appends
+/// what the corresponding `read_*` method reads. Methods return `&mut Self` so calls can be chained.
+///
+/// # Examples
+/// ```
+/// # use rawstring::{LengthPrefix, RawStr, RawStrReader, RawStringWriter};
+/// let mut writer = RawStringWriter::new();
+/// writer.put_u8(1).put_u32_be(0xCAFE).put_var_u64(300);
+/// writer.put_len_prefixed(RawStr::new(b"\xFFdata"), LengthPrefix::U16Le).unwrap();
+/// writer.align_to(8, 0);
+/// let data = writer.into_raw_string();
+/// assert_eq!(data.len(), 16);
+///
+/// let mut reader = RawStrReader::new(data.as_ref());
+/// assert_eq!(reader.read_u8(), Ok(1));
+/// assert_eq!(reader.read_u32_be(), Ok(0xCAFE));
+/// assert_eq!(reader.read_var_u64(), Ok(300));
+/// assert_eq!(reader.read_len_prefixed(LengthPrefix::U16Le), Ok(RawStr::new(b"\xFFdata")));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RawStringWriter {
+	buf: RawString,
+}
+
+macro_rules! put_int {
+	($($t:ty => $le:ident, $be:ident;)*) => {$(
+		#[doc = concat!("Appends a little-endian [`", stringify!($t), "`].")]
+		#[inline]
+		pub fn $le(&mut self, value: $t) -> &mut Self {
+			self.put_bytes(&value.to_le_bytes())
+		}
+
+		#[doc = concat!("Appends a big-endian [`", stringify!($t), "`].")]
+		#[inline]
+		pub fn $be(&mut self, value: $t) -> &mut Self {
+			self.put_bytes(&value.to_be_bytes())
+		}
+	)*};
+}
+
+impl RawStringWriter {
+	/// Creates an empty writer.
+	#[inline]
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Creates a writer that appends to an existing [`RawString`].
+	#[inline]
+	#[must_use]
+	pub fn from_raw_string(buf: RawString) -> Self {
+		Self { buf }
+	}
+
+	/// Returns the number of bytes written so far.
+	#[inline]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.buf.len()
+	}
+
+	/// Returns `true` if nothing has been written yet.
+	#[inline]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.buf.is_empty()
+	}
+
+	/// Returns the bytes written so far.
+	#[inline]
+	#[must_use]
+	pub fn as_raw_str(&self) -> &RawStr {
+		self.buf.as_ref()
+	}
+
+	/// Returns the written [`RawString`].
+	#[inline]
+	#[must_use]
+	pub fn into_raw_string(self) -> RawString {
+		self.buf
+	}
+
+	/// Appends raw bytes.
+	#[inline]
+	pub fn put_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+		self.buf.extend_from_slice(bytes);
+		self
+	}
+
+	/// Appends a [`RawStr`].
+	#[inline]
+	pub fn put_raw(&mut self, raw: &RawStr) -> &mut Self {
+		self.put_bytes(raw)
+	}
+
+	/// Appends a [`u8`].
+	#[inline]
+	pub fn put_u8(&mut self, value: u8) -> &mut Self {
+		self.buf.push(value);
+		self
+	}
+
+	/// Appends an [`i8`].
+	#[inline]
+	pub fn put_i8(&mut self, value: i8) -> &mut Self {
+		self.put_bytes(&value.to_le_bytes())
+	}
+
+	put_int! {
+		u16 => put_u16_le, put_u16_be;
+		u32 => put_u32_le, put_u32_be;
+		u64 => put_u64_le, put_u64_be;
+		u128 => put_u128_le, put_u128_be;
+		i16 => put_i16_le, put_i16_be;
+		i32 => put_i32_le, put_i32_be;
+		i64 => put_i64_le, put_i64_be;
+		i128 => put_i128_le, put_i128_be;
+		f32 => put_f32_le, put_f32_be;
+		f64 => put_f64_le, put_f64_be;
+	}
+
+	/// Appends an unsigned LEB128 variable-length integer.
+	pub fn put_var_u64(&mut self, mut value: u64) -> &mut Self {
+		while value >= 0x80 {
+			self.buf.push(value as u8 | 0x80);
+			value >>= 7;
+		}
+		self.put_u8(value as u8)
+	}
+
+	/// Appends a signed LEB128 variable-length integer using zigzag encoding.
+	#[inline]
+	pub fn put_var_i64(&mut self, value: i64) -> &mut Self {
+		self.put_var_u64(((value << 1) ^ (value >> 63)) as u64)
+	}
+
+	/// Appends `raw` preceded by its length encoded as `prefix`.
+	///
+	/// # Errors
+	/// Returns an error, writing nothing, if the length of `raw` does not fit in the prefix.
+	pub fn put_len_prefixed(&mut self, raw: &RawStr, prefix: LengthPrefix) -> Result<&mut Self, TryFromIntError> {
+		let len = raw.len();
+		match prefix {
+			LengthPrefix::U8 => self.put_u8(len.try_into()?),
+			LengthPrefix::U16Le => self.put_u16_le(len.try_into()?),
+			LengthPrefix::U16Be => self.put_u16_be(len.try_into()?),
+			LengthPrefix::U32Le => self.put_u32_le(len.try_into()?),
+			LengthPrefix::U32Be => self.put_u32_be(len.try_into()?),
+			LengthPrefix::U64Le => self.put_u64_le(len.try_into()?),
+			LengthPrefix::U64Be => self.put_u64_be(len.try_into()?),
+			LengthPrefix::Var => self.put_var_u64(len.try_into()?),
+		};
+		Ok(self.put_raw(raw))
+	}
+
+	/// Appends `n` copies of `byte`.
+	#[inline]
+	pub fn pad(&mut self, n: usize, byte: u8) -> &mut Self {
+		let len = self.buf.len();
+		self.buf.resize(len + n, byte);
+		self
+	}
+
+	/// Appends copies of `byte` until the length is a multiple of `alignment`.
+	///
+	/// # Panics
+	/// Panics if `alignment` is zero.
+	#[inline]
+	pub fn align_to(&mut self, alignment: usize, byte: u8) -> &mut Self {
+		let n = self.buf.len().next_multiple_of(alignment) - self.buf.len();
+		self.pad(n, byte)
+	}
+}
+
+impl From<RawStringWriter> for RawString {
+	#[inline]
+	fn from(writer: RawStringWriter) -> Self {
+		writer.buf
+	}
+}
+
+impl<'a> RawStrReader<'a> {
+	/// Reads a field preceded by its length encoded as `prefix`,
+	/// as written by [`RawStringWriter::put_len_prefixed`].
+	///
+	/// On failure the reader is left at its original position.
+	pub fn read_len_prefixed(&mut self, prefix: LengthPrefix) -> Result<&'a RawStr, ReadError> {
+		let start = *self;
+		let len = match prefix {
+			LengthPrefix::U8 => self.read_u8().map(u64::from),
+			LengthPrefix::U16Le => self.read_u16_le().map(u64::from),
+			LengthPrefix::U16Be => self.read_u16_be().map(u64::from),
+			LengthPrefix::U32Le => self.read_u32_le().map(u64::from),
+			LengthPrefix::U32Be => self.read_u32_be().map(u64::from),
+			LengthPrefix::U64Le => self.read_u64_le(),
+			LengthPrefix::U64Be => self.read_u64_be(),
+			LengthPrefix::Var => self.read_var_u64(),
+		}?;
+		self.read_exact_raw(usize::try_from(len).unwrap_or(usize::MAX))
+			.inspect_err(|_| *self = start)
+	}
+}